@@ -0,0 +1,77 @@
+#!/usr/bin/env python3
+"""
+Renseigne le SHA-256 des fichiers du manifeste embarque qui n'en ont pas.
+
+Pour les fichiers LFS, Hugging Face publie l'empreinte dans l'en-tete
+X-Linked-Etag de la redirection ; les autres fichiers sont telecharges et
+hashes. Usage : python3 scripts/fill-manifest-sha256.py [--check]
+(--check liste les fichiers sans empreinte sans rien telecharger).
+"""
+
+import hashlib
+import json
+import re
+import sys
+import urllib.error
+import urllib.request
+from pathlib import Path
+
+MANIFEST = Path(__file__).resolve().parent.parent / "src-tauri/src/engines/model_manifest.json"
+SHA256 = re.compile(r"^[0-9a-f]{64}$")
+
+
+class NoRedirect(urllib.request.HTTPRedirectHandler):
+    def redirect_request(self, req, fp, code, msg, headers, newurl):
+        return None
+
+
+def published_sha256(url):
+    opener = urllib.request.build_opener(NoRedirect)
+    try:
+        response = opener.open(urllib.request.Request(url, method="HEAD"))
+        headers = response.headers
+    except urllib.error.HTTPError as e:
+        headers = e.headers
+    etag = (headers.get("x-linked-etag") or "").removeprefix("W/").strip('"').lower()
+    return etag if SHA256.match(etag) else None
+
+
+def downloaded_sha256(url):
+    digest = hashlib.sha256()
+    with urllib.request.urlopen(url) as response:
+        while chunk := response.read(1 << 20):
+            digest.update(chunk)
+    return digest.hexdigest()
+
+
+def main():
+    manifest = json.loads(MANIFEST.read_text(encoding="utf-8"))
+    if "--check" in sys.argv[1:]:
+        missing = [
+            f"{model['id']}/{file['name']}"
+            for model in manifest["models"]
+            for file in model["files"]
+            if not file.get("sha256")
+        ]
+        for name in missing:
+            print(f"{name}: no sha256", file=sys.stderr)
+        return 1 if missing else 0
+
+    missing = 0
+    for model in manifest["models"]:
+        for file in model["files"]:
+            if file.get("sha256"):
+                continue
+            try:
+                file["sha256"] = published_sha256(file["url"]) or downloaded_sha256(file["url"])
+                print(f"{model['id']}/{file['name']}: {file['sha256']}")
+            except (urllib.error.URLError, OSError) as e:
+                missing += 1
+                print(f"{model['id']}/{file['name']}: {e}", file=sys.stderr)
+            # Ecrit au fur et a mesure pour pouvoir reprendre apres une erreur
+            MANIFEST.write_text(json.dumps(manifest, indent=2, ensure_ascii=False) + "\n", encoding="utf-8")
+    return 1 if missing else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,199 @@
+//! Découpage des longs fichiers audio en fenêtres transcrites une à une
+//!
+//! Chaque fenêtre dure au plus `CHUNK_SECS` et se termine sur la trame la
+//! plus silencieuse de ses dernières secondes, pour éviter de couper un mot.
+//! Les fenêtres se chevauchent légèrement ; les doublons sont retirés lors
+//! de l'assemblage des segments.
+
+/// Durée maximale d'une fenêtre de transcription
+const CHUNK_SECS: f32 = 30.0;
+
+/// Zone (en fin de fenêtre) dans laquelle chercher un silence pour couper
+const SEARCH_SECS: f32 = 5.0;
+
+/// Chevauchement entre deux fenêtres consécutives
+const OVERLAP_SECS: f32 = 1.0;
+
+/// Durée d'une trame d'analyse d'énergie
+const FRAME_SECS: f32 = 0.1;
+
+/// En dessous de cette durée de nouvel audio, la dernière fenêtre est ignorée
+const MIN_TAIL_SECS: f32 = 0.2;
+
+/// Fenêtre audio prête à être transcrite
+#[derive(Debug, Clone)]
+pub struct AudioChunk {
+    pub samples: Vec<f32>,
+    /// Position de début dans le fichier (secondes)
+    pub start_seconds: f32,
+    /// Fin de la fenêtre dans le fichier (secondes)
+    pub end_seconds: f32,
+}
+
+/// Produit des fenêtres successives à partir d'une source lue au fil de l'eau.
+/// Seule la fenêtre courante est gardée en mémoire.
+pub struct Chunker {
+    sample_rate: u32,
+    chunk_len: usize,
+    search_len: usize,
+    overlap_len: usize,
+    buffer: Vec<f32>,
+    /// Position absolue (échantillons) du premier échantillon du buffer
+    buffer_start: usize,
+    /// Début (dans le buffer) de l'audio pas encore transcrit
+    fresh_from: usize,
+    finished: bool,
+}
+
+impl Chunker {
+    pub fn new(sample_rate: u32) -> Self {
+        let secs = |s: f32| (s * sample_rate as f32) as usize;
+        Self {
+            sample_rate,
+            chunk_len: secs(CHUNK_SECS),
+            search_len: secs(SEARCH_SECS),
+            overlap_len: secs(OVERLAP_SECS),
+            buffer: Vec::new(),
+            buffer_start: 0,
+            fresh_from: 0,
+            finished: false,
+        }
+    }
+
+    /// Lit la suite de l'audio via `read(max_samples)` (vecteur vide en fin
+    /// de flux) et retourne la prochaine fenêtre, ou `None` si tout est traité
+    pub fn next_chunk<F>(&mut self, mut read: F) -> Result<Option<AudioChunk>, String>
+    where
+        F: FnMut(usize) -> Result<Vec<f32>, String>,
+    {
+        while !self.finished && self.buffer.len() < self.chunk_len {
+            let samples = read(self.chunk_len - self.buffer.len())?;
+            if samples.is_empty() {
+                self.finished = true;
+            }
+            self.buffer.extend(samples);
+        }
+
+        let fresh = self.buffer.len().saturating_sub(self.fresh_from);
+        let min_tail = (MIN_TAIL_SECS * self.sample_rate as f32) as usize;
+        if self.finished && (fresh == 0 || (fresh < min_tail && self.buffer_start > 0)) {
+            return Ok(None);
+        }
+
+        let cut = if self.finished {
+            self.buffer.len()
+        } else {
+            let min_cut = self
+                .fresh_from
+                .max(self.buffer.len().saturating_sub(self.search_len));
+            quietest_point(&self.buffer, min_cut, self.frame_len())
+        };
+
+        let chunk = AudioChunk {
+            samples: self.buffer[..cut].to_vec(),
+            start_seconds: self.buffer_start as f32 / self.sample_rate as f32,
+            end_seconds: (self.buffer_start + cut) as f32 / self.sample_rate as f32,
+        };
+
+        // Garder le chevauchement en tête de la prochaine fenêtre
+        let keep_from = cut.saturating_sub(self.overlap_len).max(1).min(cut);
+        self.buffer.drain(..keep_from);
+        self.buffer_start += keep_from;
+        self.fresh_from = cut - keep_from;
+
+        if self.finished && self.buffer.len() == self.fresh_from {
+            self.buffer.clear();
+            self.fresh_from = 0;
+        }
+
+        Ok(Some(chunk))
+    }
+
+    fn frame_len(&self) -> usize {
+        ((FRAME_SECS * self.sample_rate as f32) as usize).max(1)
+    }
+}
+
+/// Position (centre de trame) de la trame la moins énergétique après `from`
+fn quietest_point(samples: &[f32], from: usize, frame_len: usize) -> usize {
+    if from + frame_len > samples.len() {
+        return samples.len();
+    }
+
+    let mut best = samples.len();
+    let mut best_energy = f32::MAX;
+    let mut start = from;
+
+    while start + frame_len <= samples.len() {
+        let frame = &samples[start..start + frame_len];
+        let energy = frame.iter().map(|s| s * s).sum::<f32>() / frame_len as f32;
+        if energy < best_energy {
+            best_energy = energy;
+            best = start + frame_len / 2;
+        }
+        start += frame_len;
+    }
+
+    best
+}
+
+/// Progression (0-100) dans un fichier, si sa durée est connue
+pub fn progress_percent(processed_seconds: f32, total_seconds: Option<f32>) -> Option<f32> {
+    total_seconds
+        .filter(|total| *total > 0.0)
+        .map(|total| (processed_seconds / total * 100.0).clamp(0.0, 100.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 1000;
+
+    fn chunks_of(audio: Vec<f32>) -> Vec<AudioChunk> {
+        let mut chunker = Chunker::new(RATE);
+        let mut pos = 0;
+        let mut chunks = Vec::new();
+        while let Some(chunk) = chunker
+            .next_chunk(|max| {
+                let end = (pos + max).min(audio.len());
+                let out = audio[pos..end].to_vec();
+                pos = end;
+                Ok(out)
+            })
+            .unwrap()
+        {
+            chunks.push(chunk);
+        }
+        chunks
+    }
+
+    #[test]
+    fn test_short_audio_is_single_chunk() {
+        let chunks = chunks_of(vec![0.5; 10 * RATE as usize]);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].samples.len(), 10 * RATE as usize);
+    }
+
+    #[test]
+    fn test_cuts_on_silence_with_overlap() {
+        // Parole continue avec un silence à 27s
+        let mut audio = vec![0.5; 70 * RATE as usize];
+        for s in &mut audio[27 * RATE as usize..(27 * RATE + 300) as usize] {
+            *s = 0.0;
+        }
+
+        let chunks = chunks_of(audio);
+        assert!(chunks.len() >= 3);
+        assert!(chunks[0].end_seconds >= 27.0 && chunks[0].end_seconds <= 27.3);
+        assert!((chunks[1].start_seconds - (chunks[0].end_seconds - OVERLAP_SECS)).abs() < 0.01);
+        assert!((chunks.last().unwrap().end_seconds - 70.0).abs() < 0.01);
+        assert!(chunks.iter().all(|c| c.samples.len() <= 30 * RATE as usize));
+    }
+
+    #[test]
+    fn test_progress_percent() {
+        assert_eq!(progress_percent(15.0, Some(60.0)), Some(25.0));
+        assert_eq!(progress_percent(15.0, None), None);
+    }
+}
@@ -1,21 +1,164 @@
 use rubato::{
     Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
 };
+use std::collections::VecDeque;
 use std::path::Path;
 use symphonia::core::audio::SampleBuffer;
-use symphonia::core::codecs::DecoderOptions;
-use symphonia::core::formats::FormatOptions;
+use symphonia::core::codecs::{Decoder, DecoderOptions};
+use symphonia::core::formats::{FormatOptions, FormatReader};
 use symphonia::core::io::MediaSourceStream;
 use symphonia::core::meta::MetadataOptions;
 use symphonia::core::probe::Hint;
 
+/// Taux d'échantillonnage de sortie du décodeur
+const TARGET_SAMPLE_RATE: u32 = 16000;
+
+/// Taille des blocs d'entrée du resampler en flux
+const RESAMPLER_CHUNK: usize = 1024;
+
 pub struct AudioDecoder;
 
+/// Flux audio décodé à la demande, converti en 16kHz mono.
+///
+/// Seuls les échantillons en attente sont gardés en mémoire, ce qui permet
+/// de traiter des fichiers de plusieurs heures à mémoire bornée.
+pub struct AudioStream {
+    format: Box<dyn FormatReader>,
+    decoder: Box<dyn Decoder>,
+    track_id: u32,
+    channels: usize,
+    source_rate: u32,
+    total_frames: Option<u64>,
+    resampler: Option<SincFixedIn<f32>>,
+    /// Échantillons mono au taux source, en attente de resampling
+    pending_in: Vec<f32>,
+    /// Échantillons 16kHz prêts à être lus
+    pending_out: VecDeque<f32>,
+    finished: bool,
+}
+
+impl AudioStream {
+    /// Durée totale du fichier si le conteneur l'indique
+    pub fn duration_seconds(&self) -> Option<f32> {
+        self.total_frames
+            .map(|frames| frames as f32 / self.source_rate as f32)
+    }
+
+    /// Lit jusqu'à `max_samples` échantillons à 16kHz.
+    /// Retourne un vecteur vide en fin de fichier.
+    pub fn read(&mut self, max_samples: usize) -> Result<Vec<f32>, String> {
+        while self.pending_out.len() < max_samples && !self.finished {
+            self.decode_next_packet()?;
+        }
+
+        let n = max_samples.min(self.pending_out.len());
+        Ok(self.pending_out.drain(..n).collect())
+    }
+
+    /// Lit tout le reste du fichier
+    pub fn read_to_end(&mut self) -> Result<Vec<f32>, String> {
+        while !self.finished {
+            self.decode_next_packet()?;
+        }
+        Ok(self.pending_out.drain(..).collect())
+    }
+
+    fn decode_next_packet(&mut self) -> Result<(), String> {
+        let packet = match self.format.next_packet() {
+            Ok(packet) => packet,
+            Err(symphonia::core::errors::Error::IoError(ref e))
+                if e.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                return self.flush();
+            }
+            Err(symphonia::core::errors::Error::ResetRequired) => {
+                // Handle reset required by resetting the decoder
+                self.decoder.reset();
+                return Ok(());
+            }
+            Err(e) => return Err(format!("Decode error: {}", e)),
+        };
+
+        if packet.track_id() != self.track_id {
+            return Ok(());
+        }
+
+        match self.decoder.decode(&packet) {
+            Ok(decoded) => {
+                let spec = *decoded.spec();
+                let duration = decoded.capacity() as u64;
+
+                let mut sample_buf = SampleBuffer::<f32>::new(duration, spec);
+                sample_buf.copy_interleaved_ref(decoded);
+
+                let samples = sample_buf.samples();
+
+                // Convert to mono if needed
+                if self.channels > 1 {
+                    for chunk in samples.chunks(self.channels) {
+                        let mono: f32 = chunk.iter().sum::<f32>() / self.channels as f32;
+                        self.pending_in.push(mono);
+                    }
+                } else {
+                    self.pending_in.extend_from_slice(samples);
+                }
+
+                self.resample_pending(false)
+            }
+            Err(symphonia::core::errors::Error::DecodeError(_)) => {
+                // Skip decode errors
+                Ok(())
+            }
+            Err(e) => Err(format!("Decode error: {}", e)),
+        }
+    }
+
+    /// Resample les blocs complets en attente (et le reliquat si `flush`)
+    fn resample_pending(&mut self, flush: bool) -> Result<(), String> {
+        let resampler = match self.resampler.as_mut() {
+            Some(resampler) => resampler,
+            None => {
+                self.pending_out.extend(self.pending_in.drain(..));
+                return Ok(());
+            }
+        };
+
+        let mut consumed = 0;
+        while self.pending_in.len() - consumed >= resampler.input_frames_next() {
+            let needed = resampler.input_frames_next();
+            let block = &self.pending_in[consumed..consumed + needed];
+            let out = resampler
+                .process(&[block], None)
+                .map_err(|e| format!("Resample error: {}", e))?;
+            self.pending_out
+                .extend(out.into_iter().next().unwrap_or_default());
+            consumed += needed;
+        }
+
+        if flush && consumed < self.pending_in.len() {
+            let block = &self.pending_in[consumed..];
+            let out = resampler
+                .process_partial(Some(&[block]), None)
+                .map_err(|e| format!("Resample error: {}", e))?;
+            self.pending_out
+                .extend(out.into_iter().next().unwrap_or_default());
+            consumed = self.pending_in.len();
+        }
+
+        self.pending_in.drain(..consumed);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        self.finished = true;
+        self.resample_pending(true)
+    }
+}
+
 impl AudioDecoder {
-    /// Decode audio file to f32 samples at 16kHz mono
-    pub fn decode_file(path: &Path) -> Result<(Vec<f32>, u32), String> {
-        let file =
-            std::fs::File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    /// Ouvre un fichier audio pour un décodage progressif en 16kHz mono
+    pub fn open(path: &Path) -> Result<AudioStream, String> {
+        let file = std::fs::File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
 
         let mss = MediaSourceStream::new(Box::new(file), Default::default());
 
@@ -32,7 +175,7 @@ impl AudioDecoder {
             .format(&hint, mss, &format_opts, &metadata_opts)
             .map_err(|e| format!("Failed to probe format: {}", e))?;
 
-        let mut format = probed.format;
+        let format = probed.format;
 
         let track = format
             .tracks()
@@ -43,77 +186,34 @@ impl AudioDecoder {
         let track_id = track.id;
         let codec_params = track.codec_params.clone();
 
-        let mut decoder = symphonia::default::get_codecs()
+        let decoder = symphonia::default::get_codecs()
             .make(&codec_params, &decoder_opts)
             .map_err(|e| format!("Failed to create decoder: {}", e))?;
 
         let sample_rate = codec_params.sample_rate.unwrap_or(44100);
         let channels = codec_params.channels.map(|c| c.count()).unwrap_or(1);
 
-        let mut all_samples: Vec<f32> = Vec::new();
-
-        loop {
-            let packet = match format.next_packet() {
-                Ok(packet) => packet,
-                Err(symphonia::core::errors::Error::IoError(ref e))
-                    if e.kind() == std::io::ErrorKind::UnexpectedEof =>
-                {
-                    break;
-                }
-                Err(symphonia::core::errors::Error::ResetRequired) => {
-                    // Handle reset required by resetting the decoder
-                    decoder.reset();
-                    continue;
-                }
-                Err(e) => return Err(format!("Decode error: {}", e)),
-            };
-
-            if packet.track_id() != track_id {
-                continue;
-            }
-
-            match decoder.decode(&packet) {
-                Ok(decoded) => {
-                    let spec = *decoded.spec();
-                    let duration = decoded.capacity() as u64;
-
-                    let mut sample_buf = SampleBuffer::<f32>::new(duration, spec);
-                    sample_buf.copy_interleaved_ref(decoded);
-
-                    let samples = sample_buf.samples();
-
-                    // Convert to mono if needed
-                    if channels > 1 {
-                        for chunk in samples.chunks(channels) {
-                            let mono: f32 = chunk.iter().sum::<f32>() / channels as f32;
-                            all_samples.push(mono);
-                        }
-                    } else {
-                        all_samples.extend_from_slice(samples);
-                    }
-                }
-                Err(symphonia::core::errors::Error::DecodeError(_)) => {
-                    // Skip decode errors
-                    continue;
-                }
-                Err(e) => return Err(format!("Decode error: {}", e)),
-            }
-        }
-
-        // Resample to 16kHz if needed
-        let target_rate = 16000u32;
-        if sample_rate != target_rate {
-            all_samples = Self::resample(&all_samples, sample_rate, target_rate)?;
-        }
+        let resampler = if sample_rate != TARGET_SAMPLE_RATE {
+            Some(Self::create_resampler(sample_rate, TARGET_SAMPLE_RATE)?)
+        } else {
+            None
+        };
 
-        Ok((all_samples, target_rate))
+        Ok(AudioStream {
+            format,
+            decoder,
+            track_id,
+            channels,
+            source_rate: sample_rate,
+            total_frames: codec_params.n_frames,
+            resampler,
+            pending_in: Vec::new(),
+            pending_out: VecDeque::new(),
+            finished: false,
+        })
     }
 
-    fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Result<Vec<f32>, String> {
-        if samples.is_empty() {
-            return Ok(Vec::new());
-        }
-
+    fn create_resampler(from_rate: u32, to_rate: u32) -> Result<SincFixedIn<f32>, String> {
         let params = SincInterpolationParameters {
             sinc_len: 256,
             f_cutoff: 0.95,
@@ -122,27 +222,26 @@ impl AudioDecoder {
             window: WindowFunction::BlackmanHarris2,
         };
 
-        let mut resampler = SincFixedIn::<f32>::new(
+        SincFixedIn::<f32>::new(
             to_rate as f64 / from_rate as f64,
             2.0,
             params,
-            samples.len(),
+            RESAMPLER_CHUNK,
             1,
         )
-        .map_err(|e| format!("Failed to create resampler: {}", e))?;
-
-        let waves_in = vec![samples.to_vec()];
-        let waves_out = resampler
-            .process(&waves_in, None)
-            .map_err(|e| format!("Resample error: {}", e))?;
-
-        Ok(waves_out.into_iter().next().unwrap_or_default())
+        .map_err(|e| format!("Failed to create resampler: {}", e))
     }
 
     /// Get audio file duration in seconds without fully decoding
     pub fn get_duration(path: &Path) -> Result<f32, String> {
-        let (samples, rate) = Self::decode_file(path)?;
-        Ok(samples.len() as f32 / rate as f32)
+        let mut stream = Self::open(path)?;
+        match stream.duration_seconds() {
+            Some(duration) => Ok(duration),
+            None => {
+                let samples = stream.read_to_end()?;
+                Ok(samples.len() as f32 / TARGET_SAMPLE_RATE as f32)
+            }
+        }
     }
 
     /// Check if file format is supported
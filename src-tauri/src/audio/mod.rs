@@ -1,7 +1,9 @@
 pub mod capture;
+pub mod chunker;
 pub mod decoder;
 pub mod resampling;
 pub mod streaming;
+pub mod vad;
 
 pub use capture::*;
 pub use decoder::AudioDecoder;
@@ -1,5 +1,8 @@
 use std::sync::{Arc, Mutex};
 
+use super::vad;
+use crate::types::TranscriptionResult;
+
 /// Configuration for streaming transcription
 pub struct StreamingConfig {
     /// Chunk duration in seconds (default: 2.5s)
@@ -8,6 +11,10 @@ pub struct StreamingConfig {
     pub overlap_secs: f32,
     /// Sample rate of the audio
     pub sample_rate: u32,
+    /// Maximum length of the re-transcribed window before it is trimmed
+    pub max_window_secs: f32,
+    /// Pause that ends an utterance (all pending words are then committed)
+    pub min_pause_secs: f32,
 }
 
 impl Default for StreamingConfig {
@@ -16,10 +23,68 @@ impl Default for StreamingConfig {
             chunk_duration_secs: 2.5,
             overlap_secs: 0.5,
             sample_rate: 16000,
+            max_window_secs: 12.0,
+            min_pause_secs: 0.5,
         }
     }
 }
 
+/// A word of a transcription hypothesis
+#[derive(Debug, Clone, PartialEq)]
+pub struct HypothesisWord {
+    pub text: String,
+    /// End of the word relative to the start of the transcribed window, if known
+    pub end_seconds: Option<f32>,
+}
+
+impl HypothesisWord {
+    /// Words of a transcription, with timings when the engine provides them
+    pub fn from_result(result: &TranscriptionResult) -> Vec<Self> {
+        let timed: Vec<Self> = result
+            .segments
+            .iter()
+            .flat_map(|segment| &segment.words)
+            .map(|word| Self {
+                text: word.word.clone(),
+                end_seconds: Some(word.end),
+            })
+            .collect();
+
+        if !timed.is_empty() {
+            return timed;
+        }
+
+        result
+            .text
+            .split_whitespace()
+            .map(|word| Self {
+                text: word.to_string(),
+                end_seconds: None,
+            })
+            .collect()
+    }
+}
+
+/// Text changes produced by a streaming step
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StreamingUpdate {
+    /// Newly committed words: stable, they will not change anymore
+    pub committed: String,
+    /// Unconfirmed tail of the latest hypothesis, may still change
+    pub partial: String,
+}
+
+impl StreamingUpdate {
+    fn merge(mut self, next: StreamingUpdate) -> Self {
+        if !self.committed.is_empty() && !next.committed.is_empty() {
+            self.committed.push(' ');
+        }
+        self.committed.push_str(&next.committed);
+        self.partial = next.partial;
+        self
+    }
+}
+
 /// A streaming buffer that accumulates audio and provides chunks for transcription
 pub struct StreamingBuffer {
     /// The accumulated audio samples
@@ -30,6 +95,14 @@ pub struct StreamingBuffer {
     processed_samples: usize,
     /// Total accumulated text from all chunks
     accumulated_text: Arc<Mutex<String>>,
+    /// Start of the window re-transcribed at each streaming step
+    window_start: usize,
+    /// Buffer length at the last transcription of the window
+    transcribed_len: usize,
+    /// Words committed since the start of the window
+    window_committed: Vec<String>,
+    /// Uncommitted tail of the previous hypothesis
+    pending: Vec<HypothesisWord>,
 }
 
 impl StreamingBuffer {
@@ -39,6 +112,10 @@ impl StreamingBuffer {
             config,
             processed_samples: 0,
             accumulated_text: Arc::new(Mutex::new(String::new())),
+            window_start: 0,
+            transcribed_len: 0,
+            window_committed: Vec::new(),
+            pending: Vec::new(),
         }
     }
 
@@ -72,11 +149,7 @@ impl StreamingBuffer {
         let overlap = self.overlap_samples();
 
         // Start position includes overlap from previous chunk (except for first chunk)
-        let start = if self.processed_samples > overlap {
-            self.processed_samples - overlap
-        } else {
-            0
-        };
+        let start = self.processed_samples.saturating_sub(overlap);
 
         let end = start + chunk_size;
 
@@ -97,11 +170,7 @@ impl StreamingBuffer {
         let buffer = self.buffer.lock().unwrap();
         let overlap = self.overlap_samples();
 
-        let start = if self.processed_samples > overlap {
-            self.processed_samples - overlap
-        } else {
-            0
-        };
+        let start = self.processed_samples.saturating_sub(overlap);
 
         if start < buffer.len() {
             buffer[start..].to_vec()
@@ -120,6 +189,10 @@ impl StreamingBuffer {
         self.buffer.lock().unwrap().clear();
         self.processed_samples = 0;
         self.accumulated_text.lock().unwrap().clear();
+        self.window_start = 0;
+        self.transcribed_len = 0;
+        self.window_committed.clear();
+        self.pending.clear();
     }
 
     /// Append text from a chunk transcription
@@ -147,6 +220,199 @@ impl StreamingBuffer {
     }
 }
 
+/// Local-agreement streaming: the window of audio not yet committed is
+/// re-transcribed at each step, and only the words on which two consecutive
+/// hypotheses agree are committed. Committed words never change, the rest is
+/// reported as a partial result.
+impl StreamingBuffer {
+    /// Appends newly recorded samples
+    pub fn push_audio(&self, samples: &[f32]) {
+        self.buffer.lock().unwrap().extend_from_slice(samples);
+    }
+
+    /// Audio not committed yet, re-transcribed at each step
+    pub fn window_audio(&self) -> Vec<f32> {
+        let buffer = self.buffer.lock().unwrap();
+        buffer[self.window_start.min(buffer.len())..].to_vec()
+    }
+
+    /// Duration of the window in seconds
+    pub fn window_secs(&self) -> f32 {
+        self.buffer_len().saturating_sub(self.window_start) as f32 / self.config.sample_rate as f32
+    }
+
+    /// Runs one streaming step on the audio pushed so far.
+    ///
+    /// `transcribe` receives the window audio (at `config.sample_rate`) and
+    /// returns the hypothesis words. Returns `None` when nothing changed.
+    pub fn step<F>(
+        &mut self,
+        vad_enabled: bool,
+        transcribe: F,
+    ) -> Result<Option<StreamingUpdate>, String>
+    where
+        F: FnMut(&[f32]) -> Result<Vec<HypothesisWord>, String>,
+    {
+        let buffer_len = self.buffer_len();
+        if buffer_len <= self.transcribed_len {
+            return Ok(None);
+        }
+
+        let rate = self.config.sample_rate;
+        let pause_samples = (self.config.min_pause_secs * rate as f32) as usize;
+        let mut window = self.window_audio();
+
+        if vad_enabled {
+            let regions = vad::split_on_pauses(&window, rate, self.config.min_pause_secs);
+
+            let (first_start, last_end) = match (regions.first(), regions.last()) {
+                (Some(&(start, _)), Some(&(_, end))) => (start, end),
+                _ => {
+                    // Only silence: the current utterance is over
+                    let update = self.finish_window(window.len().saturating_sub(pause_samples));
+                    return Ok((!update.committed.is_empty()).then_some(update));
+                }
+            };
+
+            // Skip the silence preceding the first word
+            let skipped = if self.window_committed.is_empty() && self.pending.is_empty() {
+                first_start
+            } else {
+                0
+            };
+            self.window_start += skipped;
+            window.drain(..skipped);
+
+            return self.step_window(window, last_end - skipped, pause_samples, transcribe);
+        }
+
+        let speech_end = window.len();
+        self.step_window(window, speech_end, 0, transcribe)
+    }
+
+    fn step_window<F>(
+        &mut self,
+        window: Vec<f32>,
+        speech_end: usize,
+        pause_samples: usize,
+        mut transcribe: F,
+    ) -> Result<Option<StreamingUpdate>, String>
+    where
+        F: FnMut(&[f32]) -> Result<Vec<HypothesisWord>, String>,
+    {
+        self.transcribed_len = self.buffer_len();
+
+        // Utterance followed by a pause: its transcription is final
+        if pause_samples > 0 && window.len() - speech_end >= pause_samples {
+            let words = transcribe(&window[..speech_end])?;
+            self.pending.clear();
+            let update = self.insert_hypothesis(words);
+            return Ok(Some(update.merge(self.finish_window(speech_end))));
+        }
+
+        let words = transcribe(&window)?;
+        let update = self.insert_hypothesis(words);
+
+        if self.window_secs() > self.config.max_window_secs {
+            return Ok(Some(update.merge(self.finish_window(window.len()))));
+        }
+        Ok(Some(update))
+    }
+
+    /// Commits the words on which `words` agrees with the previous hypothesis
+    pub fn insert_hypothesis(&mut self, words: Vec<HypothesisWord>) -> StreamingUpdate {
+        // Words already committed in this window have been emitted before
+        let tail: Vec<HypothesisWord> = words
+            .into_iter()
+            .skip(self.window_committed.len())
+            .collect();
+
+        let agreed = self
+            .pending
+            .iter()
+            .zip(&tail)
+            .take_while(|(previous, current)| normalize(&previous.text) == normalize(&current.text))
+            .count();
+
+        let committed: Vec<&HypothesisWord> = tail[..agreed].iter().collect();
+        let committed_text = committed
+            .iter()
+            .map(|w| w.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !committed_text.is_empty() {
+            self.append_text(&committed_text);
+        }
+        self.window_committed
+            .extend(committed.iter().map(|w| w.text.clone()));
+
+        // Trim the window up to the last committed word when its timing is known
+        let trim_at = committed.last().and_then(|w| w.end_seconds);
+        let pending = tail[agreed..].to_vec();
+
+        if let Some(end) =
+            trim_at.filter(|_| self.window_secs() > self.config.max_window_secs / 2.0)
+        {
+            let trimmed = (end * self.config.sample_rate as f32) as usize;
+            self.window_start = (self.window_start + trimmed).min(self.buffer_len());
+            self.window_committed.clear();
+        }
+
+        let partial = pending
+            .iter()
+            .map(|w| w.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.pending = pending;
+
+        StreamingUpdate {
+            committed: committed_text,
+            partial,
+        }
+    }
+
+    /// Commits the pending words and starts a new window `at` samples after
+    /// the start of the current one (end of an utterance or forced cut)
+    pub fn finish_window(&mut self, at: usize) -> StreamingUpdate {
+        let committed = self
+            .pending
+            .drain(..)
+            .map(|w| w.text)
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !committed.is_empty() {
+            self.append_text(&committed);
+        }
+
+        self.window_start = (self.window_start + at).min(self.buffer_len());
+        self.transcribed_len = self.transcribed_len.max(self.window_start);
+        self.window_committed.clear();
+
+        StreamingUpdate {
+            committed,
+            partial: String::new(),
+        }
+    }
+}
+
+/// Comparable form of a word (case and punctuation ignored)
+fn normalize(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// Part of `final_text` not yet covered by the streamed `committed` text.
+/// Both are aligned word by word; if the final transcription rewrote the
+/// beginning, the same number of words is skipped.
+pub fn uncommitted_suffix(committed: &str, final_text: &str) -> String {
+    let committed_words = committed.split_whitespace().count();
+    final_text
+        .split_whitespace()
+        .skip(committed_words)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Result of a streaming chunk transcription
 #[derive(Debug, Clone)]
 pub struct StreamingChunk {
@@ -168,6 +434,7 @@ mod tests {
             chunk_duration_secs: 1.0,
             overlap_secs: 0.2,
             sample_rate: 16000,
+            ..Default::default()
         };
 
         let mut buffer = StreamingBuffer::new(config);
@@ -202,4 +469,46 @@ mod tests {
 
         assert_eq!(buffer.get_accumulated_text(), "Hello world test");
     }
+
+    fn words(text: &str) -> Vec<HypothesisWord> {
+        text.split_whitespace()
+            .map(|w| HypothesisWord {
+                text: w.to_string(),
+                end_seconds: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_local_agreement_commits_confirmed_prefix() {
+        let mut buffer = StreamingBuffer::new(StreamingConfig::default());
+
+        let update = buffer.insert_hypothesis(words("Bonjour à"));
+        assert_eq!(update.committed, "");
+        assert_eq!(update.partial, "Bonjour à");
+
+        // "tous" replaces "à": only "Bonjour" is confirmed
+        let update = buffer.insert_hypothesis(words("bonjour, tous les"));
+        assert_eq!(update.committed, "bonjour,");
+        assert_eq!(update.partial, "tous les");
+
+        // The rewritten beginning is not emitted again
+        let update = buffer.insert_hypothesis(words("Bonjour tous les amis"));
+        assert_eq!(update.committed, "tous les");
+        assert_eq!(update.partial, "amis");
+
+        let update = buffer.finish_window(0);
+        assert_eq!(update.committed, "amis");
+        assert_eq!(buffer.get_accumulated_text(), "bonjour, tous les amis");
+    }
+
+    #[test]
+    fn test_uncommitted_suffix() {
+        assert_eq!(uncommitted_suffix("Bonjour à", "Bonjour à tous."), "tous.");
+        assert_eq!(
+            uncommitted_suffix("Bonjour à", "Bonjour, tous les amis"),
+            "les amis"
+        );
+        assert_eq!(uncommitted_suffix("", "Salut"), "Salut");
+    }
 }
@@ -0,0 +1,403 @@
+//! Détection d'activité vocale (VAD)
+//!
+//! Détecteur léger basé sur l'énergie (relative à un plancher de bruit
+//! adaptatif) et la platitude spectrale : la voix a un spectre structuré
+//! (harmoniques, formants), le souffle et le bruit de fond un spectre plat.
+//! Sert à retirer les silences avant transcription (Whisper hallucine sur le
+//! silence), à découper le flux sur les pauses et à l'arrêt automatique.
+
+use std::f32::consts::PI;
+
+/// Durée d'une trame d'analyse
+const FRAME_MS: u32 = 30;
+
+/// Écart minimal au plancher de bruit pour qu'une trame soit de la parole
+const ENERGY_MARGIN_DB: f32 = 10.0;
+
+/// En dessous de ce niveau, une trame est toujours du silence
+const MIN_ENERGY_DB: f32 = -55.0;
+
+/// Au-delà de cette platitude spectrale, la trame est considérée comme du bruit
+const MAX_SPECTRAL_FLATNESS: f32 = 0.55;
+
+/// Maintien de l'état "parole" après la dernière trame voisée
+const HANGOVER_MS: u32 = 240;
+
+/// Marge de silence conservée autour de la parole lors du découpage
+const PADDING_MS: u32 = 200;
+
+/// Nombre de bandes de fréquence évaluées pour la platitude spectrale
+const SPECTRUM_BINS: usize = 32;
+
+/// Bande de fréquences de la voix utilisée pour l'analyse spectrale
+const SPECTRUM_MIN_HZ: f32 = 150.0;
+const SPECTRUM_MAX_HZ: f32 = 4000.0;
+
+/// Détecteur d'activité vocale trame par trame
+pub struct VoiceActivityDetector {
+    sample_rate: u32,
+    frame_len: usize,
+    hangover_frames: usize,
+    noise_floor_db: Option<f32>,
+    /// Plancher fixé par `calibrate`, qui ne s'adapte plus au signal
+    fixed_noise_floor: bool,
+    spectrum: Spectrum,
+    hangover_left: usize,
+    /// Échantillons en attente d'une trame complète
+    pending: Vec<f32>,
+    heard_speech: bool,
+    trailing_silence_frames: usize,
+}
+
+impl VoiceActivityDetector {
+    pub fn new(sample_rate: u32) -> Self {
+        let frame_len = (sample_rate * FRAME_MS / 1000).max(1) as usize;
+        Self {
+            sample_rate,
+            frame_len,
+            hangover_frames: (HANGOVER_MS / FRAME_MS) as usize,
+            noise_floor_db: None,
+            fixed_noise_floor: false,
+            spectrum: Spectrum::new(frame_len, sample_rate),
+            hangover_left: 0,
+            pending: Vec::new(),
+            heard_speech: false,
+            trailing_silence_frames: 0,
+        }
+    }
+
+    /// Fixe le plancher de bruit à partir d'un enregistrement complet
+    /// (trames les plus calmes), plus fiable que l'estimation en flux.
+    /// Si les trames les plus calmes ne se distinguent pas des plus fortes
+    /// (enregistrement sans pause), elles sont de la parole : seul le seuil
+    /// absolu `MIN_ENERGY_DB` s'applique alors.
+    fn calibrate(&mut self, samples: &[f32]) {
+        let mut energies: Vec<f32> = samples
+            .chunks_exact(self.frame_len)
+            .map(energy_db)
+            .collect();
+        if energies.is_empty() {
+            return;
+        }
+        energies.sort_by(|a, b| a.total_cmp(b));
+        let quiet = energies[energies.len() / 10];
+        let loud = energies[energies.len() * 9 / 10];
+        if loud - quiet >= ENERGY_MARGIN_DB {
+            self.noise_floor_db = Some(quiet);
+        } else {
+            self.noise_floor_db = Some(MIN_ENERGY_DB - ENERGY_MARGIN_DB);
+            self.fixed_noise_floor = true;
+        }
+    }
+
+    /// Analyse de nouveaux échantillons ; retourne un booléen par trame complète
+    pub fn push(&mut self, samples: &[f32]) -> Vec<bool> {
+        self.pending.extend_from_slice(samples);
+
+        let pending = std::mem::take(&mut self.pending);
+        let frames = pending.chunks_exact(self.frame_len);
+        self.pending = frames.remainder().to_vec();
+        frames.map(|frame| self.process_frame(frame)).collect()
+    }
+
+    fn process_frame(&mut self, frame: &[f32]) -> bool {
+        let energy = energy_db(frame);
+        let noise = *self.noise_floor_db.get_or_insert(energy);
+
+        let voiced = energy > MIN_ENERGY_DB
+            && energy > noise + ENERGY_MARGIN_DB
+            && self.spectrum.flatness(frame) < MAX_SPECTRAL_FLATNESS;
+
+        // Plancher de bruit : descend immédiatement, remonte lentement hors parole
+        if !self.fixed_noise_floor {
+            let updated = if energy < noise {
+                energy
+            } else if !voiced {
+                noise + 0.05 * (energy - noise)
+            } else {
+                noise + 0.002 * (energy - noise)
+            };
+            self.noise_floor_db = Some(updated);
+        }
+
+        let speech = if voiced {
+            self.hangover_left = self.hangover_frames;
+            true
+        } else if self.hangover_left > 0 {
+            self.hangover_left -= 1;
+            true
+        } else {
+            false
+        };
+
+        if speech {
+            self.heard_speech = true;
+            self.trailing_silence_frames = 0;
+        } else {
+            self.trailing_silence_frames += 1;
+        }
+
+        speech
+    }
+
+    /// Indique si de la parole a été entendue depuis le début
+    pub fn has_heard_speech(&self) -> bool {
+        self.heard_speech
+    }
+
+    /// Durée du silence continu en fin de flux (secondes)
+    pub fn trailing_silence_secs(&self) -> f32 {
+        (self.trailing_silence_frames * self.frame_len) as f32 / self.sample_rate as f32
+    }
+}
+
+/// Arrêt automatique après un silence prolongé suivant de la parole
+pub struct SilenceAutoStop {
+    vad: VoiceActivityDetector,
+    timeout_secs: f32,
+}
+
+impl SilenceAutoStop {
+    pub fn new(sample_rate: u32, timeout_secs: f32) -> Self {
+        Self {
+            vad: VoiceActivityDetector::new(sample_rate),
+            timeout_secs,
+        }
+    }
+
+    /// Ajoute les nouveaux échantillons ; retourne `true` quand il faut arrêter
+    pub fn push(&mut self, samples: &[f32]) -> bool {
+        self.vad.push(samples);
+        self.vad.has_heard_speech() && self.vad.trailing_silence_secs() >= self.timeout_secs
+    }
+}
+
+/// Décision parole/silence pour chaque trame d'un enregistrement complet
+fn speech_frames(samples: &[f32], sample_rate: u32) -> (Vec<bool>, usize) {
+    let mut vad = VoiceActivityDetector::new(sample_rate);
+    vad.calibrate(samples);
+    let decisions = vad.push(samples);
+    (decisions, vad.frame_len)
+}
+
+/// Régions de parole `(début, fin)` en échantillons, séparées par des pauses
+/// d'au moins `min_pause_secs`, avec une marge de silence de chaque côté
+pub fn split_on_pauses(
+    samples: &[f32],
+    sample_rate: u32,
+    min_pause_secs: f32,
+) -> Vec<(usize, usize)> {
+    let (decisions, frame_len) = speech_frames(samples, sample_rate);
+    let min_pause = (min_pause_secs * sample_rate as f32) as usize;
+    let padding = (sample_rate * PADDING_MS / 1000) as usize;
+
+    let mut regions: Vec<(usize, usize)> = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+
+    for (i, speech) in decisions.iter().enumerate() {
+        if !speech {
+            continue;
+        }
+        let (start, end) = (i * frame_len, (i + 1) * frame_len);
+        current = match current {
+            Some((s, e)) if start - e < min_pause => Some((s, end)),
+            Some(region) => {
+                regions.push(region);
+                Some((start, end))
+            }
+            None => Some((start, end)),
+        };
+    }
+    regions.extend(current);
+
+    regions
+        .into_iter()
+        .map(|(s, e)| (s.saturating_sub(padding), (e + padding).min(samples.len())))
+        .collect()
+}
+
+/// Bornes `(début, fin)` de la parole dans l'enregistrement, `None` si silence
+pub fn speech_bounds(samples: &[f32], sample_rate: u32) -> Option<(usize, usize)> {
+    let (decisions, frame_len) = speech_frames(samples, sample_rate);
+    let first = decisions.iter().position(|&s| s)?;
+    let last = decisions.iter().rposition(|&s| s)?;
+    let padding = (sample_rate * PADDING_MS / 1000) as usize;
+
+    Some((
+        (first * frame_len).saturating_sub(padding),
+        ((last + 1) * frame_len + padding).min(samples.len()),
+    ))
+}
+
+/// Retire le silence de début et de fin ; retourne une tranche vide si
+/// aucune parole n'est détectée
+pub fn trim_silence(samples: &[f32], sample_rate: u32) -> &[f32] {
+    match speech_bounds(samples, sample_rate) {
+        Some((start, end)) => &samples[start..end],
+        None => &samples[..0],
+    }
+}
+
+/// Prépare un enregistrement pour la transcription : retire les silences de
+/// début et de fin si le VAD est activé. `None` si aucune parole n'est détectée.
+pub fn prepare_for_transcription(samples: Vec<f32>, sample_rate: u32, enabled: bool) -> Option<Vec<f32>> {
+    if !enabled {
+        return Some(samples);
+    }
+
+    let (start, end) = speech_bounds(&samples, sample_rate)?;
+    if start == 0 && end == samples.len() {
+        Some(samples)
+    } else {
+        Some(samples[start..end].to_vec())
+    }
+}
+
+/// Énergie moyenne d'une trame en dBFS
+fn energy_db(frame: &[f32]) -> f32 {
+    let mean = frame.iter().map(|s| s * s).sum::<f32>() / frame.len().max(1) as f32;
+    10.0 * (mean + 1e-10).log10()
+}
+
+/// Platitude spectrale (moyenne géométrique / arithmétique de la puissance)
+/// sur la bande vocale : proche de 1 pour du bruit, faible pour de la voix.
+/// La fenêtre et les sinus/cosinus de chaque bande sont calculés une fois
+/// par détecteur, la taille des trames étant fixe.
+struct Spectrum {
+    /// Fenêtre de Hann pour limiter les fuites spectrales
+    window: Vec<f32>,
+    /// `cos`/`sin` de la phase de chaque bande, trame après trame
+    cos: Vec<f32>,
+    sin: Vec<f32>,
+}
+
+impl Spectrum {
+    fn new(frame_len: usize, sample_rate: u32) -> Self {
+        let n = frame_len as f32;
+        let max_hz = SPECTRUM_MAX_HZ.min(sample_rate as f32 / 2.0);
+        let step = (max_hz - SPECTRUM_MIN_HZ) / SPECTRUM_BINS as f32;
+
+        let window = (0..frame_len)
+            .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / n).cos())
+            .collect();
+        let mut cos = Vec::with_capacity(SPECTRUM_BINS * frame_len);
+        let mut sin = Vec::with_capacity(SPECTRUM_BINS * frame_len);
+        for bin in 0..SPECTRUM_BINS {
+            let freq = SPECTRUM_MIN_HZ + step * (bin as f32 + 0.5);
+            let omega = 2.0 * PI * freq / sample_rate as f32;
+            for i in 0..frame_len {
+                let phase = omega * i as f32;
+                cos.push(phase.cos());
+                sin.push(phase.sin());
+            }
+        }
+
+        Self { window, cos, sin }
+    }
+
+    fn flatness(&self, frame: &[f32]) -> f32 {
+        let windowed: Vec<f32> = frame.iter().zip(&self.window).map(|(s, w)| s * w).collect();
+
+        let mut log_sum = 0.0f32;
+        let mut sum = 0.0f32;
+
+        for (cos, sin) in self
+            .cos
+            .chunks_exact(windowed.len())
+            .zip(self.sin.chunks_exact(windowed.len()))
+        {
+            let (mut re, mut im) = (0.0f32, 0.0f32);
+            for ((s, c), si) in windowed.iter().zip(cos).zip(sin) {
+                re += s * c;
+                im -= s * si;
+            }
+
+            let power = re * re + im * im + 1e-12;
+            log_sum += power.ln();
+            sum += power;
+        }
+
+        let geometric = (log_sum / SPECTRUM_BINS as f32).exp();
+        let arithmetic = sum / SPECTRUM_BINS as f32;
+        (geometric / arithmetic).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16000;
+
+    /// Voyelle synthétique : fondamentale et harmoniques
+    fn voice(secs: f32) -> Vec<f32> {
+        (0..(secs * RATE as f32) as usize)
+            .map(|i| {
+                let t = i as f32 / RATE as f32;
+                (1..6)
+                    .map(|h| (2.0 * PI * 140.0 * h as f32 * t).sin() * 0.2 / h as f32)
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Bruit de fond faible (pseudo-aléatoire déterministe)
+    fn noise(secs: f32) -> Vec<f32> {
+        let mut state = 12345u32;
+        (0..(secs * RATE as f32) as usize)
+            .map(|_| {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                ((state >> 16) as f32 / 32768.0 - 1.0) * 0.002
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_trim_silence() {
+        let mut audio = noise(1.0);
+        audio.extend(voice(1.0));
+        audio.extend(noise(1.5));
+
+        let trimmed = trim_silence(&audio, RATE);
+        let secs = trimmed.len() as f32 / RATE as f32;
+        assert!(secs > 1.0 && secs < 1.8, "trimmed to {}s", secs);
+    }
+
+    #[test]
+    fn test_speech_without_silence_is_kept() {
+        // Les trames les plus calmes sont de la parole : pas de plancher relatif
+        let audio = voice(1.0);
+        assert_eq!(trim_silence(&audio, RATE).len(), audio.len());
+
+        // Parole plus forte en fin d'enregistrement, sans pause
+        let mut audio = voice(1.0).into_iter().map(|s| s * 0.5).collect::<Vec<_>>();
+        audio.extend(voice(1.0));
+        assert_eq!(trim_silence(&audio, RATE).len(), audio.len());
+    }
+
+    #[test]
+    fn test_silence_only_is_empty() {
+        assert!(trim_silence(&noise(2.0), RATE).is_empty());
+    }
+
+    #[test]
+    fn test_split_on_pauses() {
+        let mut audio = noise(0.5);
+        audio.extend(voice(0.8));
+        audio.extend(noise(1.0));
+        audio.extend(voice(0.8));
+        audio.extend(noise(0.5));
+
+        assert_eq!(split_on_pauses(&audio, RATE, 0.5).len(), 2);
+        assert_eq!(split_on_pauses(&audio, RATE, 2.0).len(), 1);
+    }
+
+    #[test]
+    fn test_auto_stop_after_silence() {
+        let mut auto_stop = SilenceAutoStop::new(RATE, 1.0);
+        assert!(!auto_stop.push(&noise(2.0)));
+        assert!(!auto_stop.push(&voice(1.0)));
+        assert!(!auto_stop.push(&noise(0.5)));
+        assert!(auto_stop.push(&noise(1.0)));
+    }
+}
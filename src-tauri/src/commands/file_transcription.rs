@@ -1,16 +1,27 @@
-use crate::audio::AudioDecoder;
+use crate::audio::chunker::{self, Chunker};
+use crate::audio::{vad, AudioDecoder};
+use crate::engines::{confidence, segments};
+use crate::export;
+use crate::pipeline::{HistorySink, Pipeline, PipelineContext, SharedEngine, StatsSink};
 use crate::state::AppState;
-use crate::storage::history;
-use crate::types::TranscriptionResult;
+use crate::types::{ExportConfig, Segment, TranscriptionResult};
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::{AppHandle, Emitter, State};
 
+/// Taux d'échantillonnage attendu par les moteurs
+const TARGET_SAMPLE_RATE: u32 = 16000;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileTranscriptionResult {
     pub file_path: String,
     pub file_name: String,
     pub transcription: Option<TranscriptionResult>,
     pub error: Option<String>,
+    #[serde(default)]
+    pub exported_files: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -19,6 +30,136 @@ pub struct FileTranscriptionProgress {
     pub total: usize,
     pub file_name: String,
     pub status: String,
+    /// Avancement global du lot (0-100), mis à jour à chaque fenêtre transcrite
+    pub percent: f32,
+}
+
+/// Demande d'annulation de la transcription de fichiers en cours
+static FILE_TRANSCRIPTION_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+fn emit_progress(app: &AppHandle, index: usize, total: usize, file_name: &str, status: &str, file_percent: f32) {
+    let percent = if total == 0 {
+        100.0
+    } else {
+        (index as f32 + file_percent / 100.0) / total as f32 * 100.0
+    };
+
+    let _ = app.emit(
+        "file-transcription-progress",
+        FileTranscriptionProgress {
+            current: (index + 1).min(total),
+            total,
+            file_name: file_name.to_string(),
+            status: status.to_string(),
+            percent,
+        },
+    );
+}
+
+/// Transcrit un fichier fenêtre par fenêtre sans le décoder entièrement en mémoire
+fn transcribe_file_chunked(
+    app: &AppHandle,
+    engine: &SharedEngine,
+    path: &Path,
+    index: usize,
+    total: usize,
+    file_name: &str,
+    vad_enabled: bool,
+) -> Result<TranscriptionResult, String> {
+    let mut stream = AudioDecoder::open(path).map_err(|e| format!("Failed to decode: {}", e))?;
+    let total_seconds = stream.duration_seconds();
+    let mut chunker = Chunker::new(TARGET_SAMPLE_RATE);
+
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut detected_language = None;
+    let mut model_used = None;
+    let mut duration_seconds = 0.0f32;
+    let mut processing_time_ms = 0u64;
+    let mut confidences: Vec<f32> = Vec::new();
+
+    emit_progress(app, index, total, file_name, "transcribing", 0.0);
+
+    loop {
+        if FILE_TRANSCRIPTION_CANCELLED.load(Ordering::SeqCst) {
+            return Err("Cancelled".to_string());
+        }
+
+        let chunk = match chunker.next_chunk(|max| stream.read(max))? {
+            Some(chunk) => chunk,
+            None => break,
+        };
+
+        duration_seconds = chunk.end_seconds;
+
+        // Fenêtre sans parole : ne pas la transcrire (évite les hallucinations)
+        let speech = if vad_enabled {
+            vad::speech_bounds(&chunk.samples, TARGET_SAMPLE_RATE)
+        } else {
+            Some((0, chunk.samples.len()))
+        };
+
+        if let Some((speech_start, speech_end)) = speech {
+            let offset = chunk.start_seconds + speech_start as f32 / TARGET_SAMPLE_RATE as f32;
+            let samples = &chunk.samples[speech_start..speech_end];
+
+            let result = {
+                let engine_guard = engine.read().map_err(|e| e.to_string())?;
+                match *engine_guard {
+                    Some(ref engine) => engine.transcribe(samples, TARGET_SAMPLE_RATE)?,
+                    None => return Err("No engine initialized".to_string()),
+                }
+            };
+
+            let mut chunk_segments = result.segments;
+            if chunk_segments.is_empty() && !result.text.trim().is_empty() {
+                chunk_segments.push(Segment {
+                    start: 0.0,
+                    end: samples.len() as f32 / TARGET_SAMPLE_RATE as f32,
+                    text: result.text.trim().to_string(),
+                    words: Vec::new(),
+                });
+            }
+            segments::append_chunk_segments(&mut segments, chunk_segments, offset);
+
+            detected_language = detected_language.or(result.detected_language);
+            model_used = model_used.or(result.model_used);
+            processing_time_ms += result.processing_time_ms;
+            confidences.push(result.confidence);
+        }
+
+        let file_percent = chunker::progress_percent(chunk.end_seconds, total_seconds).unwrap_or(0.0);
+        emit_progress(app, index, total, file_name, "transcribing", file_percent);
+    }
+
+    let text = segments
+        .iter()
+        .map(|s| s.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // Sans probabilités par mot, moyenne des confiances de chaque fenêtre
+    let has_words = segments.iter().any(|s| !s.words.is_empty());
+    let confidence = if has_words {
+        confidence::overall_confidence(&segments)
+    } else if confidences.is_empty() {
+        0.0
+    } else {
+        confidences.iter().sum::<f32>() / confidences.len() as f32
+    };
+    let low_confidence_spans =
+        confidence::low_confidence_spans(&segments, confidence::LOW_CONFIDENCE_THRESHOLD);
+
+    Ok(TranscriptionResult {
+        text,
+        confidence,
+        duration_seconds,
+        processing_time_ms,
+        detected_language,
+        timestamp: Utc::now().timestamp(),
+        model_used,
+        segments,
+        low_confidence_spans,
+    })
 }
 
 /// Transcribe multiple audio files using the currently configured engine
@@ -28,6 +169,8 @@ pub async fn transcribe_files(
     state: State<'_, AppState>,
     paths: Vec<String>,
 ) -> Result<Vec<FileTranscriptionResult>, String> {
+    FILE_TRANSCRIPTION_CANCELLED.store(false, Ordering::SeqCst);
+
     let mut results = Vec::new();
     let total = paths.len();
 
@@ -39,16 +182,19 @@ pub async fn transcribe_files(
             .unwrap_or("unknown")
             .to_string();
 
+        if FILE_TRANSCRIPTION_CANCELLED.load(Ordering::SeqCst) {
+            results.push(FileTranscriptionResult {
+                file_path: path_str,
+                file_name,
+                transcription: None,
+                error: Some("Cancelled".to_string()),
+                exported_files: Vec::new(),
+            });
+            continue;
+        }
+
         // Emit progress: decoding
-        let _ = app.emit(
-            "file-transcription-progress",
-            FileTranscriptionProgress {
-                current: index + 1,
-                total,
-                file_name: file_name.clone(),
-                status: "decoding".to_string(),
-            },
-        );
+        emit_progress(&app, index, total, &file_name, "decoding", 0.0);
 
         // Check if format is supported
         if !AudioDecoder::is_supported(path) {
@@ -57,70 +203,64 @@ pub async fn transcribe_files(
                 file_name,
                 transcription: None,
                 error: Some("Unsupported audio format".to_string()),
+                exported_files: Vec::new(),
             });
             continue;
         }
 
-        // Decode audio
-        let (audio, sample_rate) = match AudioDecoder::decode_file(path) {
-            Ok(data) => data,
-            Err(e) => {
-                results.push(FileTranscriptionResult {
-                    file_path: path_str,
-                    file_name,
-                    transcription: None,
-                    error: Some(format!("Failed to decode: {}", e)),
-                });
-                continue;
-            }
-        };
-
-        // Emit progress: transcribing
-        let _ = app.emit(
-            "file-transcription-progress",
-            FileTranscriptionProgress {
-                current: index + 1,
-                total,
-                file_name: file_name.clone(),
-                status: "transcribing".to_string(),
-            },
-        );
-
-        // Transcribe using current engine
+        // Décodage et transcription par fenêtres hors du runtime async
+        let settings = state.settings.read().map_err(|e| e.to_string())?.clone();
         let transcription = {
-            let engine_guard = state.engine.read().map_err(|e| e.to_string())?;
-            if let Some(ref engine) = *engine_guard {
-                engine.transcribe(&audio, sample_rate)
-            } else {
-                Err("No engine initialized".to_string())
-            }
+            let app = app.clone();
+            let engine = state.engine.clone();
+            let path = path.to_path_buf();
+            let file_name = file_name.clone();
+            tokio::task::spawn_blocking(move || {
+                let result = transcribe_file_chunked(
+                    &app,
+                    &engine,
+                    &path,
+                    index,
+                    total,
+                    &file_name,
+                    settings.vad_enabled,
+                )?;
+
+                Pipeline::new()
+                    .file_post_processing()
+                    .sink(HistorySink)
+                    .sink(StatsSink)
+                    .run(PipelineContext::from_result(settings, result))
+            })
+            .await
+            .map_err(|e| format!("Transcription task failed: {}", e))?
         };
 
         match transcription {
             Ok(result) => {
-                // Save to history
-                if let Err(e) = history::add_transcription(result.clone()) {
-                    log::warn!("Failed to save transcription to history: {}", e);
-                }
-
-                // Record stats
-                {
-                    let settings = state.settings.read().map_err(|e| e.to_string())?;
-                    if settings.stats_tracking_enabled {
-                        let word_count = result.text.split_whitespace().count() as u64;
-                        let _ = crate::storage::stats::record_transcription(
-                            word_count,
-                            result.duration_seconds as f64,
-                            result.detected_language.as_deref(),
-                        );
+                // Export automatique (SRT, VTT, TXT, JSON)
+                let export_config = state.settings.read().map_err(|e| e.to_string())?.export.clone();
+                let exported_files = if export_config.auto_export {
+                    match export::export_transcription(&result, path, &export_config) {
+                        Ok(paths) => paths
+                            .into_iter()
+                            .map(|p| p.to_string_lossy().to_string())
+                            .collect(),
+                        Err(e) => {
+                            log::warn!("Failed to export transcription of {}: {}", file_name, e);
+                            Vec::new()
+                        }
                     }
-                }
+                } else {
+                    Vec::new()
+                };
 
                 results.push(FileTranscriptionResult {
                     file_path: path_str.clone(),
                     file_name,
                     transcription: Some(result),
                     error: None,
+                    exported_files,
                 });
             }
             Err(e) => {
@@ -129,25 +269,49 @@ pub async fn transcribe_files(
                     file_name,
                     transcription: None,
                     error: Some(e),
+                    exported_files: Vec::new(),
                 });
             }
         }
     }
 
     // Emit completion
-    let _ = app.emit(
-        "file-transcription-progress",
-        FileTranscriptionProgress {
-            current: total,
-            total,
-            file_name: String::new(),
-            status: "completed".to_string(),
-        },
-    );
+    let status = if FILE_TRANSCRIPTION_CANCELLED.load(Ordering::SeqCst) {
+        "cancelled"
+    } else {
+        "completed"
+    };
+    emit_progress(&app, total, total, "", status, 0.0);
 
     Ok(results)
 }
 
+/// Annule la transcription de fichiers en cours (après la fenêtre courante)
+#[tauri::command]
+pub fn cancel_file_transcription() {
+    FILE_TRANSCRIPTION_CANCELLED.store(true, Ordering::SeqCst);
+}
+
+/// Export a transcription next to its source file (or into the configured folder)
+#[tauri::command]
+pub fn export_transcription(
+    state: State<'_, AppState>,
+    transcription: TranscriptionResult,
+    source_path: String,
+    config: Option<ExportConfig>,
+) -> Result<Vec<String>, String> {
+    let config = match config {
+        Some(config) => config,
+        None => state.settings.read().map_err(|e| e.to_string())?.export.clone(),
+    };
+
+    let paths = export::export_transcription(&transcription, std::path::Path::new(&source_path), &config)?;
+    Ok(paths
+        .into_iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect())
+}
+
 /// Get list of supported audio formats
 #[tauri::command]
 pub fn get_supported_audio_formats() -> Vec<String> {
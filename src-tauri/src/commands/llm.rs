@@ -1,12 +1,16 @@
 use std::sync::Arc;
 use keyring::Entry;
-use tauri::{Emitter, State};
-use tokio::sync::RwLock;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, State};
 
 use crate::engines::ModelManager;
-use crate::llm::{groq_client, LocalLlmEngine};
+use crate::llm::local_engine::CompletionParams;
+use crate::llm::provider::{self, TokenCallback};
+use crate::llm::{
+    groq_client, openai_client, translator, ChatEndpoint, CompletionProvider, LlmWorker,
+};
 use crate::storage::config;
-use crate::types::{LlmProvider, LocalLlmModel};
+use crate::types::{LlmProvider, LocalLlmModel, OpenAiProviderConfig};
 
 const SERVICE_NAME: &str = "wakascribe";
 const ACCOUNT_NAME: &str = "groq_api_key";
@@ -49,6 +53,28 @@ pub fn get_groq_api_key_internal() -> Option<String> {
     entry.get_password().ok()
 }
 
+/// Point d'accès du provider cloud configuré : le serveur compatible OpenAI
+/// s'il est sélectionné, Groq sinon
+pub fn get_chat_endpoint_internal() -> Result<ChatEndpoint, String> {
+    let settings = config::load_settings();
+    match settings.llm_provider {
+        LlmProvider::OpenAi => Ok(ChatEndpoint::from_config(&settings.openai_provider)),
+        LlmProvider::Groq | LlmProvider::Local => get_groq_api_key_internal()
+            .map(|key| groq_client::endpoint(&key))
+            .ok_or_else(|| "No Groq API key configured".to_string()),
+    }
+}
+
+/// Provider des réglages pour toutes les fonctionnalités LLM : Groq,
+/// serveur compatible OpenAI ou LLM local
+pub fn get_llm_provider_internal() -> Result<Box<dyn CompletionProvider>, String> {
+    let settings = config::load_settings();
+    match settings.llm_provider {
+        LlmProvider::Local => Ok(Box::new(provider::local(settings.local_llm_model)?)),
+        LlmProvider::Groq | LlmProvider::OpenAi => Ok(Box::new(get_chat_endpoint_internal()?)),
+    }
+}
+
 /// Vérifie si une clé API Groq existe
 #[tauri::command]
 pub fn has_groq_api_key() -> bool {
@@ -98,17 +124,55 @@ pub fn delete_groq_api_key() -> Result<(), String> {
     Ok(())
 }
 
+/// Fragment de réponse diffusé pendant une génération (événement `llm-token`)
+#[derive(Debug, Clone, Serialize)]
+pub struct LlmToken {
+    /// Fonctionnalité à l'origine de la requête : "summary", "translation",
+    /// "post_processing" ou "voice_action"
+    pub source: &'static str,
+    pub token: String,
+}
+
+/// Requête du LLM local mise en file (événement `llm-request`), annulable
+/// avec `cancel_llm_request`
+#[derive(Debug, Clone, Serialize)]
+pub struct LlmRequestEvent {
+    pub source: &'static str,
+    pub request_id: u64,
+}
+
+/// Callback qui émet chaque fragment généré en événement `llm-token`
+pub fn llm_token_emitter(app: AppHandle, source: &'static str) -> TokenCallback {
+    Arc::new(move |token: &str| {
+        let _ = app.emit(
+            "llm-token",
+            LlmToken {
+                source,
+                token: token.to_string(),
+            },
+        );
+    })
+}
+
+/// Teste un serveur compatible OpenAI ; retourne la réponse du modèle
+#[tauri::command]
+pub async fn test_openai_provider(config: OpenAiProviderConfig) -> Result<String, String> {
+    openai_client::send_completion(&ChatEndpoint::from_config(&config), "Reply with OK", "test")
+        .await
+        .map_err(|e| e.to_string())
+}
+
 /// Récupère les informations de quota Groq
 #[tauri::command]
 pub fn get_groq_quota() -> Option<groq_client::GroqQuota> {
     groq_client::get_last_quota()
 }
 
-/// Résume un texte transcrit via Groq
+/// Résume un texte transcrit via le provider cloud (Groq ou compatible OpenAI)
 #[tauri::command]
-pub async fn summarize_text(text: String) -> Result<String, String> {
-    let api_key = get_groq_api_key_internal()
-        .ok_or_else(|| "Clé API Groq non configurée. Configurez-la dans les paramètres.".to_string())?;
+pub async fn summarize_text(app: AppHandle, text: String) -> Result<String, String> {
+    let endpoint = get_chat_endpoint_internal()
+        .map_err(|_| "Clé API Groq non configurée. Configurez-la dans les paramètres.".to_string())?;
 
     let system_prompt = r#"Tu es un assistant expert en analyse de transcriptions audio.
 
@@ -167,7 +231,11 @@ comme si tu racontais à quelqu'un qui n'a pas écouté l'audio.
 
     let user_message = format!("Voici la transcription à analyser :\n\n{}", text);
 
-    match groq_client::send_completion(&api_key, system_prompt, &user_message).await {
+    let on_token = llm_token_emitter(app, "summary");
+    match endpoint
+        .complete_streaming(system_prompt, &user_message, on_token)
+        .await
+    {
         Ok(summary) => {
             log::info!("Summarization successful: {} chars -> {} chars", text.len(), summary.len());
             Ok(summary.trim().to_string())
@@ -179,45 +247,19 @@ comme si tu racontais à quelqu'un qui n'a pas écouté l'audio.
     }
 }
 
-/// Traduit un texte vers une langue cible via Groq
+/// Traduit un texte vers une langue cible via le provider LLM
 #[tauri::command]
-pub async fn translate_text(text: String, target_language: String) -> Result<String, String> {
-    let api_key = get_groq_api_key_internal()
-        .ok_or_else(|| "No Groq API key configured".to_string())?;
-
-    let language_name = match target_language.as_str() {
-        "fr" => "French",
-        "en" => "English",
-        "de" => "German",
-        "es" => "Spanish",
-        "it" => "Italian",
-        "pt" => "Portuguese",
-        "nl" => "Dutch",
-        "ru" => "Russian",
-        "zh" => "Chinese",
-        "ja" => "Japanese",
-        "ko" => "Korean",
-        "ar" => "Arabic",
-        _ => &target_language,
-    };
-
-    let system_prompt = format!(
-        "You are a professional translator. Translate the following text to {}. \
-         Only output the translation, nothing else. Preserve the original formatting, \
-         punctuation and tone. If the text is already in {}, return it unchanged.",
-        language_name, language_name
-    );
+pub async fn translate_text(
+    app: AppHandle,
+    text: String,
+    target_language: String,
+) -> Result<String, String> {
+    let provider = get_llm_provider_internal()?;
+    let on_token = llm_token_emitter(app, "translation");
 
-    match groq_client::send_completion(&api_key, &system_prompt, &text).await {
-        Ok(translated) => {
-            log::info!("Translation successful: {} -> {}", text.len(), translated.len());
-            Ok(translated.trim().to_string())
-        }
-        Err(e) => {
-            log::error!("Translation failed: {}", e);
-            Err(format!("Translation failed: {}", e))
-        }
-    }
+    translator::translate(&text, &target_language, provider.as_ref(), Some(on_token))
+        .await
+        .inspect_err(|e| log::error!("{}", e))
 }
 
 // === LLM LOCAL (MISTRAL) ===
@@ -259,6 +301,7 @@ pub async fn download_llm_model(
                 println!("[LLM] Download progress: {}% ({}/{})", progress, downloaded, total);
             }
             let _ = app_clone.emit("llm-download-progress", serde_json::json!({
+                "id": model_size.file_name(),
                 "model": model_size,
                 "downloaded": downloaded,
                 "total": total,
@@ -285,48 +328,35 @@ pub async fn download_llm_model(
 #[tauri::command]
 pub async fn delete_llm_model(
     model_manager: State<'_, Arc<ModelManager>>,
+    llm_worker: State<'_, LlmWorker>,
     model_size: LocalLlmModel,
 ) -> Result<(), String> {
+    llm_worker.unload(model_size);
     model_manager.delete_llm_model(model_size).await
 }
 
 /// Résume un texte avec le modèle local Mistral
 #[tauri::command]
 pub async fn summarize_text_local(
-    model_manager: State<'_, Arc<ModelManager>>,
-    llm_engine: State<'_, Arc<RwLock<Option<LocalLlmEngine>>>>,
+    app: AppHandle,
+    llm_worker: State<'_, LlmWorker>,
     text: String,
 ) -> Result<String, String> {
     let settings = config::load_settings();
-
-    // Vérifier que le modèle est disponible
-    let model_path = model_manager
-        .get_llm_model_path(settings.local_llm_model)
-        .ok_or_else(|| format!(
-            "Modèle LLM {} non installé. Téléchargez-le dans les paramètres.",
-            settings.local_llm_model.display_name()
-        ))?;
-
-    // Charger le moteur si nécessaire
-    {
-        let engine_read = llm_engine.read().await;
-        if engine_read.is_none() {
-            drop(engine_read);
-            let mut engine_write = llm_engine.write().await;
-            if engine_write.is_none() {
-                log::info!("Initializing Local LLM engine...");
-                let engine = LocalLlmEngine::new(&model_path, settings.local_llm_model)?;
-                *engine_write = Some(engine);
-            }
-        }
-    }
-
-    // Effectuer le résumé
-    let engine_read = llm_engine.read().await;
-    let engine = engine_read.as_ref().ok_or("LLM engine not initialized")?;
+    let model = settings.local_llm_model;
 
     log::info!("Summarizing {} chars with local LLM", text.len());
-    let summary = engine.summarize(&text)?;
+    let prompt = format!("{}\n\n{}", model.summary_instruction(), text);
+    let on_token = llm_token_emitter(app.clone(), "summary");
+    let request = llm_worker.complete(model, "", &prompt, CompletionParams::default(), on_token)?;
+    let _ = app.emit(
+        "llm-request",
+        LlmRequestEvent {
+            source: "summary",
+            request_id: request.id,
+        },
+    );
+    let summary = request.response().await?;
     log::info!("Local summarization complete: {} chars", summary.len());
 
     Ok(summary)
@@ -335,8 +365,8 @@ pub async fn summarize_text_local(
 /// Résume un texte avec le provider configuré (auto-sélection local/cloud)
 #[tauri::command]
 pub async fn summarize_text_smart(
-    model_manager: State<'_, Arc<ModelManager>>,
-    llm_engine: State<'_, Arc<RwLock<Option<LocalLlmEngine>>>>,
+    app: AppHandle,
+    llm_worker: State<'_, LlmWorker>,
     text: String,
     provider: Option<LlmProvider>,
 ) -> Result<String, String> {
@@ -345,10 +375,22 @@ pub async fn summarize_text_smart(
 
     match use_provider {
         LlmProvider::Local => {
-            summarize_text_local(model_manager, llm_engine, text).await
+            summarize_text_local(app, llm_worker, text).await
         }
-        LlmProvider::Groq => {
-            summarize_text(text).await
+        LlmProvider::Groq | LlmProvider::OpenAi => {
+            summarize_text(app, text).await
         }
     }
 }
+
+/// Interrompt une requête du LLM local (identifiant reçu via `llm-request`)
+#[tauri::command]
+pub fn cancel_llm_request(llm_worker: State<'_, LlmWorker>, request_id: u64) {
+    llm_worker.cancel(request_id);
+}
+
+/// Interrompt la génération du LLM local en cours et toutes les requêtes en attente
+#[tauri::command]
+pub fn cancel_all_llm_requests(llm_worker: State<'_, LlmWorker>) {
+    llm_worker.cancel_all();
+}
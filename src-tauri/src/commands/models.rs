@@ -1,20 +1,15 @@
 use tauri::{AppHandle, Emitter, State};
+use crate::engines::model_manifest::ManifestEngine;
 use crate::state::AppState;
-use crate::types::{EngineType, ModelSize, ParakeetModelSize, VoskLanguage};
-use serde::{Deserialize, Serialize};
+use crate::types::{CustomModel, EngineType, ModelInfo, ModelSize, ParakeetModelSize, VoskLanguage};
+use serde::Serialize;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModelInfo {
-    pub size: ModelSize,
-    pub display_name: String,
-    pub available: bool,
-    pub size_bytes: u64,
-}
-
 #[derive(Debug, Clone, Serialize)]
 pub struct DownloadProgress {
+    /// Identifiant à passer à `pause_download` / `cancel_download`
+    pub id: String,
     pub downloaded: u64,
     pub total: u64,
     pub percent: f32,
@@ -22,15 +17,7 @@ pub struct DownloadProgress {
 
 #[tauri::command]
 pub fn get_available_models(state: State<'_, AppState>) -> Vec<ModelInfo> {
-    [ModelSize::Tiny, ModelSize::Small, ModelSize::Medium]
-        .into_iter()
-        .map(|size| ModelInfo {
-            size,
-            display_name: size.display_name().to_string(),
-            available: state.model_manager.is_model_available(size),
-            size_bytes: size.size_bytes(),
-        })
-        .collect()
+    state.model_manager.available_models()
 }
 
 #[tauri::command]
@@ -58,6 +45,7 @@ pub async fn download_model(
         total_clone.store(t, Ordering::SeqCst);
 
         let progress = DownloadProgress {
+            id: size.file_name().to_string(),
             downloaded: dl,
             total: t,
             percent: (dl as f32 / t as f32) * 100.0,
@@ -80,8 +68,29 @@ pub async fn delete_model(state: State<'_, AppState>, size: ModelSize) -> Result
     state.model_manager.delete_model(size).await
 }
 
+/// Met en pause un téléchargement ; relancer le téléchargement le reprend
+#[tauri::command]
+pub fn pause_download(state: State<'_, AppState>, id: String) -> Result<(), String> {
+    state.model_manager.pause_download(&id)
+}
+
+/// Annule un téléchargement et supprime les données partielles
 #[tauri::command]
-pub fn switch_model(state: State<'_, AppState>, size: ModelSize) -> Result<(), String> {
+pub fn cancel_download(state: State<'_, AppState>, id: String) -> Result<(), String> {
+    state.model_manager.cancel_download(&id)
+}
+
+/// Sélectionne un modèle Whisper intégré, ou le modèle importé `custom_id`
+#[tauri::command]
+pub fn switch_model(
+    state: State<'_, AppState>,
+    size: ModelSize,
+    custom_id: Option<String>,
+) -> Result<(), String> {
+    if let Some(id) = custom_id {
+        return select_custom_model(&state, id);
+    }
+
     if !state.model_manager.is_model_available(size) {
         return Err(format!("Model {:?} is not available. Please download it first.", size));
     }
@@ -94,9 +103,11 @@ pub fn switch_model(state: State<'_, AppState>, size: ModelSize) -> Result<(), S
     };
     drop(settings);
 
-    state.reload_engine(size, language)?;
+    // Le modèle importé reste sélectionné si le chargement échoue
+    state.load_whisper_model(size, language)?;
 
     let mut settings = state.settings.write().map_err(|e| e.to_string())?;
+    settings.custom_model = None;
     settings.whisper_model = size;
     drop(settings);
 
@@ -106,6 +117,72 @@ pub fn switch_model(state: State<'_, AppState>, size: ModelSize) -> Result<(), S
     Ok(())
 }
 
+fn select_custom_model(state: &AppState, id: String) -> Result<(), String> {
+    let model = state
+        .model_manager
+        .custom_model(&id)
+        .ok_or_else(|| format!("Imported model '{}' not found", id))?;
+
+    state.load_custom_model(&model)?;
+
+    let mut settings = state.settings.write().map_err(|e| e.to_string())?;
+    settings.custom_model = Some(id);
+    settings.engine_type = model.engine;
+    crate::storage::config::save_settings(&settings)
+}
+
+// ===== Imported Model Commands =====
+
+#[tauri::command]
+pub fn get_custom_models(state: State<'_, AppState>) -> Vec<CustomModel> {
+    state.model_manager.custom_models()
+}
+
+/// Vérifie et enregistre un modèle local (fichier ggml, dossier ONNX ou Vosk).
+/// `language` (code ISO 639-1) est requis pour un modèle Vosk.
+#[tauri::command]
+pub fn import_model(
+    state: State<'_, AppState>,
+    path: String,
+    name: String,
+    language: Option<String>,
+) -> Result<CustomModel, String> {
+    let language = match language {
+        Some(code) => Some(
+            VoskLanguage::from_language_code(&code)
+                .ok_or_else(|| format!("Unsupported Vosk language: {}", code))?,
+        ),
+        None => None,
+    };
+
+    state
+        .model_manager
+        .import_model(std::path::Path::new(&path), &name, language)
+}
+
+/// Retire un modèle importé ; s'il était utilisé, le modèle intégré de son
+/// moteur est rechargé
+#[tauri::command]
+pub fn remove_custom_model(state: State<'_, AppState>, id: String) -> Result<(), String> {
+    let mut settings = state.settings.write().map_err(|e| e.to_string())?;
+    let was_selected = settings.custom_model.as_deref() == Some(id.as_str());
+    if was_selected {
+        settings.custom_model = None;
+        crate::storage::config::save_settings(&settings)?;
+    }
+    let engine_type = settings.engine_type;
+    drop(settings);
+
+    state.model_manager.remove_custom_model(&id)?;
+
+    if was_selected {
+        if let Err(e) = state.switch_engine_type(engine_type) {
+            log::warn!("Failed to reload built-in model: {}", e);
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub fn is_engine_ready(state: State<'_, AppState>) -> bool {
     state.engine.read().map(|e| e.is_some()).unwrap_or(false)
@@ -122,9 +199,7 @@ pub struct VoskModelInfo {
 
 #[tauri::command]
 pub fn get_vosk_models(state: State<'_, AppState>) -> Vec<VoskModelInfo> {
-    use VoskLanguage::*;
-
-    [En, Fr, De, Es, It, Ru, Zh, Ja, Ko, Pt, Nl, Pl, Uk, Tr, Vi, Ar, Hi, Fa, Ca, Cs]
+    VoskLanguage::ALL
         .into_iter()
         .map(|lang| VoskModelInfo {
             language: lang,
@@ -153,6 +228,7 @@ pub async fn download_vosk_model(
         total_clone.store(t, Ordering::SeqCst);
 
         let progress = DownloadProgress {
+            id: language.model_name().to_string(),
             downloaded: dl,
             total: t,
             percent: (dl as f32 / t as f32) * 100.0,
@@ -176,9 +252,10 @@ pub fn select_vosk_language(state: State<'_, AppState>, language: VoskLanguage)
         return Err(format!("Vosk model for {:?} is not available. Please download it first.", language));
     }
 
-    state.reload_vosk_engine(language)?;
+    state.load_vosk_model(language)?;
 
     let mut settings = state.settings.write().map_err(|e| e.to_string())?;
+    settings.custom_model = None;
     settings.vosk_language = Some(language);
     settings.engine_type = EngineType::Vosk;
     drop(settings);
@@ -231,14 +308,13 @@ pub struct ParakeetModelInfo {
 
 #[tauri::command]
 pub fn get_parakeet_models(state: State<'_, AppState>) -> Vec<ParakeetModelInfo> {
-    vec![
-        ParakeetModelInfo {
-            size: ParakeetModelSize::Tdt06bV3,
-            display_name: "Parakeet TDT 0.6B v3".to_string(),
-            available: state.model_manager.get_parakeet_model_path(ParakeetModelSize::Tdt06bV3).is_some(),
-            size_bytes: 1_200_000_000, // ~1.2 GB
-        },
-    ]
+    let size = ParakeetModelSize::Tdt06bV3;
+    vec![ParakeetModelInfo {
+        size,
+        display_name: size.display_name().to_string(),
+        available: state.model_manager.get_parakeet_model_path(size).is_some(),
+        size_bytes: size.size_bytes(),
+    }]
 }
 
 #[tauri::command]
@@ -261,6 +337,7 @@ pub async fn download_parakeet_model(
         total_clone.store(t, Ordering::SeqCst);
 
         let progress = DownloadProgress {
+            id: size.model_name().to_string(),
             downloaded: dl,
             total: t,
             percent: (dl as f32 / t as f32) * 100.0,
@@ -300,9 +377,10 @@ pub fn select_parakeet_model(state: State<'_, AppState>, size: ParakeetModelSize
         return Err(format!("Parakeet model {:?} is not available. Please download it first.", size));
     }
 
-    state.reload_parakeet_engine(size)?;
+    state.load_parakeet_model(size)?;
 
     let mut settings = state.settings.write().map_err(|e| e.to_string())?;
+    settings.custom_model = None;
     settings.parakeet_model = size;
     settings.engine_type = EngineType::Parakeet;
     drop(settings);
@@ -312,3 +390,82 @@ pub fn select_parakeet_model(state: State<'_, AppState>, size: ParakeetModelSize
 
     Ok(())
 }
+
+// ===== Catalogue du manifeste =====
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CatalogModelInfo {
+    pub id: String,
+    pub engine: ManifestEngine,
+    pub display_name: String,
+    pub license: String,
+    pub languages: Vec<String>,
+    pub size_bytes: u64,
+    pub installed: bool,
+}
+
+/// Modèles proposés par le manifeste en plus des modèles intégrés
+#[tauri::command]
+pub fn get_catalog_models(state: State<'_, AppState>) -> Vec<CatalogModelInfo> {
+    state
+        .model_manager
+        .catalog_models()
+        .into_iter()
+        .map(|(model, installed)| CatalogModelInfo {
+            id: model.id,
+            engine: model.engine,
+            display_name: model.display_name,
+            license: model.license,
+            languages: model.languages,
+            size_bytes: model.size_bytes,
+            installed,
+        })
+        .collect()
+}
+
+/// Télécharge un modèle du catalogue ; il devient un modèle importé
+#[tauri::command]
+pub async fn download_catalog_model(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    id: String,
+) -> Result<CustomModel, String> {
+    let model_manager = state.model_manager.clone();
+    let app_clone = app.clone();
+    let progress_id = model_manager
+        .catalog_models()
+        .into_iter()
+        .find(|(model, _)| model.id == id)
+        .map(|(model, _)| model.install_name)
+        .unwrap_or_else(|| id.clone());
+
+    let progress_callback = move |dl: u64, t: u64| {
+        let progress = DownloadProgress {
+            id: progress_id.clone(),
+            downloaded: dl,
+            total: t,
+            percent: (dl as f32 / t.max(1) as f32) * 100.0,
+        };
+        let _ = app_clone.emit("catalog-download-progress", progress);
+    };
+
+    let model = model_manager
+        .download_catalog_model(&id, progress_callback)
+        .await?;
+
+    let _ = app.emit("catalog-download-complete", &model);
+    Ok(model)
+}
+
+/// Recharge le manifeste distant configuré dans les réglages
+#[tauri::command]
+pub async fn refresh_model_manifest(state: State<'_, AppState>) -> Result<(), String> {
+    let url = state
+        .settings
+        .read()
+        .map_err(|e| e.to_string())?
+        .model_manifest_url
+        .clone()
+        .ok_or("No model manifest URL configured")?;
+    state.model_manager.refresh_manifest(&url).await
+}
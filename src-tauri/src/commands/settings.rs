@@ -1,9 +1,10 @@
 use tauri::{AppHandle, State};
 use tauri_plugin_global_shortcut::GlobalShortcutExt;
 use crate::hotkeys::parse_hotkey;
+use crate::llm::LlmWorker;
 use crate::state::AppState;
 use crate::storage::{config, dictionary};
-use crate::types::AppSettings;
+use crate::types::{AppSettings, EngineType, LlmProvider};
 
 #[tauri::command]
 pub fn get_settings(state: State<'_, AppState>) -> Result<AppSettings, String> {
@@ -15,13 +16,24 @@ pub fn get_settings(state: State<'_, AppState>) -> Result<AppSettings, String> {
 pub fn update_settings(
     app: AppHandle,
     state: State<'_, AppState>,
+    llm_worker: State<'_, LlmWorker>,
     new_settings: AppSettings
 ) -> Result<(), String> {
     let old_settings = state.settings.read().map_err(|e| e.to_string())?.clone();
     let ptt_hotkey_changed = old_settings.hotkey_push_to_talk != new_settings.hotkey_push_to_talk;
+    let toggle_hotkey_changed = old_settings.hotkey_toggle_record != new_settings.hotkey_toggle_record;
     let translate_hotkey_changed = old_settings.hotkey_translate != new_settings.hotkey_translate;
+    let translate_dictation_hotkey_changed =
+        old_settings.hotkey_translate_dictation != new_settings.hotkey_translate_dictation;
     let translation_enabled_changed = old_settings.translation_enabled != new_settings.translation_enabled;
     let engine_type_changed = old_settings.engine_type != new_settings.engine_type;
+    let parakeet_beam_changed = old_settings.parakeet_beam_width != new_settings.parakeet_beam_width;
+    let whisper_decode_changed = old_settings.whisper_decode != new_settings.whisper_decode;
+    let language_changed = old_settings.transcription_language != new_settings.transcription_language
+        || old_settings.auto_detect_language != new_settings.auto_detect_language;
+    let local_llm_selected = new_settings.llm_provider == LlmProvider::Local
+        && (old_settings.llm_provider != LlmProvider::Local
+            || old_settings.local_llm_model != new_settings.local_llm_model);
 
     config::save_settings(&new_settings)?;
 
@@ -34,6 +46,25 @@ pub fn update_settings(
         if let Err(e) = state.switch_engine_type(new_settings.engine_type) {
             log::warn!("Failed to switch engine type: {}. Model may need to be downloaded first.", e);
         }
+    } else if parakeet_beam_changed && new_settings.engine_type == EngineType::Parakeet {
+        if let Err(e) = state.reload_parakeet_engine(new_settings.parakeet_model) {
+            log::warn!("Failed to apply Parakeet decoding settings: {}", e);
+        }
+    } else if whisper_decode_changed {
+        if let Err(e) = state.apply_whisper_decode_options() {
+            log::warn!("Failed to apply Whisper decoding settings: {}", e);
+        }
+    }
+
+    if language_changed && !engine_type_changed {
+        if let Err(e) = state.apply_language() {
+            log::warn!("Failed to apply transcription language: {}", e);
+        }
+    }
+
+    llm_worker.set_idle_timeout(new_settings.llm_idle_timeout_minutes);
+    if local_llm_selected {
+        llm_worker.preload(new_settings.local_llm_model);
     }
 
     if ptt_hotkey_changed {
@@ -42,6 +73,13 @@ pub fn update_settings(
         }
     }
 
+    if toggle_hotkey_changed {
+        if let Err(e) = update_shortcut(&app, &old_settings.hotkey_toggle_record, &new_settings.hotkey_toggle_record) {
+            log::warn!("Failed to update toggle shortcut: {}. Restart may be required.", e);
+        }
+        crate::ptt::set_toggle_shortcut(parse_hotkey(&new_settings.hotkey_toggle_record));
+    }
+
     if translation_enabled_changed || translate_hotkey_changed {
         if old_settings.translation_enabled {
             if let Some(old_shortcut) = parse_hotkey(&old_settings.hotkey_translate) {
@@ -59,6 +97,27 @@ pub fn update_settings(
         }
     }
 
+    if translation_enabled_changed || translate_dictation_hotkey_changed {
+        if old_settings.translation_enabled {
+            if let Some(old_shortcut) = parse_hotkey(&old_settings.hotkey_translate_dictation) {
+                let _ = app.global_shortcut().unregister(old_shortcut);
+            }
+        }
+        let new_shortcut = parse_hotkey(&new_settings.hotkey_translate_dictation)
+            .filter(|_| new_settings.translation_enabled);
+        if let Some(shortcut) = new_shortcut {
+            if let Err(e) = app.global_shortcut().register(shortcut) {
+                log::warn!("Failed to register translated dictation shortcut: {}", e);
+            } else {
+                log::info!(
+                    "Translated dictation shortcut registered: {}",
+                    new_settings.hotkey_translate_dictation
+                );
+            }
+        }
+        crate::ptt::set_translate_dictation_shortcut(new_shortcut);
+    }
+
     Ok(())
 }
 
@@ -72,7 +131,7 @@ fn update_shortcut(app: &AppHandle, old_hotkey: &str, new_hotkey: &str) -> Resul
         app.global_shortcut()
             .register(new_shortcut)
             .map_err(|e| format!("Failed to register new shortcut: {}", e))?;
-        log::info!("PTT shortcut updated to: {}", new_hotkey);
+        log::info!("Shortcut updated to: {}", new_hotkey);
     } else {
         return Err(format!("Invalid hotkey format: {}", new_hotkey));
     }
@@ -86,11 +145,13 @@ pub fn get_dictionary() -> Result<Vec<String>, String> {
 }
 
 #[tauri::command]
-pub fn add_dictionary_word(word: String) -> Result<(), String> {
-    dictionary::add_word(word)
+pub fn add_dictionary_word(state: State<'_, AppState>, word: String) -> Result<(), String> {
+    dictionary::add_word(word)?;
+    state.refresh_vocabulary()
 }
 
 #[tauri::command]
-pub fn remove_dictionary_word(word: String) -> Result<(), String> {
-    dictionary::remove_word(&word)
+pub fn remove_dictionary_word(state: State<'_, AppState>, word: String) -> Result<(), String> {
+    dictionary::remove_word(&word)?;
+    state.refresh_vocabulary()
 }
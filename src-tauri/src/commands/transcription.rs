@@ -2,23 +2,23 @@ use std::sync::{mpsc, Arc, Mutex, RwLock};
 use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::{AppHandle, Emitter, State};
 use serde::Serialize;
-use crate::engines::SpeechEngine;
+use crate::commands::llm::llm_token_emitter;
+use crate::engines::{LiveSession, SharedEngine};
 use crate::state::AppState;
 use crate::storage::history;
 use crate::types::TranscriptionResult;
-use crate::audio::AudioCapture;
-use crate::voice_commands;
-use crate::llm;
+use crate::audio::{AudioCapture, StreamingUpdate};
+use crate::pipeline::{ActionsSink, EventSink, HistorySink, Pipeline, PipelineContext, StatsSink};
 
-/// Taux d'échantillonnage requis par Whisper
-const TARGET_SAMPLE_RATE: u32 = 16000;
-
-/// Durée d'un chunk en secondes pour le streaming
-const STREAMING_CHUNK_DURATION_SECS: f32 = 2.5;
+/// Intervalle entre deux passes de transcription en streaming
+const STREAMING_INTERVAL_MS: u64 = 1000;
 
 /// État global pour le streaming
 static STREAMING_ACTIVE: AtomicBool = AtomicBool::new(false);
 
+/// Session de streaming de l'enregistrement en cours, terminée par `stop_recording`
+static LIVE_SESSION: Mutex<Option<LiveSession>> = Mutex::new(None);
+
 /// Buffer audio partagé pour le streaming (clone du buffer interne pendant l'enregistrement)
 static STREAMING_BUFFER: Mutex<Option<Arc<RwLock<Vec<f32>>>>> = Mutex::new(None);
 static STREAMING_SAMPLE_RATE: Mutex<u32> = Mutex::new(16000);
@@ -57,11 +57,37 @@ fn emit_recording_status(app: &AppHandle, status: &str) {
     let _ = app.emit("recording-status", status);
 }
 
+/// Payload des événements `transcription-committed` et `transcription-partial`
+#[derive(Clone, Serialize)]
+pub struct StreamingTextEvent {
+    /// Mots nouvellement validés, ou fin provisoire de l'hypothèse
+    pub text: String,
+    /// Tout le texte validé depuis le début de l'enregistrement
+    pub committed_text: String,
+    pub duration_seconds: f32,
+}
+
 /// Émet un chunk de transcription streaming
 fn emit_streaming_chunk(app: &AppHandle, chunk: StreamingChunkEvent) {
     let _ = app.emit("transcription-chunk", chunk);
 }
 
+/// Émet les mots validés (définitifs) puis la partie provisoire de l'hypothèse
+pub fn emit_streaming_update(app: &AppHandle, update: &StreamingUpdate, committed_text: &str, duration_seconds: f32) {
+    if !update.committed.is_empty() {
+        let _ = app.emit("transcription-committed", StreamingTextEvent {
+            text: update.committed.clone(),
+            committed_text: committed_text.to_string(),
+            duration_seconds,
+        });
+    }
+    let _ = app.emit("transcription-partial", StreamingTextEvent {
+        text: update.partial.clone(),
+        committed_text: committed_text.to_string(),
+        duration_seconds,
+    });
+}
+
 /// Initialise le thread audio dédié pour les commandes de transcription GUI
 pub fn init_gui_audio_thread() {
     let (cmd_tx, cmd_rx) = mpsc::channel::<AudioCommand>();
@@ -181,6 +207,9 @@ pub fn start_recording(app: AppHandle, state: State<'_, AppState>) -> Result<(),
 
     *is_recording = true;
     STREAMING_ACTIVE.store(streaming_enabled, Ordering::SeqCst);
+    if let Ok(mut live_session) = LIVE_SESSION.lock() {
+        *live_session = None;
+    }
 
     // Émettre le statut d'enregistrement
     emit_recording_status(&app, "recording");
@@ -198,16 +227,17 @@ pub fn start_recording(app: AppHandle, state: State<'_, AppState>) -> Result<(),
     Ok(())
 }
 
-/// Tâche de streaming qui transcrit l'audio en temps réel
-fn run_streaming_task(app: AppHandle, state: Arc<RwLock<Option<Box<dyn SpeechEngine>>>>) {
+/// Tâche de streaming qui transcrit l'audio en temps réel.
+/// L'audio est transmis à une session de streaming du moteur ; la session est
+/// reprise par `stop_recording` pour produire la transcription finale.
+fn run_streaming_task(app: AppHandle, engine: SharedEngine) {
     log::info!("Streaming task started with real-time transcription");
 
     let start_time = std::time::Instant::now();
-    let mut last_processed_samples: usize = 0;
-    let chunk_samples = (STREAMING_CHUNK_DURATION_SECS * TARGET_SAMPLE_RATE as f32) as usize;
+    let vad_enabled = crate::storage::config::load_settings().vad_enabled;
 
     while STREAMING_ACTIVE.load(Ordering::SeqCst) {
-        std::thread::sleep(std::time::Duration::from_millis(500));
+        std::thread::sleep(std::time::Duration::from_millis(STREAMING_INTERVAL_MS));
 
         if !STREAMING_ACTIVE.load(Ordering::SeqCst) {
             break;
@@ -232,52 +262,44 @@ fn run_streaming_task(app: AppHandle, state: Arc<RwLock<Option<Box<dyn SpeechEng
         if let Some((audio, sample_rate)) = snapshot {
             let elapsed = start_time.elapsed().as_secs_f32();
 
-            // Calculer combien de nouveaux échantillons nous avons
-            let current_samples = audio.len();
-            let new_samples = current_samples.saturating_sub(last_processed_samples);
+            let mut live_session = match LIVE_SESSION.lock() {
+                Ok(guard) => guard,
+                Err(_) => break,
+            };
 
-            // Si nous avons assez de nouveaux échantillons pour un chunk
-            if new_samples >= chunk_samples && current_samples >= chunk_samples {
-                // Prendre les derniers samples pour la transcription partielle
-                let chunk_start = current_samples.saturating_sub(chunk_samples);
-                let chunk_audio = &audio[chunk_start..];
+            // La session a pu être reprise par stop_recording entre-temps
+            if !STREAMING_ACTIVE.load(Ordering::SeqCst) {
+                break;
+            }
 
-                // Resampling si nécessaire
-                let resampled = if sample_rate != TARGET_SAMPLE_RATE {
-                    resample_audio(chunk_audio, sample_rate, TARGET_SAMPLE_RATE)
-                } else {
-                    chunk_audio.to_vec()
-                };
-
-                // Transcrire le chunk
-                if let Ok(engine_guard) = state.read() {
-                    if let Some(ref engine) = *engine_guard {
-                        match engine.transcribe(&resampled, TARGET_SAMPLE_RATE) {
-                            Ok(result) => {
-                                if !result.text.trim().is_empty() {
-                                    log::info!("Streaming chunk: '{}'", result.text);
-                                    emit_streaming_chunk(&app, StreamingChunkEvent {
-                                        text: result.text,
-                                        is_final: false,
-                                        duration_seconds: elapsed,
-                                    });
-                                }
-                            }
-                            Err(e) => {
-                                log::warn!("Streaming transcription error: {}", e);
-                            }
-                        }
+            if live_session.is_none() {
+                match LiveSession::start(&engine, sample_rate, vad_enabled) {
+                    Ok(session) => *live_session = Some(session),
+                    Err(e) => {
+                        log::warn!("Streaming session unavailable: {}", e);
+                        break;
                     }
                 }
+            }
 
-                last_processed_samples = current_samples;
-            } else {
-                // Émettre juste la durée pour indiquer que le streaming est actif
-                emit_streaming_chunk(&app, StreamingChunkEvent {
-                    text: String::new(),
-                    is_final: false,
-                    duration_seconds: elapsed,
-                });
+            let session = match live_session.as_mut() {
+                Some(session) => session,
+                None => break,
+            };
+
+            match session.feed(&audio).and_then(|_| session.poll()) {
+                Ok(Some(update)) => {
+                    emit_streaming_update(&app, &update, &session.committed_text(), elapsed);
+                }
+                Ok(None) => {
+                    // Émettre juste la durée pour indiquer que le streaming est actif
+                    emit_streaming_chunk(&app, StreamingChunkEvent {
+                        text: String::new(),
+                        is_final: false,
+                        duration_seconds: elapsed,
+                    });
+                }
+                Err(e) => log::warn!("Streaming transcription error: {}", e),
             }
         }
     }
@@ -348,101 +370,32 @@ pub async fn stop_recording(app: AppHandle, state: State<'_, AppState>) -> Resul
 
     log::info!("Audio received: {:.1}s at {}Hz", duration_seconds, sample_rate);
 
-    // Resampling si nécessaire
-    let resampled_audio = if sample_rate != TARGET_SAMPLE_RATE {
-        log::info!("Resampling audio from {}Hz to {}Hz", sample_rate, TARGET_SAMPLE_RATE);
-        resample_audio(&audio_buffer, sample_rate, TARGET_SAMPLE_RATE)
-    } else {
-        audio_buffer
+    // Transcription et post-traitement (pipeline commun avec le push-to-talk)
+    // La session de streaming a déjà décodé l'essentiel de l'enregistrement
+    let settings = state.settings.read().map_err(|e| e.to_string())?.clone();
+    let live_session = LIVE_SESSION.lock().ok().and_then(|mut session| session.take());
+    let pipeline = match live_session {
+        Some(session) => Pipeline::streamed_dictation(session),
+        None => Pipeline::dictation(state.engine.clone()),
     };
-
-    // Transcription
-    let result = {
-        let engine_guard = state.engine.read().map_err(|e| e.to_string())?;
-        let engine = engine_guard
-            .as_ref()
-            .ok_or("Whisper engine not initialized. Please download a model first.")?;
-        engine.transcribe(&resampled_audio, TARGET_SAMPLE_RATE)?
-    };
-
-    // Lire les settings pour le post-processing
-    let (voice_commands_enabled, dictation_mode, llm_enabled, llm_mode, system_commands_enabled) = {
-        let settings = state.settings.read().map_err(|e| e.to_string())?;
-        (
-            settings.voice_commands_enabled,
-            settings.dictation_mode,
-            settings.llm_enabled,
-            settings.llm_mode,
-            settings.system_commands_enabled,
-        )
-    };
-
-    // Post-traitement
-    let mut final_text = result.text.clone();
-
-    // Voice commands
-    if voice_commands_enabled {
-        let snippets = crate::storage::snippets::load_snippets().snippets;
-        let parse_result = voice_commands::parse(&final_text, dictation_mode, &snippets, system_commands_enabled);
-        final_text = parse_result.text;
-        if !parse_result.actions.is_empty() {
-            log::info!("Voice commands detected: {:?}", parse_result.actions);
-            voice_commands::execute_actions(&parse_result.actions, &snippets);
-        }
-    }
-
-    // LLM post-processing
-    if llm_enabled {
-        if let Some(api_key) = super::llm::get_groq_api_key_internal() {
-            match llm::process(&final_text, llm_mode, dictation_mode, &api_key).await {
-                Ok(processed) => {
-                    log::info!("LLM processing successful");
-                    final_text = processed;
-                }
-                Err(e) => {
-                    log::warn!("LLM processing failed: {}", e);
-                }
-            }
-        } else {
-            log::warn!("LLM enabled but no API key found");
-        }
-    }
-
-    // Créer le résultat final
-    let final_result = TranscriptionResult {
-        text: final_text.clone(),
-        confidence: result.confidence,
-        duration_seconds: result.duration_seconds,
-        processing_time_ms: result.processing_time_ms,
-        detected_language: result.detected_language,
-        timestamp: result.timestamp,
-        model_used: result.model_used,
+    let pipeline = pipeline
+        .sink(EventSink::new(app.clone()))
+        .sink(ActionsSink)
+        .sink(HistorySink)
+        .sink(StatsSink);
+    let mut context = PipelineContext::from_audio(settings, audio_buffer, sample_rate);
+    context.llm_tokens = Some(llm_token_emitter(app.clone(), "post_processing"));
+
+    let result = match tokio::task::spawn_blocking(move || pipeline.run(context)).await {
+        Ok(result) => result,
+        Err(e) => Err(format!("Transcription task failed: {}", e)),
     };
 
-    // Émettre le chunk final
-    emit_streaming_chunk(&app, StreamingChunkEvent {
-        text: final_text,
-        is_final: true,
-        duration_seconds,
-    });
-
     // Émettre le statut "idle"
     emit_recording_status(&app, "idle");
 
-    history::add_transcription(final_result.clone())?;
-
-    // Record stats
-    if state.settings.read().map(|s| s.stats_tracking_enabled).unwrap_or(true) {
-        let word_count = final_result.text.split_whitespace().count() as u64;
-        let _ = crate::storage::stats::record_transcription(
-            word_count,
-            duration_seconds as f64,
-            final_result.detected_language.as_deref(),
-        );
-    }
-
     log::info!("Recording stopped, duration: {:.1}s", duration_seconds);
-    Ok(final_result)
+    result
 }
 
 #[tauri::command]
@@ -479,7 +432,3 @@ pub fn reset_recording_state(app: AppHandle, state: State<'_, AppState>) -> Resu
     log::info!("Recording state reset complete");
     Ok(())
 }
-
-fn resample_audio(input: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
-    crate::audio::resampling::resample_audio(input, from_rate, to_rate)
-}
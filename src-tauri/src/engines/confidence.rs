@@ -0,0 +1,99 @@
+//! Scores de confiance calculés à partir des probabilités par mot
+
+use crate::types::{ConfidenceSpan, Segment};
+
+/// Probabilité en dessous de laquelle un mot est signalé à l'utilisateur
+pub const LOW_CONFIDENCE_THRESHOLD: f32 = 0.5;
+
+/// Confiance globale : moyenne des probabilités de tous les mots
+pub fn overall_confidence(segments: &[Segment]) -> f32 {
+    let (sum, count) = segments
+        .iter()
+        .flat_map(|s| s.words.iter())
+        .fold((0.0f32, 0usize), |(sum, count), w| (sum + w.probability, count + 1));
+
+    if count == 0 {
+        0.0
+    } else {
+        (sum / count as f32).clamp(0.0, 1.0)
+    }
+}
+
+/// Regroupe les mots consécutifs peu sûrs en plages à vérifier
+pub fn low_confidence_spans(segments: &[Segment], threshold: f32) -> Vec<ConfidenceSpan> {
+    let mut spans: Vec<ConfidenceSpan> = Vec::new();
+
+    for segment in segments {
+        let mut current: Option<ConfidenceSpan> = None;
+
+        for word in &segment.words {
+            if word.probability < threshold {
+                match current.as_mut() {
+                    Some(span) => {
+                        span.text.push(' ');
+                        span.text.push_str(&word.word);
+                        span.end = word.end;
+                        span.probability = span.probability.min(word.probability);
+                    }
+                    None => {
+                        current = Some(ConfidenceSpan {
+                            start: word.start,
+                            end: word.end,
+                            text: word.word.clone(),
+                            probability: word.probability,
+                        });
+                    }
+                }
+            } else if let Some(span) = current.take() {
+                spans.push(span);
+            }
+        }
+
+        spans.extend(current);
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::WordTiming;
+
+    fn word(text: &str, start: f32, probability: f32) -> WordTiming {
+        WordTiming {
+            word: text.to_string(),
+            start,
+            end: start + 0.3,
+            probability,
+        }
+    }
+
+    #[test]
+    fn test_spans_merge_consecutive_words() {
+        let segments = vec![Segment {
+            start: 0.0,
+            end: 1.5,
+            text: "le projet fonon avance bien".to_string(),
+            words: vec![
+                word("le", 0.0, 0.9),
+                word("projet", 0.3, 0.95),
+                word("fonon", 0.6, 0.2),
+                word("avance", 0.9, 0.4),
+                word("bien", 1.2, 0.9),
+            ],
+        }];
+
+        let spans = low_confidence_spans(&segments, LOW_CONFIDENCE_THRESHOLD);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].text, "fonon avance");
+        assert_eq!(spans[0].probability, 0.2);
+        assert!((overall_confidence(&segments) - 0.67).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_no_words_means_no_confidence() {
+        assert_eq!(overall_confidence(&[]), 0.0);
+        assert!(low_confidence_spans(&[], LOW_CONFIDENCE_THRESHOLD).is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+//! Téléchargement des modèles
+//!
+//! Le fichier est écrit dans `<destination>.part`, repris via une requête
+//! HTTP Range après une interruption, vérifié (SHA-256) puis renommé : un
+//! modèle présent à son emplacement final est donc toujours complet.
+
+use futures_util::StreamExt;
+use sha2::{Digest, Sha256};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+pub const PAUSED: &str = "Download paused";
+pub const CANCELLED: &str = "Download cancelled";
+
+// 0 : téléchargement en cours
+const PAUSE_REQUESTED: u8 = 1;
+const CANCEL_REQUESTED: u8 = 2;
+
+/// Permet de mettre en pause ou d'annuler un téléchargement en cours
+#[derive(Clone, Default)]
+pub struct DownloadControl(Arc<AtomicU8>);
+
+impl DownloadControl {
+    /// Interrompt le téléchargement en gardant le fichier partiel
+    pub fn pause(&self) {
+        self.0.store(PAUSE_REQUESTED, Ordering::SeqCst);
+    }
+
+    /// Interrompt le téléchargement et supprime le fichier partiel
+    pub fn cancel(&self) {
+        self.0.store(CANCEL_REQUESTED, Ordering::SeqCst);
+    }
+
+    fn check(&self) -> Result<(), &'static str> {
+        match self.0.load(Ordering::SeqCst) {
+            PAUSE_REQUESTED => Err(PAUSED),
+            CANCEL_REQUESTED => Err(CANCELLED),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn part_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    dest.with_file_name(name)
+}
+
+/// SHA-256 publié par Hugging Face pour les fichiers LFS (en-tête
+/// `X-Linked-Etag` de la réponse de redirection)
+pub async fn remote_sha256(url: &str) -> Option<String> {
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .ok()?;
+    let response = client.head(url).send().await.ok()?;
+    let etag = response.headers().get("x-linked-etag")?.to_str().ok()?;
+    parse_sha256(etag)
+}
+
+fn parse_sha256(etag: &str) -> Option<String> {
+    let hash = etag
+        .trim_start_matches("W/")
+        .trim_matches('"')
+        .to_lowercase();
+    (hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())).then_some(hash)
+}
+
+/// Télécharge `url` vers `dest` en reprenant un éventuel `.part` ; le
+/// fichier n'est renommé que si son empreinte vaut `sha256`. Sans empreinte
+/// (fichier embarqué pas encore renseigné), il n'est pas vérifié.
+pub async fn download_file<F>(
+    client: &reqwest::Client,
+    url: &str,
+    dest: &Path,
+    sha256: Option<&str>,
+    control: &DownloadControl,
+    progress_callback: F,
+) -> Result<(), String>
+where
+    F: Fn(u64, u64),
+{
+    let part = part_path(dest);
+    let mut offset = match fs::metadata(&part).await {
+        Ok(meta) => meta.len(),
+        Err(_) => 0,
+    };
+
+    let mut request = client.get(url);
+    if offset > 0 {
+        log::info!("Resuming download of {:?} at byte {}", dest, offset);
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", offset));
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to start download: {}", e))?;
+
+    let status = response.status();
+    // 416 : le fichier partiel est déjà complet
+    if status != reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+        if !status.is_success() {
+            return Err(format!("Download failed with status: {}", status));
+        }
+        if status != reqwest::StatusCode::PARTIAL_CONTENT {
+            // Le serveur ignore le Range : on repart de zéro
+            offset = 0;
+        }
+
+        let total = offset + response.content_length().unwrap_or(0);
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(offset > 0)
+            .truncate(offset == 0)
+            .open(&part)
+            .await
+            .map_err(|e| format!("Failed to create file: {}", e))?;
+
+        let mut downloaded = offset;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            if let Err(reason) = control.check() {
+                file.flush().await.ok();
+                drop(file);
+                if reason == CANCELLED {
+                    fs::remove_file(&part).await.ok();
+                }
+                log::info!("{}: {:?}", reason, dest);
+                return Err(reason.to_string());
+            }
+
+            let chunk = chunk.map_err(|e| format!("Download error: {}", e))?;
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| format!("Write error: {}", e))?;
+            downloaded += chunk.len() as u64;
+            progress_callback(downloaded, total.max(downloaded));
+        }
+
+        file.flush()
+            .await
+            .map_err(|e| format!("Flush error: {}", e))?;
+    }
+
+    if let Some(expected) = sha256 {
+        let path = part.clone();
+        let actual = tokio::task::spawn_blocking(move || file_sha256(&path))
+            .await
+            .map_err(|e| format!("Task error: {}", e))??;
+        if !actual.eq_ignore_ascii_case(expected) {
+            fs::remove_file(&part).await.ok();
+            return Err(format!(
+                "Checksum mismatch for {:?}: expected {}, got {}",
+                dest.file_name().unwrap_or_default(),
+                expected,
+                actual
+            ));
+        }
+    } else {
+        log::warn!("No checksum known for {:?}, skipping verification", dest);
+    }
+
+    fs::rename(&part, dest)
+        .await
+        .map_err(|e| format!("Failed to finalize download: {}", e))
+}
+
+fn file_sha256(path: &Path) -> Result<String, String> {
+    let mut file =
+        std::fs::File::open(path).map_err(|e| format!("Failed to open download: {}", e))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 1 << 20];
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read download: {}", e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_path() {
+        assert_eq!(
+            part_path(Path::new("/models/ggml-tiny.bin")),
+            PathBuf::from("/models/ggml-tiny.bin.part")
+        );
+    }
+
+    #[test]
+    fn test_parse_sha256() {
+        let hash = "be07e048e1e599ad46341c8d2a135645097a538221678b7acdd1b1919c6e1b21";
+        assert_eq!(
+            parse_sha256(&format!("\"{}\"", hash)),
+            Some(hash.to_string())
+        );
+        assert_eq!(parse_sha256("W/\"abc\""), None);
+    }
+
+    #[test]
+    fn test_control() {
+        let control = DownloadControl::default();
+        assert_eq!(control.check(), Ok(()));
+        control.clone().pause();
+        assert_eq!(control.check(), Err(PAUSED));
+        control.cancel();
+        assert_eq!(control.check(), Err(CANCELLED));
+    }
+}
@@ -0,0 +1,82 @@
+//! Identification de la langue parlée
+//!
+//! Whisper estime la langue à partir des 30 premières secondes d'audio. Les
+//! moteurs qui n'exposent pas leur propre détection (Parakeet) utilisent un
+//! petit modèle Whisper comme front-end pour étiqueter leurs transcriptions.
+
+use std::path::Path;
+use std::sync::Mutex;
+use whisper_rs::{WhisperContext, WhisperContextParameters};
+
+/// Audio analysé par la détection (fenêtre de l'encodeur Whisper)
+const MAX_DETECTION_SECS: usize = 30;
+
+/// Probabilité minimale pour annoncer une langue
+const MIN_LANGUAGE_PROBABILITY: f32 = 0.3;
+
+/// Front-end d'identification de langue basé sur un modèle Whisper multilingue
+pub struct LanguageIdentifier {
+    ctx: Mutex<WhisperContext>,
+}
+
+impl LanguageIdentifier {
+    pub fn new(model_path: &Path) -> Result<Self, String> {
+        let ctx = WhisperContext::new_with_params(
+            model_path.to_str().ok_or("Invalid model path")?,
+            WhisperContextParameters::default(),
+        )
+        .map_err(|e| format!("Failed to load language ID model: {}", e))?;
+
+        if !ctx.is_multilingual() {
+            return Err("Language ID requires a multilingual Whisper model".to_string());
+        }
+
+        log::info!("Language identifier loaded from {:?}", model_path);
+
+        Ok(Self {
+            ctx: Mutex::new(ctx),
+        })
+    }
+
+    /// Langue parlée dans l'audio 16 kHz (code ISO 639-1)
+    pub fn detect(&self, audio: &[f32]) -> Result<Option<String>, String> {
+        let ctx = self.ctx.lock().map_err(|e| format!("Lock error: {}", e))?;
+        detect_language(&ctx, audio)
+    }
+}
+
+/// Langue la plus probable selon `ctx` ; `None` pour un modèle anglais
+/// seulement ou une détection trop incertaine
+pub fn detect_language(ctx: &WhisperContext, audio: &[f32]) -> Result<Option<String>, String> {
+    if !ctx.is_multilingual() || audio.is_empty() {
+        return Ok(None);
+    }
+
+    let n_threads = std::thread::available_parallelism()
+        .map(|n| (n.get() / 2).clamp(1, 8))
+        .unwrap_or(4);
+
+    let mut state = ctx
+        .create_state()
+        .map_err(|e| format!("Failed to create state: {}", e))?;
+
+    let audio = &audio[..audio.len().min(MAX_DETECTION_SECS * 16000)];
+    state
+        .pcm_to_mel(audio, n_threads)
+        .map_err(|e| format!("Failed to compute mel spectrogram: {}", e))?;
+
+    let (lang_id, probabilities) = state
+        .lang_detect(0, n_threads)
+        .map_err(|e| format!("Language detection failed: {}", e))?;
+
+    let probability = probabilities.get(lang_id as usize).copied().unwrap_or(0.0);
+    let language = whisper_rs::get_lang_str(lang_id).map(|s| s.to_string());
+
+    log::info!("Detected language {:?} (p={:.2})", language, probability);
+
+    if probability < MIN_LANGUAGE_PROBABILITY {
+        return Ok(None);
+    }
+
+    Ok(language)
+}
@@ -1,15 +1,26 @@
+pub mod confidence;
+pub mod downloader;
 pub mod error;
+pub mod language_id;
 pub mod model_manager;
+pub mod model_manifest;
+pub mod model_registry;
 pub mod parakeet;
 pub mod parakeet_coreml;
+pub mod segments;
+pub mod streaming;
 pub mod traits;
+pub mod vocabulary;
 pub mod vosk;
 pub mod whisper;
 
 pub use error::EngineError;
+pub use language_id::LanguageIdentifier;
 pub use model_manager::ModelManager;
 pub use parakeet::{ParakeetEngine, ParakeetModelSize};
 pub use parakeet_coreml::ParakeetCoreMLEngine;
-pub use traits::SpeechEngine;
+pub use streaming::{BatchSession, LiveSession, SharedEngine};
+pub use traits::{SpeechEngine, StreamingSession};
+pub use vocabulary::Vocabulary;
 pub use vosk::VoskEngine;
 pub use whisper::WhisperEngine;
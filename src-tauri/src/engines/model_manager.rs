@@ -1,23 +1,141 @@
-use crate::types::{LocalLlmModel, ModelSize, ParakeetModelSize, VoskLanguage};
-use futures_util::StreamExt;
-use std::path::PathBuf;
+use crate::engines::downloader::{self, DownloadControl};
+use crate::engines::model_manifest::{
+    self, ManifestEngine, ManifestFile, ManifestModel, ModelManifest,
+};
+use crate::engines::model_registry;
+use crate::types::{
+    CustomModel, EngineType, LocalLlmModel, ModelInfo, ModelSize, ParakeetModelSize, VoskLanguage,
+};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, RwLock};
 use tokio::fs;
-use tokio::io::AsyncWriteExt;
 
 pub struct ModelManager {
     models_dir: PathBuf,
     bundled_model_path: Option<PathBuf>,
+    /// Téléchargements en cours, par identifiant (nom du fichier ou dossier du modèle)
+    downloads: Mutex<HashMap<String, DownloadControl>>,
+    manifest: RwLock<ModelManifest>,
 }
 
 impl ModelManager {
     pub fn new(app_data_dir: PathBuf, bundled_model_path: Option<PathBuf>) -> Self {
         let models_dir = app_data_dir.join("models");
+        let manifest = model_manifest::load(&models_dir);
         Self {
             models_dir,
             bundled_model_path,
+            downloads: Mutex::new(HashMap::new()),
+            manifest: RwLock::new(manifest),
         }
     }
 
+    // === MANIFESTE ===
+
+    pub fn manifest(&self) -> ModelManifest {
+        match self.manifest.read() {
+            Ok(manifest) => manifest.clone(),
+            Err(_) => model_manifest::bundled().clone(),
+        }
+    }
+
+    /// Entrée active (éventuellement redéfinie par un manifeste distant ou
+    /// local) d'un modèle intégré
+    fn manifest_model<T: Serialize + Debug>(
+        &self,
+        engine: ManifestEngine,
+        model: &T,
+    ) -> ManifestModel {
+        let builtin = model_manifest::builtin(engine, model);
+        self.manifest()
+            .get(engine, &builtin.id)
+            .cloned()
+            .unwrap_or_else(|| builtin.clone())
+    }
+
+    /// Télécharge le manifeste distant, le met en cache et l'active.
+    /// Un manifeste local reste prioritaire.
+    pub async fn refresh_manifest(&self, url: &str) -> Result<(), String> {
+        let (manifest, content) = model_manifest::fetch(url).await?;
+        fs::create_dir_all(&self.models_dir)
+            .await
+            .map_err(|e| format!("Failed to create models directory: {}", e))?;
+        fs::write(
+            self.models_dir.join(model_manifest::REMOTE_CACHE_FILE),
+            content,
+        )
+        .await
+        .map_err(|e| format!("Failed to cache model manifest: {}", e))?;
+
+        let manifest = if self.models_dir.join(model_manifest::LOCAL_FILE).exists() {
+            model_manifest::load(&self.models_dir)
+        } else {
+            manifest
+        };
+        log::info!("Model manifest refreshed: {} models", manifest.models.len());
+        *self.manifest.write().map_err(|e| e.to_string())? = manifest;
+        Ok(())
+    }
+
+    // === TÉLÉCHARGEMENTS ===
+
+    fn begin_download(&self, id: &str) -> Result<DownloadControl, String> {
+        let mut downloads = self.downloads.lock().map_err(|e| e.to_string())?;
+        if downloads.contains_key(id) {
+            return Err(format!("{} is already downloading", id));
+        }
+        let control = DownloadControl::default();
+        downloads.insert(id.to_string(), control.clone());
+        Ok(control)
+    }
+
+    fn end_download(&self, id: &str) {
+        if let Ok(mut downloads) = self.downloads.lock() {
+            downloads.remove(id);
+        }
+    }
+
+    fn download_control(&self, id: &str) -> Result<DownloadControl, String> {
+        self.downloads
+            .lock()
+            .map_err(|e| e.to_string())?
+            .get(id)
+            .cloned()
+            .ok_or_else(|| format!("No download in progress for {}", id))
+    }
+
+    /// Met en pause un téléchargement : il reprendra là où il s'est arrêté
+    pub fn pause_download(&self, id: &str) -> Result<(), String> {
+        self.download_control(id)?.pause();
+        Ok(())
+    }
+
+    /// Annule un téléchargement et supprime les données partielles
+    pub fn cancel_download(&self, id: &str) -> Result<(), String> {
+        self.download_control(id)?.cancel();
+        Ok(())
+    }
+
+    /// Télécharge les fichiers d'un modèle du manifeste dans `parent` et
+    /// retourne son chemin d'installation
+    async fn download_from_manifest<F>(
+        &self,
+        model: &ManifestModel,
+        parent: &Path,
+        progress_callback: F,
+    ) -> Result<PathBuf, String>
+    where
+        F: Fn(u64, u64),
+    {
+        let control = self.begin_download(&model.install_name)?;
+        let result = download_files(model, parent, &control, progress_callback).await;
+        self.end_download(&model.install_name);
+        result
+    }
+
     /// Retourne le chemin du modèle s'il existe
     pub fn get_model_path(&self, size: ModelSize) -> Option<PathBuf> {
         // Pour tiny, vérifier d'abord le bundled
@@ -44,9 +162,25 @@ impl ModelManager {
         self.get_model_path(size).is_some()
     }
 
-    /// Liste les modèles disponibles
-    pub fn available_models(&self) -> Vec<ModelSize> {
-        [ModelSize::Tiny, ModelSize::Small, ModelSize::Medium]
+    /// Catalogue des modèles Whisper, installés ou non
+    pub fn available_models(&self) -> Vec<ModelInfo> {
+        ModelSize::ALL
+            .into_iter()
+            .map(|size| ModelInfo {
+                size,
+                display_name: size.display_name().to_string(),
+                available: self.is_model_available(size),
+                size_bytes: size.size_bytes(),
+                ram_bytes: size.ram_bytes(),
+                relative_speed: size.relative_speed(),
+                multilingual: size.multilingual(),
+            })
+            .collect()
+    }
+
+    /// Modèles Whisper installés
+    pub fn installed_models(&self) -> Vec<ModelSize> {
+        ModelSize::ALL
             .into_iter()
             .filter(|&size| self.is_model_available(size))
             .collect()
@@ -61,48 +195,16 @@ impl ModelManager {
     where
         F: Fn(u64, u64) + Send + 'static,
     {
-        // Créer le dossier models si nécessaire
-        fs::create_dir_all(&self.models_dir)
-            .await
-            .map_err(|e| format!("Failed to create models directory: {}", e))?;
+        let model = self.manifest_model(ManifestEngine::Whisper, &size);
+        log::info!(
+            "Downloading model {} from {}",
+            size.file_name(),
+            model.files[0].url
+        );
 
-        let dest_path = self.models_dir.join(size.file_name());
-        let url = size.download_url();
-
-        log::info!("Downloading model {} from {}", size.file_name(), url);
-
-        let client = reqwest::Client::new();
-        let response = client
-            .get(url)
-            .send()
-            .await
-            .map_err(|e| format!("Failed to start download: {}", e))?;
-
-        if !response.status().is_success() {
-            return Err(format!("Download failed with status: {}", response.status()));
-        }
-
-        let total_size = response.content_length().unwrap_or(size.size_bytes());
-        let mut downloaded: u64 = 0;
-
-        let mut file = fs::File::create(&dest_path)
-            .await
-            .map_err(|e| format!("Failed to create file: {}", e))?;
-
-        let mut stream = response.bytes_stream();
-
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk.map_err(|e| format!("Download error: {}", e))?;
-            file.write_all(&chunk)
-                .await
-                .map_err(|e| format!("Write error: {}", e))?;
-            downloaded += chunk.len() as u64;
-            progress_callback(downloaded, total_size);
-        }
-
-        file.flush()
-            .await
-            .map_err(|e| format!("Flush error: {}", e))?;
+        let dest_path = self
+            .download_from_manifest(&model, &self.models_dir, progress_callback)
+            .await?;
 
         log::info!("Model {} downloaded successfully", size.file_name());
         Ok(dest_path)
@@ -116,9 +218,142 @@ impl ModelManager {
                 .await
                 .map_err(|e| format!("Failed to delete model: {}", e))?;
         }
+        fs::remove_file(downloader::part_path(&path)).await.ok();
         Ok(())
     }
 
+    // === MODÈLES IMPORTÉS ===
+
+    fn registry_path(&self) -> PathBuf {
+        self.models_dir.join("registry.json")
+    }
+
+    /// Modèles importés par l'utilisateur
+    pub fn custom_models(&self) -> Vec<CustomModel> {
+        model_registry::load(&self.registry_path()).models
+    }
+
+    pub fn custom_model(&self, id: &str) -> Option<CustomModel> {
+        self.custom_models()
+            .into_iter()
+            .find(|model| model.id == id)
+    }
+
+    /// Vérifie un modèle local et l'ajoute au registre. Un modèle Vosk
+    /// nécessite sa langue.
+    pub fn import_model(
+        &self,
+        path: &Path,
+        name: &str,
+        language: Option<VoskLanguage>,
+    ) -> Result<CustomModel, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("A display name is required".to_string());
+        }
+
+        let engine = model_registry::detect_engine(path)?;
+        if engine == EngineType::Vosk && language.is_none() {
+            return Err("Vosk models need a language".to_string());
+        }
+
+        let path = path
+            .canonicalize()
+            .map_err(|e| format!("Invalid model path: {}", e))?;
+
+        let registry_path = self.registry_path();
+        let mut registry = model_registry::load(&registry_path);
+        if registry.models.iter().any(|model| model.path == path) {
+            return Err(format!("Model already imported: {:?}", path));
+        }
+
+        let model = CustomModel {
+            id: model_registry::unique_id(name, &registry.models),
+            name: name.to_string(),
+            engine,
+            path,
+            language: language.filter(|_| engine == EngineType::Vosk),
+            imported_at: chrono::Utc::now().timestamp(),
+        };
+        registry.models.push(model.clone());
+        model_registry::save(&registry_path, &registry)?;
+
+        log::info!(
+            "Imported {:?} model '{}' from {:?}",
+            engine,
+            model.name,
+            model.path
+        );
+        Ok(model)
+    }
+
+    /// Retire un modèle du registre (ses fichiers ne sont pas supprimés)
+    pub fn remove_custom_model(&self, id: &str) -> Result<(), String> {
+        let registry_path = self.registry_path();
+        let mut registry = model_registry::load(&registry_path);
+        registry.models.retain(|model| model.id != id);
+        model_registry::save(&registry_path, &registry)
+    }
+
+    // === CATALOGUE (modèles du manifeste sans équivalent intégré) ===
+
+    fn catalog_path(&self, model: &ManifestModel) -> PathBuf {
+        self.models_dir.join("catalog").join(&model.install_name)
+    }
+
+    /// Modèles proposés par le manifeste actif en plus des modèles intégrés,
+    /// avec leur état d'installation
+    pub fn catalog_models(&self) -> Vec<(ManifestModel, bool)> {
+        self.manifest()
+            .extra_models()
+            .filter(|model| model.engine != ManifestEngine::Llm)
+            .map(|model| {
+                let installed = self.catalog_path(model).exists();
+                (model.clone(), installed)
+            })
+            .collect()
+    }
+
+    /// Télécharge un modèle du catalogue et l'ajoute aux modèles importés
+    pub async fn download_catalog_model<F>(
+        &self,
+        id: &str,
+        progress_callback: F,
+    ) -> Result<CustomModel, String>
+    where
+        F: Fn(u64, u64) + Send + 'static,
+    {
+        let model = self
+            .catalog_models()
+            .into_iter()
+            .map(|(model, _)| model)
+            .find(|model| model.id == id)
+            .ok_or_else(|| format!("Unknown catalog model: {}", id))?;
+
+        log::info!(
+            "Downloading catalog model {} ({:?})",
+            model.id,
+            model.engine
+        );
+        let path = self
+            .download_from_manifest(&model, &self.models_dir.join("catalog"), progress_callback)
+            .await?;
+
+        let canonical = path.canonicalize().map_err(|e| e.to_string())?;
+        if let Some(existing) = self
+            .custom_models()
+            .into_iter()
+            .find(|m| m.path == canonical)
+        {
+            return Ok(existing);
+        }
+        let language = model
+            .languages
+            .first()
+            .and_then(|code| VoskLanguage::from_language_code(code));
+        self.import_model(&path, &model.display_name, language)
+    }
+
     // === VOSK MODELS ===
 
     /// Get path to a Vosk model if installed
@@ -138,8 +373,7 @@ impl ModelManager {
 
     /// List available Vosk models
     pub fn available_vosk_models(&self) -> Vec<VoskLanguage> {
-        use VoskLanguage::*;
-        [En, Fr, De, Es, It, Ru, Zh, Ja, Ko, Pt, Nl, Pl, Uk, Tr, Vi, Ar, Hi, Fa, Ca, Cs]
+        VoskLanguage::ALL
             .into_iter()
             .filter(|&lang| self.is_vosk_model_available(lang))
             .collect()
@@ -155,66 +389,17 @@ impl ModelManager {
         F: Fn(u64, u64) + Send + 'static,
     {
         let vosk_dir = self.models_dir.join("vosk");
-        fs::create_dir_all(&vosk_dir)
-            .await
-            .map_err(|e| format!("Failed to create vosk directory: {}", e))?;
-
-        let zip_path = vosk_dir.join(format!("{}.zip", language.model_name()));
-        let extract_path = vosk_dir.join(language.model_name());
-        let url = language.download_url();
-
-        log::info!("Downloading Vosk model {} from {}", language.model_name(), url);
-
-        // Download
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| format!("Failed to start download: {}", e))?;
-
-        if !response.status().is_success() {
-            return Err(format!("Download failed with status: {}", response.status()));
-        }
-
-        let total_size = response.content_length().unwrap_or(50_000_000);
-        let mut downloaded: u64 = 0;
-
-        let mut file = fs::File::create(&zip_path)
-            .await
-            .map_err(|e| format!("Failed to create file: {}", e))?;
+        let model = self.manifest_model(ManifestEngine::Vosk, &language);
 
-        let mut stream = response.bytes_stream();
+        log::info!(
+            "Downloading Vosk model {} from {}",
+            language.model_name(),
+            model.files[0].url
+        );
 
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk.map_err(|e| format!("Download error: {}", e))?;
-            file.write_all(&chunk)
-                .await
-                .map_err(|e| format!("Write error: {}", e))?;
-            downloaded += chunk.len() as u64;
-            progress_callback(downloaded, total_size);
-        }
-
-        file.flush().await.map_err(|e| format!("Flush error: {}", e))?;
-        drop(file);
-
-        // Extract zip
-        log::info!("Extracting Vosk model...");
-        let zip_path_clone = zip_path.clone();
-        let vosk_dir_clone = vosk_dir.clone();
-
-        tokio::task::spawn_blocking(move || {
-            let file = std::fs::File::open(&zip_path_clone)
-                .map_err(|e| format!("Failed to open zip: {}", e))?;
-            let mut archive = zip::ZipArchive::new(file)
-                .map_err(|e| format!("Failed to read zip: {}", e))?;
-            archive.extract(&vosk_dir_clone)
-                .map_err(|e| format!("Failed to extract: {}", e))?;
-            std::fs::remove_file(&zip_path_clone).ok();
-            Ok::<(), String>(())
-        })
-        .await
-        .map_err(|e| format!("Task error: {}", e))??;
+        let extract_path = self
+            .download_from_manifest(&model, &vosk_dir, progress_callback)
+            .await?;
 
         log::info!("Vosk model {} installed successfully", language.model_name());
         Ok(extract_path)
@@ -225,7 +410,12 @@ impl ModelManager {
     /// Get path to Parakeet model if installed
     pub fn get_parakeet_model_path(&self, model_size: ParakeetModelSize) -> Option<PathBuf> {
         let parakeet_dir = self.models_dir.join("parakeet").join(model_size.model_name());
-        if parakeet_dir.exists() {
+        let model = self.manifest_model(ManifestEngine::Parakeet, &model_size);
+        if model
+            .files
+            .iter()
+            .all(|file| parakeet_dir.join(&file.name).exists())
+        {
             Some(parakeet_dir)
         } else {
             None
@@ -245,7 +435,7 @@ impl ModelManager {
             .collect()
     }
 
-    /// Download a Parakeet model from HuggingFace (istupakov/parakeet-tdt-0.6b-v3-onnx)
+    /// Download a Parakeet model (non-quantized ONNX export, for tract-onnx compatibility)
     pub async fn download_parakeet_model<F>(
         &self,
         model_size: ParakeetModelSize,
@@ -255,79 +445,11 @@ impl ModelManager {
         F: Fn(u64, u64) + Send + 'static,
     {
         let parakeet_dir = self.models_dir.join("parakeet");
-        let model_dir = parakeet_dir.join(model_size.model_name());
+        let model = self.manifest_model(ManifestEngine::Parakeet, &model_size);
 
-        fs::create_dir_all(&model_dir)
-            .await
-            .map_err(|e| format!("Failed to create parakeet directory: {}", e))?;
-
-        // Files to download from HuggingFace (non-quantized for tract-onnx compatibility)
-        let base_url = "https://huggingface.co/istupakov/parakeet-tdt-0.6b-v3-onnx/resolve/main";
-        let files = [
-            ("encoder-model.onnx", 41_800_000u64),
-            ("encoder-model.onnx.data", 2_440_000_000u64),
-            ("decoder_joint-model.onnx", 72_500_000u64),
-            ("vocab.txt", 94_000u64),
-        ];
-
-        let total_size: u64 = files.iter().map(|(_, s)| s).sum();
-        let mut total_downloaded: u64 = 0;
-
-        let client = reqwest::Client::builder()
-            .redirect(reqwest::redirect::Policy::limited(10))
-            .build()
-            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
-
-        for (filename, estimated_size) in files {
-            let url = format!("{}/{}", base_url, filename);
-            let dest_path = model_dir.join(filename);
-
-            // Skip if file already exists with reasonable size
-            if dest_path.exists() {
-                if let Ok(meta) = std::fs::metadata(&dest_path) {
-                    if meta.len() > estimated_size / 2 {
-                        log::info!("File {} already exists, skipping", filename);
-                        total_downloaded += estimated_size;
-                        progress_callback(total_downloaded, total_size);
-                        continue;
-                    }
-                }
-            }
-
-            log::info!("Downloading {} from {}", filename, url);
-
-            let response = client
-                .get(&url)
-                .send()
-                .await
-                .map_err(|e| format!("Failed to download {}: {}", filename, e))?;
-
-            if !response.status().is_success() {
-                return Err(format!("Download of {} failed with status: {}", filename, response.status()));
-            }
-
-            let file_size = response.content_length().unwrap_or(estimated_size);
-            let mut file_downloaded: u64 = 0;
-
-            let mut file = fs::File::create(&dest_path)
-                .await
-                .map_err(|e| format!("Failed to create file {}: {}", filename, e))?;
-
-            let mut stream = response.bytes_stream();
-
-            while let Some(chunk) = stream.next().await {
-                let chunk = chunk.map_err(|e| format!("Download error for {}: {}", filename, e))?;
-                file.write_all(&chunk)
-                    .await
-                    .map_err(|e| format!("Write error for {}: {}", filename, e))?;
-                file_downloaded += chunk.len() as u64;
-                progress_callback(total_downloaded + file_downloaded, total_size);
-            }
-
-            file.flush().await.map_err(|e| format!("Flush error: {}", e))?;
-            total_downloaded += file_size;
-            log::info!("Downloaded {} successfully", filename);
-        }
+        let model_dir = self
+            .download_from_manifest(&model, &parakeet_dir, progress_callback)
+            .await?;
 
         log::info!("Parakeet model {} downloaded successfully", model_size.model_name());
         Ok(model_dir)
@@ -380,51 +502,17 @@ impl ModelManager {
         F: Fn(u64, u64) + Send + 'static,
     {
         let llm_dir = self.models_dir.join("llm");
-        fs::create_dir_all(&llm_dir)
-            .await
-            .map_err(|e| format!("Failed to create llm directory: {}", e))?;
-
-        let dest_path = llm_dir.join(model_size.file_name());
-        let url = model_size.download_url();
-
-        log::info!("Downloading LLM model {} from {}", model_size.file_name(), url);
+        let model = self.manifest_model(ManifestEngine::Llm, &model_size);
 
-        let client = reqwest::Client::builder()
-            .redirect(reqwest::redirect::Policy::limited(10))
-            .build()
-            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+        log::info!(
+            "Downloading LLM model {} from {}",
+            model_size.file_name(),
+            model.files[0].url
+        );
 
-        let response = client
-            .get(url)
-            .send()
-            .await
-            .map_err(|e| format!("Failed to start download: {}", e))?;
-
-        if !response.status().is_success() {
-            return Err(format!("Download failed with status: {}", response.status()));
-        }
-
-        let total_size = response.content_length().unwrap_or(model_size.size_bytes());
-        let mut downloaded: u64 = 0;
-
-        let mut file = fs::File::create(&dest_path)
-            .await
-            .map_err(|e| format!("Failed to create file: {}", e))?;
-
-        let mut stream = response.bytes_stream();
-
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk.map_err(|e| format!("Download error: {}", e))?;
-            file.write_all(&chunk)
-                .await
-                .map_err(|e| format!("Write error: {}", e))?;
-            downloaded += chunk.len() as u64;
-            progress_callback(downloaded, total_size);
-        }
-
-        file.flush()
-            .await
-            .map_err(|e| format!("Flush error: {}", e))?;
+        let dest_path = self
+            .download_from_manifest(&model, &llm_dir, progress_callback)
+            .await?;
 
         log::info!("LLM model {} downloaded successfully", model_size.file_name());
         Ok(dest_path)
@@ -439,6 +527,135 @@ impl ModelManager {
                 .await
                 .map_err(|e| format!("Failed to delete LLM model: {}", e))?;
         }
+        fs::remove_file(downloader::part_path(&path)).await.ok();
         Ok(())
     }
 }
+
+/// Empreinte à vérifier : celle du manifeste, ou celle publiée par Hugging
+/// Face si l'entrée (d'un manifeste utilisateur) l'accepte explicitement.
+/// Un fichier du manifeste embarqué sans empreinte renseignée est vérifié
+/// avec l'empreinte publiée s'il y en a une, et n'est pas vérifié sinon.
+async fn expected_sha256(file: &ManifestFile) -> Result<Option<String>, String> {
+    if let Some(hash) = &file.sha256 {
+        return Ok(Some(hash.clone()));
+    }
+    if file.verify_remote_etag {
+        return downloader::remote_sha256(&file.url)
+            .await
+            .map(Some)
+            .ok_or_else(|| format!("No SHA-256 checksum published for {}", file.url));
+    }
+    if model_manifest::is_bundled_file(file) {
+        return Ok(downloader::remote_sha256(&file.url).await);
+    }
+    Err(format!("No SHA-256 checksum known for {}", file.name))
+}
+
+/// Télécharge chaque fichier du modèle (en sautant ceux déjà complets) puis
+/// extrait l'archive éventuelle
+async fn download_files<F>(
+    model: &ManifestModel,
+    parent: &Path,
+    control: &DownloadControl,
+    progress_callback: F,
+) -> Result<PathBuf, String>
+where
+    F: Fn(u64, u64),
+{
+    let install_path = parent.join(&model.install_name);
+    // Les fichiers d'un modèle multi-fichiers vont dans son dossier ;
+    // une archive est extraite à côté
+    let files_dir = if model.is_directory() && !model.archive {
+        install_path.clone()
+    } else {
+        parent.to_path_buf()
+    };
+    fs::create_dir_all(&files_dir)
+        .await
+        .map_err(|e| format!("Failed to create {:?}: {}", files_dir, e))?;
+
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::limited(10))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let single = model.files.len() == 1;
+    let total_size: u64 = model.files.iter().map(|file| file.size_bytes).sum();
+    let mut total_downloaded: u64 = 0;
+
+    for file in &model.files {
+        let dest_path = if model.is_directory() {
+            files_dir.join(&file.name)
+        } else {
+            install_path.clone()
+        };
+
+        // Un fichier n'est renommé qu'une fois complet et vérifié
+        if dest_path.exists() {
+            log::info!("File {} already exists, skipping", file.name);
+            total_downloaded += file.size_bytes;
+            progress_callback(total_downloaded, total_size);
+            continue;
+        }
+
+        let sha256 = expected_sha256(file).await?;
+        let result = downloader::download_file(
+            &client,
+            &file.url,
+            &dest_path,
+            sha256.as_deref(),
+            control,
+            |downloaded, total| {
+                if single {
+                    progress_callback(downloaded, total)
+                } else {
+                    progress_callback(total_downloaded + downloaded, total_size)
+                }
+            },
+        )
+        .await;
+
+        if let Err(e) = result {
+            return Err(match e.as_str() {
+                downloader::PAUSED => e,
+                downloader::CANCELLED => {
+                    if !single {
+                        fs::remove_dir_all(&install_path).await.ok();
+                    }
+                    e
+                }
+                _ => format!("Download of {} failed: {}", file.name, e),
+            });
+        }
+
+        total_downloaded += fs::metadata(&dest_path)
+            .await
+            .map(|meta| meta.len())
+            .unwrap_or(file.size_bytes);
+        log::info!("Downloaded {} successfully", file.name);
+    }
+
+    if model.archive {
+        log::info!("Extracting {}...", model.files[0].name);
+        extract_archive(files_dir.join(&model.files[0].name), files_dir).await?;
+    }
+
+    Ok(install_path)
+}
+
+async fn extract_archive(zip_path: PathBuf, dest: PathBuf) -> Result<(), String> {
+    tokio::task::spawn_blocking(move || {
+        let file =
+            std::fs::File::open(&zip_path).map_err(|e| format!("Failed to open zip: {}", e))?;
+        let mut archive =
+            zip::ZipArchive::new(file).map_err(|e| format!("Failed to read zip: {}", e))?;
+        archive
+            .extract(&dest)
+            .map_err(|e| format!("Failed to extract: {}", e))?;
+        std::fs::remove_file(&zip_path).ok();
+        Ok::<(), String>(())
+    })
+    .await
+    .map_err(|e| format!("Task error: {}", e))?
+}
@@ -0,0 +1,326 @@
+//! Manifeste des modèles téléchargeables
+//!
+//! Fichiers, URLs, tailles, empreintes, langues et licences des modèles sont
+//! décrits par un manifeste JSON versionné. Le manifeste embarqué suffit hors
+//! ligne ; un manifeste local (`models/manifest.json`) ou distant
+//! (`model_manifest_url`, mis en cache) le complète pour proposer de nouveaux
+//! modèles ou de nouvelles sources sans nouvelle version de l'application.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Version du format de manifeste comprise par cette version de l'application
+pub const MANIFEST_VERSION: u32 = 1;
+
+const BUNDLED: &str = include_str!("model_manifest.json");
+
+/// Manifeste déposé par l'utilisateur, prioritaire
+pub const LOCAL_FILE: &str = "manifest.json";
+/// Dernier manifeste distant téléchargé
+pub const REMOTE_CACHE_FILE: &str = "manifest-remote.json";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ManifestEngine {
+    Whisper,
+    Parakeet,
+    Vosk,
+    Llm,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ManifestFile {
+    pub name: String,
+    pub url: String,
+    pub size_bytes: u64,
+    /// Empreinte attendue ; sans elle, le fichier d'un manifeste utilisateur
+    /// n'est pas téléchargé (sauf `verify_remote_etag`)
+    #[serde(default)]
+    pub sha256: Option<String>,
+    /// Sans `sha256`, accepter l'empreinte publiée par Hugging Face
+    /// (en-tête `X-Linked-Etag`) ; réservé aux manifestes utilisateur
+    #[serde(default)]
+    pub verify_remote_etag: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ManifestModel {
+    pub id: String,
+    pub engine: ManifestEngine,
+    pub display_name: String,
+    pub license: String,
+    /// Codes ISO 639-1 ; vide pour un modèle multilingue
+    #[serde(default)]
+    pub languages: Vec<String>,
+    /// Nom du fichier (modèle à fichier unique) ou du dossier installé
+    pub install_name: String,
+    pub size_bytes: u64,
+    #[serde(default)]
+    pub ram_bytes: Option<u64>,
+    /// Vitesse relative à Whisper Large v3
+    #[serde(default)]
+    pub relative_speed: Option<f32>,
+    /// Le fichier unique est une archive zip contenant le dossier `install_name`
+    #[serde(default)]
+    pub archive: bool,
+    pub files: Vec<ManifestFile>,
+}
+
+impl ManifestModel {
+    pub fn multilingual(&self) -> bool {
+        self.languages.is_empty()
+    }
+
+    /// Modèle installé sous forme de dossier
+    pub fn is_directory(&self) -> bool {
+        self.archive || self.files.len() > 1
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModelManifest {
+    pub version: u32,
+    pub models: Vec<ManifestModel>,
+}
+
+impl ModelManifest {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let manifest: Self =
+            serde_json::from_str(content).map_err(|e| format!("Invalid model manifest: {}", e))?;
+        if manifest.version != MANIFEST_VERSION {
+            return Err(format!(
+                "Unsupported model manifest version {} (expected {})",
+                manifest.version, MANIFEST_VERSION
+            ));
+        }
+        if let Some(model) = manifest.models.iter().find(|m| m.files.is_empty()) {
+            return Err(format!("Model {} has no files", model.id));
+        }
+        for model in &manifest.models {
+            for file in &model.files {
+                if let Some(hash) = file.sha256.as_deref().filter(|hash| !is_sha256(hash)) {
+                    return Err(format!(
+                        "Invalid SHA-256 for {}/{}: {}",
+                        model.id, file.name, hash
+                    ));
+                }
+            }
+        }
+        Ok(manifest)
+    }
+
+    pub fn get(&self, engine: ManifestEngine, id: &str) -> Option<&ManifestModel> {
+        self.models
+            .iter()
+            .find(|model| model.engine == engine && model.id == id)
+    }
+
+    /// Complète ce manifeste avec les modèles embarqués qu'il ne redéfinit
+    /// pas. Un modèle intégré garde son nom d'installation, dont dépendent
+    /// les chemins des modèles déjà téléchargés.
+    pub fn merged_with_bundled(mut self) -> Self {
+        let bundled = bundled();
+        for model in &mut self.models {
+            if let Some(builtin) = bundled.get(model.engine, &model.id) {
+                model.install_name = builtin.install_name.clone();
+            }
+        }
+        for builtin in &bundled.models {
+            if self.get(builtin.engine, &builtin.id).is_none() {
+                self.models.push(builtin.clone());
+            }
+        }
+        self
+    }
+
+    /// Modèles absents du manifeste embarqué : ils s'installent comme des
+    /// modèles importés
+    pub fn extra_models(&self) -> impl Iterator<Item = &ManifestModel> {
+        self.models
+            .iter()
+            .filter(|model| bundled().get(model.engine, &model.id).is_none())
+    }
+}
+
+/// Empreinte SHA-256 en hexadécimal minuscule
+fn is_sha256(hash: &str) -> bool {
+    hash.len() == 64 && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+pub fn bundled() -> &'static ModelManifest {
+    static MANIFEST: OnceLock<ModelManifest> = OnceLock::new();
+    MANIFEST.get_or_init(|| ModelManifest::parse(BUNDLED).expect("bundled model manifest is valid"))
+}
+
+/// Fichier proposé par le manifeste embarqué (même URL)
+pub fn is_bundled_file(file: &ManifestFile) -> bool {
+    bundled()
+        .models
+        .iter()
+        .flat_map(|model| &model.files)
+        .any(|bundled| bundled.url == file.url)
+}
+
+/// Entrée embarquée d'un modèle intégré (`model` est l'énumération
+/// sérialisée en identifiant, comme dans les réglages)
+pub fn builtin<T: Serialize + Debug>(engine: ManifestEngine, model: &T) -> &'static ManifestModel {
+    let id = serde_json::to_value(model)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default();
+    bundled()
+        .get(engine, &id)
+        .unwrap_or_else(|| panic!("{:?} missing from bundled model manifest", model))
+}
+
+/// Manifeste actif : local, sinon distant en cache, sinon embarqué
+pub fn load(models_dir: &Path) -> ModelManifest {
+    for file in [LOCAL_FILE, REMOTE_CACHE_FILE] {
+        let path = models_dir.join(file);
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        match ModelManifest::parse(&content) {
+            Ok(manifest) => {
+                log::info!("Using model manifest {:?}", path);
+                return manifest.merged_with_bundled();
+            }
+            Err(e) => log::warn!("Ignoring model manifest {:?}: {}", path, e),
+        }
+    }
+    bundled().clone()
+}
+
+/// Télécharge et valide un manifeste distant ; retourne aussi son contenu
+/// brut pour la mise en cache
+pub async fn fetch(url: &str) -> Result<(ModelManifest, String), String> {
+    let response = reqwest::get(url)
+        .await
+        .map_err(|e| format!("Failed to fetch model manifest: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "Model manifest request failed with status: {}",
+            response.status()
+        ));
+    }
+    let content = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read model manifest: {}", e))?;
+    let manifest = ModelManifest::parse(&content)?.merged_with_bundled();
+    Ok((manifest, content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{LocalLlmModel, ModelSize, ParakeetModelSize, VoskLanguage};
+
+    #[test]
+    fn test_bundled_covers_builtin_models() {
+        for size in ModelSize::ALL {
+            builtin(ManifestEngine::Whisper, &size);
+        }
+        for language in VoskLanguage::ALL {
+            builtin(ManifestEngine::Vosk, &language);
+        }
+        builtin(ManifestEngine::Parakeet, &ParakeetModelSize::Tdt06bV3);
+        for model in [
+            LocalLlmModel::SmolLM2_360M,
+            LocalLlmModel::Phi3Mini,
+            LocalLlmModel::Qwen2_5_3B,
+        ] {
+            builtin(ManifestEngine::Llm, &model);
+        }
+        assert_eq!(bundled().extra_models().count(), 0);
+
+        // L'empreinte publiée ne sert qu'en l'absence d'empreinte embarquée
+        // (`scripts/fill-manifest-sha256.py --check` signale les manquantes)
+        for model in &bundled().models {
+            for file in &model.files {
+                assert!(file.sha256.as_deref().is_none_or(is_sha256));
+                assert!(!file.verify_remote_etag);
+            }
+        }
+    }
+
+    #[test]
+    fn test_override_keeps_builtin_install_names() {
+        let manifest = ModelManifest::parse(
+            r#"{
+                "version": 1,
+                "models": [
+                    {
+                        "id": "tiny",
+                        "engine": "whisper",
+                        "display_name": "Tiny (miroir)",
+                        "license": "MIT",
+                        "install_name": "autre.bin",
+                        "size_bytes": 75000000,
+                        "files": [{ "name": "ggml-tiny.bin", "url": "https://mirror.example/ggml-tiny.bin", "size_bytes": 75000000 }]
+                    },
+                    {
+                        "id": "jargon-fr",
+                        "engine": "whisper",
+                        "display_name": "Whisper jargon FR",
+                        "license": "MIT",
+                        "languages": ["fr"],
+                        "install_name": "ggml-jargon-fr.bin",
+                        "size_bytes": 466000000,
+                        "files": [{ "name": "ggml-jargon-fr.bin", "url": "https://mirror.example/ggml-jargon-fr.bin", "size_bytes": 466000000 }]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap()
+        .merged_with_bundled();
+
+        let tiny = manifest.get(ManifestEngine::Whisper, "tiny").unwrap();
+        assert_eq!(tiny.install_name, "ggml-tiny.bin");
+        assert_eq!(tiny.files[0].url, "https://mirror.example/ggml-tiny.bin");
+        // Le miroir n'est pas un fichier embarqué : il lui faut sa propre empreinte
+        assert!(!is_bundled_file(&tiny.files[0]));
+        let builtin_tiny = bundled().get(ManifestEngine::Whisper, "tiny").unwrap();
+        assert!(is_bundled_file(&builtin_tiny.files[0]));
+        assert!(manifest.get(ManifestEngine::Vosk, "fr").is_some());
+
+        let extras: Vec<_> = manifest.extra_models().map(|m| m.id.as_str()).collect();
+        assert_eq!(extras, vec!["jargon-fr"]);
+    }
+
+    #[test]
+    fn test_rejects_malformed_sha256() {
+        let manifest = |sha256: &str| {
+            format!(
+                r#"{{
+                    "version": 1,
+                    "models": [
+                        {{
+                            "id": "tiny",
+                            "engine": "whisper",
+                            "display_name": "Tiny",
+                            "license": "MIT",
+                            "install_name": "ggml-tiny.bin",
+                            "size_bytes": 75000000,
+                            "files": [{{ "name": "ggml-tiny.bin", "url": "https://mirror.example/ggml-tiny.bin", "size_bytes": 75000000, "sha256": "{}" }}]
+                        }}
+                    ]
+                }}"#,
+                sha256
+            )
+        };
+        let hash = "be07e048e1e599ad46341c8d2a135645097a538221678b7acdd1b1919c6e1b21";
+        assert!(ModelManifest::parse(&manifest(hash)).is_ok());
+        assert!(ModelManifest::parse(&manifest(&hash.to_uppercase())).is_err());
+        assert!(ModelManifest::parse(&manifest(&hash[..40])).is_err());
+        assert!(ModelManifest::parse(&manifest("")).is_err());
+    }
+
+    #[test]
+    fn test_rejects_unknown_version() {
+        assert!(ModelManifest::parse(r#"{ "version": 2, "models": [] }"#).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+//! Registre des modèles importés
+//!
+//! Les modèles fournis par l'utilisateur (Whisper affiné sur un jargon
+//! interne, export ONNX de Parakeet, modèle Vosk) restent à leur
+//! emplacement : le registre `models/registry.json` en garde le chemin, le
+//! moteur et un nom d'affichage.
+
+use crate::types::{CustomModel, EngineType, ModelRegistry};
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+/// En-tête des fichiers ggml de whisper.cpp (0x67676d6c en little-endian)
+const GGML_MAGIC: &[u8; 4] = b"lmgg";
+
+pub fn load(path: &Path) -> ModelRegistry {
+    if path.exists() {
+        let content = fs::read_to_string(path).unwrap_or_default();
+        serde_json::from_str(&content).unwrap_or_default()
+    } else {
+        ModelRegistry::default()
+    }
+}
+
+pub fn save(path: &Path, registry: &ModelRegistry) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let content = serde_json::to_string_pretty(registry).map_err(|e| e.to_string())?;
+    fs::write(path, content).map_err(|e| e.to_string())
+}
+
+/// Moteur capable de charger le modèle, après vérification des fichiers requis
+pub fn detect_engine(path: &Path) -> Result<EngineType, String> {
+    if path.is_file() {
+        let mut magic = [0u8; 4];
+        fs::File::open(path)
+            .and_then(|mut file| file.read_exact(&mut magic))
+            .map_err(|e| format!("Failed to read model file: {}", e))?;
+        if &magic != GGML_MAGIC {
+            return Err("Not a ggml Whisper model".to_string());
+        }
+        return Ok(EngineType::Whisper);
+    }
+
+    if !path.is_dir() {
+        return Err(format!("Model path not found: {:?}", path));
+    }
+
+    if path.join("encoder-model.onnx").exists() {
+        let fused = path.join("decoder_joint-model.onnx").exists();
+        let split =
+            path.join("decoder-model.onnx").exists() && path.join("joiner-model.onnx").exists();
+        if !fused && !split {
+            return Err("ONNX model is missing its decoder and joiner".to_string());
+        }
+        if !path.join("vocab.txt").exists() {
+            return Err("ONNX model is missing vocab.txt".to_string());
+        }
+        return Ok(EngineType::Parakeet);
+    }
+
+    if path.join("am").join("final.mdl").exists() {
+        if !path.join("conf").is_dir() {
+            return Err("Vosk model is missing its conf directory".to_string());
+        }
+        return Ok(EngineType::Vosk);
+    }
+
+    Err(
+        "Unrecognized model: expected a ggml file, a Parakeet ONNX directory or a Vosk directory"
+            .to_string(),
+    )
+}
+
+/// Identifiant dérivé du nom, unique dans le registre
+pub fn unique_id(name: &str, models: &[CustomModel]) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let base = if slug.is_empty() {
+        "model".to_string()
+    } else {
+        slug
+    };
+
+    let mut id = base.clone();
+    let mut suffix = 2;
+    while models.iter().any(|model| model.id == id) {
+        id = format!("{}-{}", base, suffix);
+        suffix += 1;
+    }
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("phonon-registry-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_detect_engine() {
+        let dir = temp_dir("detect");
+
+        let ggml = dir.join("ggml-jargon.bin");
+        fs::write(&ggml, b"lmgg\x01\x00\x00\x00").unwrap();
+        assert_eq!(detect_engine(&ggml), Ok(EngineType::Whisper));
+
+        let not_ggml = dir.join("model.gguf");
+        fs::write(&not_ggml, b"GGUF").unwrap();
+        assert!(detect_engine(&not_ggml).is_err());
+
+        let onnx = dir.join("parakeet");
+        fs::create_dir_all(&onnx).unwrap();
+        fs::write(onnx.join("encoder-model.onnx"), b"").unwrap();
+        fs::write(onnx.join("decoder_joint-model.onnx"), b"").unwrap();
+        assert!(detect_engine(&onnx).is_err());
+        fs::write(onnx.join("vocab.txt"), b"").unwrap();
+        assert_eq!(detect_engine(&onnx), Ok(EngineType::Parakeet));
+
+        let vosk = dir.join("vosk");
+        fs::create_dir_all(vosk.join("am")).unwrap();
+        fs::create_dir_all(vosk.join("conf")).unwrap();
+        fs::write(vosk.join("am").join("final.mdl"), b"").unwrap();
+        assert_eq!(detect_engine(&vosk), Ok(EngineType::Vosk));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_unique_id() {
+        let existing = CustomModel {
+            id: "whisper-jargon".to_string(),
+            name: "Whisper jargon".to_string(),
+            engine: EngineType::Whisper,
+            path: "/models/ggml-jargon.bin".into(),
+            language: None,
+            imported_at: 0,
+        };
+
+        assert_eq!(unique_id("Whisper jargon", &[]), "whisper-jargon");
+        assert_eq!(
+            unique_id("Whisper  Jargon!", &[existing]),
+            "whisper-jargon-2"
+        );
+        assert_eq!(unique_id("???", &[]), "model");
+    }
+}
@@ -1,14 +1,41 @@
-use crate::engines::traits::SpeechEngine;
-use crate::types::TranscriptionResult;
+use crate::engines::confidence::{low_confidence_spans, overall_confidence, LOW_CONFIDENCE_THRESHOLD};
+use crate::engines::language_id::LanguageIdentifier;
+use crate::audio::StreamingUpdate;
+use crate::engines::traits::{SpeechEngine, StreamingSession};
+use crate::engines::segments::{group_into_segments, push_token};
+use crate::engines::vocabulary::Vocabulary;
+use crate::types::{TranscriptionResult, WordTiming};
 use chrono::Utc;
 use std::collections::HashMap;
+use std::ops::Range;
 use std::fs;
 use std::path::Path;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use tract_onnx::prelude::*;
 
 type TractModel = SimplePlan<TypedFact, Box<dyn TypedOp>, Graph<TypedFact, Box<dyn TypedOp>>>;
 
+/// Durées (en frames encodeur) prédites par la tête TDT du joiner
+const TDT_DURATIONS: [usize; 5] = [0, 1, 2, 3, 4];
+
+/// Nombre maximal de tokens émis sur une même frame encodeur
+const MAX_SYMBOLS_PER_FRAME: usize = 10;
+
+/// Langues de Parakeet TDT 0.6B v3 (ISO 639-1)
+const SUPPORTED_LANGUAGES: [&str; 25] = [
+    "bg", "cs", "da", "de", "el", "en", "es", "et", "fi", "fr", "hr", "hu", "it", "lt", "lv",
+    "mt", "nl", "pl", "pt", "ro", "ru", "sk", "sl", "sv", "uk",
+];
+
+/// Token émis par le décodeur, avec sa position et sa durée en frames encodeur
+#[derive(Debug, Clone, Copy)]
+struct DecodedToken {
+    id: i64,
+    frame: usize,
+    duration: usize,
+    probability: f32,
+}
+
 /// Parakeet model size options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ParakeetModelSize {
@@ -38,14 +65,50 @@ impl From<crate::types::ParakeetModelSize> for ParakeetModelSize {
     }
 }
 
-pub struct ParakeetEngine {
+/// Modèle ONNX chargé, partagé entre le moteur et ses sessions de streaming
+struct ParakeetModel {
     encoder: Mutex<TractModel>,
-    decoder_joint: Mutex<TractModel>,
+    joint: Mutex<JointModel>,
     vocab: HashMap<i64, String>,
-    model_size: ParakeetModelSize,
     blank_id: i64,
 }
 
+/// Langue annoncée dans les résultats. Parakeet reconnaît la langue sans
+/// l'exposer : en détection automatique, elle est identifiée par un front-end
+/// Whisper s'il est disponible.
+#[derive(Clone, Default)]
+struct LanguageTag {
+    language: Option<String>,
+    identifier: Option<Arc<LanguageIdentifier>>,
+}
+
+impl LanguageTag {
+    fn resolve(&self, audio: &[f32]) -> Option<String> {
+        if let Some(ref language) = self.language {
+            return Some(language.clone());
+        }
+
+        match self.identifier.as_ref()?.detect(audio) {
+            Ok(language) => language,
+            Err(e) => {
+                log::warn!("Language identification failed: {}", e);
+                None
+            }
+        }
+    }
+}
+
+pub struct ParakeetEngine {
+    model: Arc<ParakeetModel>,
+    model_size: ParakeetModelSize,
+    /// Nom d'un modèle importé, à la place de celui de `model_size`
+    custom_name: Option<String>,
+    vocabulary: Vocabulary,
+    /// Largeur du faisceau (1 = décodage glouton)
+    beam_width: usize,
+    language: LanguageTag,
+}
+
 impl ParakeetEngine {
     pub fn new(model_path: &Path, model_size: ParakeetModelSize) -> Result<Self, String> {
         log::info!("Loading Parakeet model from {:?}", model_path);
@@ -59,10 +122,15 @@ impl ParakeetEngine {
         let decoder_joint_file = model_path.join("decoder_joint-model.onnx");
         let vocab_file = model_path.join("vocab.txt");
 
+        // Certains exports séparent le réseau de prédiction et le joiner
+        let decoder_file = model_path.join("decoder-model.onnx");
+        let joiner_file = model_path.join("joiner-model.onnx");
+        let split = decoder_file.exists() && joiner_file.exists();
+
         // Check all required files
         for (name, path) in [
             ("Encoder", &encoder_file),
-            ("Decoder+Joiner", &decoder_joint_file),
+            ("Decoder+Joiner", if split { &decoder_file } else { &decoder_joint_file }),
             ("Vocab", &vocab_file),
         ] {
             if !path.exists() {
@@ -72,23 +140,19 @@ impl ParakeetEngine {
 
         // Load encoder with tract-onnx
         log::info!("Loading encoder with tract-onnx...");
-        let encoder = tract_onnx::onnx()
-            .model_for_path(&encoder_file)
-            .map_err(|e| format!("Failed to load encoder model: {}", e))?
-            .into_optimized()
-            .map_err(|e| format!("Failed to optimize encoder: {}", e))?
-            .into_runnable()
-            .map_err(|e| format!("Failed to make encoder runnable: {}", e))?;
+        let encoder = load_onnx(&encoder_file, "encoder")?;
 
         // Load decoder+joiner with tract-onnx
-        log::info!("Loading decoder+joiner with tract-onnx...");
-        let decoder_joint = tract_onnx::onnx()
-            .model_for_path(&decoder_joint_file)
-            .map_err(|e| format!("Failed to load decoder+joiner model: {}", e))?
-            .into_optimized()
-            .map_err(|e| format!("Failed to optimize decoder+joiner: {}", e))?
-            .into_runnable()
-            .map_err(|e| format!("Failed to make decoder+joiner runnable: {}", e))?;
+        let joint = if split {
+            log::info!("Loading decoder and joiner with tract-onnx...");
+            JointModel::split(
+                load_onnx(&decoder_file, "decoder")?,
+                load_onnx(&joiner_file, "joiner")?,
+            )?
+        } else {
+            log::info!("Loading decoder+joiner with tract-onnx...");
+            JointModel::fused(load_onnx(&decoder_joint_file, "decoder+joiner")?)?
+        };
 
         // Load vocabulary
         let vocab_content = fs::read_to_string(&vocab_file)
@@ -103,19 +167,128 @@ impl ParakeetEngine {
         let blank_id = 0i64;
 
         log::info!(
-            "Parakeet model loaded successfully ({} tokens)",
-            vocab.len()
+            "Parakeet model loaded successfully ({} tokens, {} decoder states)",
+            vocab.len(),
+            joint.initial_states.len()
         );
 
         Ok(Self {
-            encoder: Mutex::new(encoder),
-            decoder_joint: Mutex::new(decoder_joint),
-            vocab,
+            model: Arc::new(ParakeetModel {
+                encoder: Mutex::new(encoder),
+                joint: Mutex::new(joint),
+                vocab,
+                blank_id,
+            }),
             model_size,
-            blank_id,
+            custom_name: None,
+            vocabulary: Vocabulary::default(),
+            beam_width: 1,
+            language: LanguageTag::default(),
+        })
+    }
+
+    /// Nom d'affichage d'un modèle importé
+    pub fn set_display_name(&mut self, name: String) {
+        self.custom_name = Some(name);
+    }
+
+    /// Active la recherche en faisceau (`width` > 1) ou le décodage glouton
+    pub fn set_beam_width(&mut self, width: usize) {
+        self.beam_width = width.max(1);
+    }
+
+    /// Front-end d'identification de la langue en détection automatique
+    pub fn set_language_identifier(&mut self, identifier: Option<Arc<LanguageIdentifier>>) {
+        self.language.identifier = identifier;
+    }
+}
+
+impl ParakeetModel {
+    /// Transcrit un enregistrement complet (audio 16 kHz)
+    fn transcribe(
+        &self,
+        audio: &[f32],
+        vocabulary: &Vocabulary,
+        model_used: String,
+        beam_width: usize,
+    ) -> Result<TranscriptionResult, String> {
+        let start_time = std::time::Instant::now();
+        let duration_seconds = audio.len() as f32 / 16000.0;
+
+        if duration_seconds < 0.1 {
+            return Err("Audio too short".to_string());
+        }
+
+        let encoder_out = self.encode(audio)?;
+
+        let time_steps = encoder_out.shape()[1].max(1);
+        let frame_secs = duration_seconds / time_steps as f32;
+        let frames = 0..encoder_out.shape()[1];
+        let (tokens, _) = self.decode(&encoder_out, frames, self.initial_state(), beam_width)?;
+        let text = vocabulary.correct(&self.decode_tokens(&tokens));
+
+        // Correction appliquée aux mots : les segments (et les exports) en
+        // sont reconstruits
+        let words = vocabulary.correct_words(self.decode_words(&tokens, frame_secs));
+        let segments = group_into_segments(words);
+
+        let processing_time_ms = start_time.elapsed().as_millis() as u64;
+
+        log::info!(
+            "Parakeet transcription completed in {}ms: {} chars",
+            processing_time_ms,
+            text.len()
+        );
+
+        Ok(TranscriptionResult {
+            text,
+            confidence: overall_confidence(&segments),
+            low_confidence_spans: low_confidence_spans(&segments, LOW_CONFIDENCE_THRESHOLD),
+            duration_seconds,
+            processing_time_ms,
+            detected_language: None,
+            timestamp: Utc::now().timestamp(),
+            model_used: Some(model_used),
+            segments,
         })
     }
 
+    /// Calcule les features et exécute l'encodeur : sortie `[1, frames, dim]`
+    fn encode(&self, audio: &[f32]) -> Result<tract_ndarray::ArrayD<f32>, String> {
+        // Compute mel-spectrogram features
+        let n_mels = 80;
+        let features = self.compute_features(audio, 16000);
+        let num_frames = features.len() / n_mels;
+
+        // Create encoder input tensor [batch, time, features]
+        // Note: features is [n_mels, num_frames], need to transpose to [num_frames, n_mels]
+        let mut transposed = vec![0.0f32; num_frames * n_mels];
+        for mel in 0..n_mels {
+            for frame in 0..num_frames {
+                transposed[frame * n_mels + mel] = features[mel * num_frames + frame];
+            }
+        }
+
+        let features_tensor: Tensor = tract_ndarray::Array3::from_shape_vec(
+            (1, num_frames, n_mels),
+            transposed,
+        )
+        .map_err(|e| format!("Failed to create features tensor: {}", e))?
+        .into();
+
+        // Run encoder
+        let encoder = self.encoder.lock().map_err(|e| e.to_string())?;
+        let encoder_outputs = encoder
+            .run(tvec![features_tensor.into()])
+            .map_err(|e| format!("Encoder error: {}", e))?;
+
+        let encoder_out = encoder_outputs[0]
+            .to_array_view::<f32>()
+            .map_err(|e| format!("Encoder output error: {}", e))?;
+
+        Ok(encoder_out.to_owned().into_dyn())
+    }
+
     /// Radix-2 Cooley-Tukey FFT (in-place, n must be power of 2)
     fn fft(buf: &mut [(f32, f32)]) {
         let n = buf.len();
@@ -269,94 +442,642 @@ impl ParakeetEngine {
         filterbank
     }
 
-    fn decode_tokens(&self, token_ids: &[i64]) -> String {
+    fn decode_tokens(&self, tokens: &[DecodedToken]) -> String {
         let mut text = String::new();
 
-        for &id in token_ids {
-            if id == self.blank_id {
+        for token in tokens {
+            if token.id == self.blank_id {
                 continue;
             }
-            if let Some(token) = self.vocab.get(&id) {
+            if let Some(piece) = self.vocab.get(&token.id) {
                 // Handle SentencePiece tokens (underscore = space)
-                let token = token.replace("▁", " ");
-                text.push_str(&token);
+                let piece = piece.replace("▁", " ");
+                text.push_str(&piece);
             }
         }
 
         text.trim().to_string()
     }
 
-    fn greedy_decode(
+    /// Regroupe les tokens SentencePiece en mots horodatés
+    fn decode_words(&self, tokens: &[DecodedToken], frame_secs: f32) -> Vec<WordTiming> {
+        let mut words = Vec::new();
+        self.push_words(&mut words, tokens, frame_secs, 0.0);
+        words
+    }
+
+    /// Ajoute les tokens aux mots existants (un sous-mot complète le dernier mot) ;
+    /// `offset_secs` est la position de la fenêtre décodée dans l'enregistrement
+    fn push_words(
+        &self,
+        words: &mut Vec<WordTiming>,
+        tokens: &[DecodedToken],
+        frame_secs: f32,
+        offset_secs: f32,
+    ) {
+        for token in tokens {
+            if token.id == self.blank_id {
+                continue;
+            }
+            if let Some(piece) = self.vocab.get(&token.id) {
+                let start = offset_secs + token.frame as f32 * frame_secs;
+                let end = offset_secs + (token.frame + token.duration.max(1)) as f32 * frame_secs;
+                push_token(words, &piece.replace("▁", " "), start, end, token.probability);
+            }
+        }
+    }
+
+    /// Retourne l'indice du logit maximal et sa probabilité après softmax
+    fn argmax_softmax(logits: &[f32]) -> (usize, f32) {
+        let mut max_idx = 0usize;
+        let mut max_val = f32::NEG_INFINITY;
+        for (i, &val) in logits.iter().enumerate() {
+            if val > max_val {
+                max_val = val;
+                max_idx = i;
+            }
+        }
+
+        let sum: f32 = logits.iter().map(|&v| (v - max_val).exp()).sum();
+        let probability = if sum > 0.0 { 1.0 / sum } else { 0.0 };
+
+        (max_idx, probability)
+    }
+
+    /// État du décodeur en début d'enregistrement
+    fn initial_state(&self) -> DecoderState {
+        let initial_states = self
+            .joint
+            .lock()
+            .map(|joint| joint.initial_states.clone())
+            .unwrap_or_default();
+        DecoderState::new(self.blank_id, initial_states, true)
+    }
+
+    /// Décode les frames `frames` de la sortie de l'encodeur à partir de
+    /// `state`, et retourne les tokens avec l'état du décodeur après le dernier.
+    /// Une largeur de faisceau de 1 correspond au décodage glouton.
+    fn decode(
         &self,
         encoder_out: &tract_ndarray::ArrayD<f32>,
-    ) -> Result<Vec<i64>, String> {
-        let shape = encoder_out.shape();
-        let time_steps = shape[1];
-        let encoder_dim = shape[2];
-
-        let mut decoded_tokens: Vec<i64> = Vec::new();
-        let decoder_joint = self.decoder_joint.lock().map_err(|e| e.to_string())?;
-
-        // Initial decoder state
-        let mut last_token = self.blank_id;
-
-        for t in 0..time_steps {
-            // Get encoder frame at timestep t
-            let encoder_frame: Vec<f32> = (0..encoder_dim)
-                .map(|d| encoder_out[[0, t, d]])
-                .collect();
-
-            // Create input tensors for decoder+joiner
-            // Shape: [batch=1, time=1, features]
-            let encoder_tensor: Tensor = tract_ndarray::Array3::from_shape_vec(
-                (1, 1, encoder_dim),
-                encoder_frame,
-            )
+        frames: Range<usize>,
+        state: DecoderState,
+        beam_width: usize,
+    ) -> Result<(Vec<DecodedToken>, DecoderState), String> {
+        let vocab_size = self.vocab.len();
+        let model = self.joint.lock().map_err(|e| e.to_string())?;
+        let joint = OnnxJoint {
+            model: &model,
+            encoder_out,
+        };
+
+        if beam_width > 1 {
+            tdt_beam_decode(frames, vocab_size, self.blank_id, state, beam_width, joint)
+        } else {
+            tdt_greedy_decode(frames, vocab_size, self.blank_id, state, joint)
+        }
+    }
+}
+
+fn load_onnx(path: &Path, name: &str) -> Result<TractModel, String> {
+    tract_onnx::onnx()
+        .model_for_path(path)
+        .map_err(|e| format!("Failed to load {} model: {}", name, e))?
+        .into_optimized()
+        .map_err(|e| format!("Failed to optimize {}: {}", name, e))?
+        .into_runnable()
+        .map_err(|e| format!("Failed to make {} runnable: {}", name, e))
+}
+
+/// Rôle d'une entrée du décodeur ou du joiner, déduit de son nom ONNX
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JointInput {
+    /// Frame de l'encodeur
+    Encoder,
+    /// Dernier token émis
+    Targets,
+    /// Longueur de `Targets` (toujours 1)
+    TargetLength,
+    /// Sortie du réseau de prédiction (joiner séparé)
+    Prediction,
+    /// État LSTM du réseau de prédiction
+    State,
+}
+
+impl JointInput {
+    fn from_name(name: &str) -> Self {
+        let name = name.to_lowercase();
+        if name.contains("encoder") {
+            JointInput::Encoder
+        } else if name.contains("state") {
+            JointInput::State
+        } else if name.contains("length") {
+            JointInput::TargetLength
+        } else if name.contains("decoder") || name.contains("pred") {
+            JointInput::Prediction
+        } else {
+            JointInput::Targets
+        }
+    }
+}
+
+/// Entrée d'un modèle ONNX (dimensions symboliques ramenées à 1)
+struct InputSpec {
+    role: JointInput,
+    datum_type: DatumType,
+    shape: Vec<usize>,
+}
+
+/// Entrées et sorties utiles d'un modèle ONNX du décodeur
+struct ModelIo {
+    inputs: Vec<InputSpec>,
+    /// Sortie principale (logits ou sortie du prédicteur)
+    output: usize,
+    /// Sorties des états LSTM, dans l'ordre des entrées `State`
+    state_outputs: Vec<usize>,
+}
+
+impl ModelIo {
+    fn inspect(plan: &TractModel) -> Result<Self, String> {
+        let model = plan.model();
+
+        let mut inputs = Vec::new();
+        for outlet in model.input_outlets().map_err(|e| e.to_string())? {
+            let fact = model.outlet_fact(*outlet).map_err(|e| e.to_string())?;
+            inputs.push(InputSpec {
+                role: JointInput::from_name(&model.node(outlet.node).name),
+                datum_type: fact.datum_type,
+                shape: fact
+                    .shape
+                    .iter()
+                    .map(|dim| dim.to_usize().unwrap_or(1))
+                    .collect(),
+            });
+        }
+
+        let mut output = None;
+        let mut state_outputs = Vec::new();
+        for (i, outlet) in model
+            .output_outlets()
+            .map_err(|e| e.to_string())?
+            .iter()
+            .enumerate()
+        {
+            let name = model
+                .outlet_label(*outlet)
+                .unwrap_or(model.node(outlet.node).name.as_str())
+                .to_lowercase();
+            if name.contains("state") {
+                state_outputs.push(i);
+            } else if !name.contains("length") && output.is_none() {
+                output = Some(i);
+            }
+        }
+
+        Ok(Self {
+            inputs,
+            output: output.unwrap_or(0),
+            state_outputs,
+        })
+    }
+
+    /// États LSTM nuls, un par entrée `State`
+    fn zero_states(&self) -> Vec<Tensor> {
+        self.inputs
+            .iter()
+            .filter(|input| input.role == JointInput::State)
+            .map(|input| Tensor::zero_dt(input.datum_type, &input.shape))
+            .collect::<TractResult<Vec<_>>>()
+            .unwrap_or_default()
+    }
+}
+
+/// Réseau de prédiction et joiner : un seul modèle ONNX (`decoder_joint`)
+/// ou deux modèles séparés, ce qui permet de ne pas réexécuter le
+/// prédicteur tant qu'aucun token n'est émis
+struct JointModel {
+    kind: JointKind,
+    /// États LSTM initiaux (vide si l'export n'expose pas d'état)
+    initial_states: Vec<Tensor>,
+}
+
+enum JointKind {
+    Fused {
+        plan: TractModel,
+        io: ModelIo,
+    },
+    Split {
+        decoder: TractModel,
+        decoder_io: ModelIo,
+        joiner: TractModel,
+        joiner_io: ModelIo,
+    },
+}
+
+impl JointModel {
+    fn fused(plan: TractModel) -> Result<Self, String> {
+        let io = ModelIo::inspect(&plan)?;
+        Ok(Self {
+            initial_states: io.zero_states(),
+            kind: JointKind::Fused { plan, io },
+        })
+    }
+
+    fn split(decoder: TractModel, joiner: TractModel) -> Result<Self, String> {
+        let decoder_io = ModelIo::inspect(&decoder)?;
+        let joiner_io = ModelIo::inspect(&joiner)?;
+        Ok(Self {
+            initial_states: decoder_io.zero_states(),
+            kind: JointKind::Split {
+                decoder,
+                decoder_io,
+                joiner,
+                joiner_io,
+            },
+        })
+    }
+}
+
+/// Sortie du réseau de prédiction pour le dernier token de l'état
+#[derive(Clone)]
+struct PredictorOutput {
+    /// Sortie à donner au joiner (modèles séparés uniquement)
+    prediction: Option<TValue>,
+    /// États LSTM après le dernier token
+    states: Vec<Tensor>,
+}
+
+/// Historique du décodeur : dernier token émis et états LSTM qui le précèdent.
+/// La sortie du prédicteur est calculée au premier pas puis réutilisée sur
+/// les frames suivantes tant que seuls des blanks sont prédits.
+#[derive(Clone)]
+struct DecoderState {
+    last_token: i64,
+    states: Vec<Tensor>,
+    cache: Option<PredictorOutput>,
+    /// Faux : les états restent nuls, seul le dernier token est transmis
+    /// (comportement sans historique, gardé pour comparaison)
+    keep_history: bool,
+}
+
+impl DecoderState {
+    fn new(last_token: i64, states: Vec<Tensor>, keep_history: bool) -> Self {
+        Self {
+            last_token,
+            states,
+            cache: None,
+            keep_history,
+        }
+    }
+
+    /// Avance l'historique après l'émission d'un token non blank
+    fn emit(&mut self, token: i64) {
+        if let Some(cache) = self.cache.take() {
+            if self.keep_history && cache.states.len() == self.states.len() {
+                self.states = cache.states;
+            }
+        }
+        self.last_token = token;
+    }
+}
+
+/// Calcul des logits (vocabulaire puis durées) pour une frame et un état
+trait Joint {
+    fn logits(&mut self, t: usize, state: &mut DecoderState) -> Result<Vec<f32>, String>;
+}
+
+/// Joiner sans état, qui ne dépend que du dernier token
+impl<F> Joint for F
+where
+    F: FnMut(usize, i64) -> Result<Vec<f32>, String>,
+{
+    fn logits(&mut self, t: usize, state: &mut DecoderState) -> Result<Vec<f32>, String> {
+        self(t, state.last_token)
+    }
+}
+
+/// Joiner ONNX appliqué à la sortie de l'encodeur `[1, frames, dim]`
+struct OnnxJoint<'a> {
+    model: &'a JointModel,
+    encoder_out: &'a tract_ndarray::ArrayD<f32>,
+}
+
+impl OnnxJoint<'_> {
+    /// Frame `t` de l'encodeur, au format attendu par l'entrée
+    /// (`[1, 1, dim]` ou `[1, dim, 1]`)
+    fn encoder_frame(&self, t: usize, spec: &InputSpec) -> Result<Tensor, String> {
+        let encoder_dim = self.encoder_out.shape()[2];
+        let frame: Vec<f32> = (0..encoder_dim)
+            .map(|d| self.encoder_out[[0, t, d]])
+            .collect();
+
+        let shape = if spec.shape.get(1) == Some(&encoder_dim) {
+            (1, encoder_dim, 1)
+        } else {
+            (1, 1, encoder_dim)
+        };
+
+        Ok(tract_ndarray::Array3::from_shape_vec(shape, frame)
             .map_err(|e| format!("Encoder tensor error: {}", e))?
-            .into();
-
-            // Decoder input: previous token
-            let decoder_input: Tensor = tract_ndarray::Array2::from_shape_vec(
-                (1, 1),
-                vec![last_token],
-            )
-            .map_err(|e| format!("Decoder input error: {}", e))?
-            .into();
-
-            // Run decoder+joiner
-            let inputs = tvec![encoder_tensor.into(), decoder_input.into()];
-            let outputs = decoder_joint
-                .run(inputs)
-                .map_err(|e| format!("Decoder+joiner error: {}", e))?;
-
-            let logits = outputs[0]
-                .to_array_view::<f32>()
-                .map_err(|e| format!("Output error: {}", e))?;
-
-            // Find argmax
-            let mut max_idx = 0i64;
-            let mut max_val = f32::NEG_INFINITY;
-            for (i, &val) in logits.iter().enumerate() {
-                if val > max_val {
-                    max_val = val;
-                    max_idx = i as i64;
+            .into())
+    }
+
+    /// Entier dans le type et la forme attendus par l'entrée
+    fn int_input(spec: &InputSpec, value: i64) -> Result<Tensor, String> {
+        let shape = if spec.shape.is_empty() { vec![1] } else { spec.shape.clone() };
+        Tensor::from(tract_ndarray::ArrayD::from_elem(shape, value))
+            .cast_to_dt(spec.datum_type)
+            .map(|tensor| tensor.into_owned())
+            .map_err(|e| format!("Decoder input error: {}", e))
+    }
+
+    /// Entrées du modèle, dans l'ordre ONNX
+    fn inputs(
+        &self,
+        io: &ModelIo,
+        t: usize,
+        state: &DecoderState,
+        prediction: Option<&TValue>,
+    ) -> Result<TVec<TValue>, String> {
+        let mut states = state.states.iter();
+        let mut inputs = TVec::new();
+
+        for spec in &io.inputs {
+            let input: TValue = match spec.role {
+                JointInput::Encoder => self.encoder_frame(t, spec)?.into(),
+                JointInput::Targets => Self::int_input(spec, state.last_token)?.into(),
+                JointInput::TargetLength => Self::int_input(spec, 1)?.into(),
+                JointInput::Prediction => prediction
+                    .cloned()
+                    .ok_or("Missing prediction network output")?,
+                JointInput::State => states
+                    .next()
+                    .cloned()
+                    .ok_or("Missing decoder state")?
+                    .into(),
+            };
+            inputs.push(input);
+        }
+
+        Ok(inputs)
+    }
+
+    fn run(plan: &TractModel, inputs: TVec<TValue>) -> Result<TVec<TValue>, String> {
+        plan.run(inputs)
+            .map_err(|e| format!("Decoder+joiner error: {}", e))
+    }
+
+    fn states(io: &ModelIo, outputs: &TVec<TValue>) -> Vec<Tensor> {
+        io.state_outputs
+            .iter()
+            .filter_map(|&i| outputs.get(i))
+            .map(|value| value.clone().into_tensor())
+            .collect()
+    }
+}
+
+impl Joint for OnnxJoint<'_> {
+    fn logits(&mut self, t: usize, state: &mut DecoderState) -> Result<Vec<f32>, String> {
+        let outputs = match &self.model.kind {
+            JointKind::Fused { plan, io } => {
+                let outputs = Self::run(plan, self.inputs(io, t, state, None)?)?;
+                if state.cache.is_none() {
+                    state.cache = Some(PredictorOutput {
+                        prediction: None,
+                        states: Self::states(io, &outputs),
+                    });
+                }
+                outputs
+                    .get(io.output)
+                    .cloned()
+                    .ok_or("Missing decoder+joiner output")?
+            }
+            JointKind::Split {
+                decoder,
+                decoder_io,
+                joiner,
+                joiner_io,
+            } => {
+                if state.cache.is_none() {
+                    let outputs = Self::run(decoder, self.inputs(decoder_io, t, state, None)?)?;
+                    state.cache = Some(PredictorOutput {
+                        prediction: outputs.get(decoder_io.output).cloned(),
+                        states: Self::states(decoder_io, &outputs),
+                    });
                 }
+                let prediction = state.cache.as_ref().and_then(|c| c.prediction.as_ref());
+                let outputs = Self::run(joiner, self.inputs(joiner_io, t, state, prediction)?)?;
+                outputs
+                    .get(joiner_io.output)
+                    .cloned()
+                    .ok_or("Missing joiner output")?
+            }
+        };
+
+        let logits = outputs
+            .to_array_view::<f32>()
+            .map_err(|e| format!("Output error: {}", e))?;
+
+        Ok(logits.iter().copied().collect())
+    }
+}
+
+/// Log-probabilités (log-softmax) des logits
+fn log_softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let log_sum = logits.iter().map(|&v| (v - max).exp()).sum::<f32>().ln() + max;
+    logits.iter().map(|&v| v - log_sum).collect()
+}
+
+/// Les `k` meilleurs indices, par score décroissant
+fn top_k(scores: &[f32], k: usize) -> Vec<(usize, f32)> {
+    let mut indexed: Vec<(usize, f32)> = scores.iter().copied().enumerate().collect();
+    indexed.sort_by(|a, b| b.1.total_cmp(&a.1));
+    indexed.truncate(k);
+    indexed
+}
+
+/// Durées candidates (en frames) et leurs log-probabilités ; sans tête de
+/// durée (modèle RNN-T classique), on avance d'une frame par token
+fn duration_log_probs(duration_logits: &[f32]) -> Vec<(usize, f32)> {
+    if duration_logits.is_empty() {
+        return vec![(1, 0.0)];
+    }
+    log_softmax(duration_logits)
+        .into_iter()
+        .enumerate()
+        .map(|(i, log_prob)| (TDT_DURATIONS.get(i).copied().unwrap_or(1), log_prob))
+        .collect()
+}
+
+/// Décodage TDT glouton : plusieurs tokens peuvent être émis sur la même
+/// frame (durée prédite nulle), puis on saute le nombre de frames prédit.
+/// Un blank de durée nulle, ou plus de `MAX_SYMBOLS_PER_FRAME` tokens sur une
+/// frame, force l'avance d'une frame pour garantir la terminaison.
+fn tdt_greedy_decode<J: Joint>(
+    frames: Range<usize>,
+    vocab_size: usize,
+    blank_id: i64,
+    mut state: DecoderState,
+    mut joint: J,
+) -> Result<(Vec<DecodedToken>, DecoderState), String> {
+    let mut decoded_tokens = Vec::new();
+    let mut t = frames.start;
+
+    while t < frames.end {
+        let mut symbols = 0;
+
+        loop {
+            let logits = joint.logits(t, &mut state)?;
+            let (token_logits, duration_logits) = logits.split_at(vocab_size.min(logits.len()));
+
+            let (id, probability) = ParakeetModel::argmax_softmax(token_logits);
+            let id = id as i64;
+            let duration = duration_log_probs(duration_logits)
+                .into_iter()
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map_or(1, |(duration, _)| duration);
+
+            if id != blank_id {
+                decoded_tokens.push(DecodedToken {
+                    id,
+                    frame: t,
+                    duration,
+                    probability,
+                });
+                state.emit(id);
+                symbols += 1;
             }
 
-            if max_idx != self.blank_id {
-                decoded_tokens.push(max_idx);
-                last_token = max_idx;
+            if duration > 0 {
+                t += duration;
+                break;
+            }
+            if id == blank_id || symbols >= MAX_SYMBOLS_PER_FRAME {
+                t += 1;
+                break;
             }
         }
+    }
 
-        Ok(decoded_tokens)
+    Ok((decoded_tokens, state))
+}
+
+/// Hypothèse de la recherche en faisceau
+#[derive(Clone)]
+struct BeamHypothesis {
+    tokens: Vec<DecodedToken>,
+    state: DecoderState,
+    frame: usize,
+    /// Tokens déjà émis sur `frame`
+    symbols: usize,
+    score: f32,
+}
+
+/// Recherche en faisceau TDT : chaque hypothèse avance à son propre rythme,
+/// étendue par les `beam_width` meilleurs tokens et durées. Les hypothèses
+/// identiques (mêmes tokens, même position) sont fusionnées.
+fn tdt_beam_decode<J: Joint>(
+    frames: Range<usize>,
+    vocab_size: usize,
+    blank_id: i64,
+    state: DecoderState,
+    beam_width: usize,
+    mut joint: J,
+) -> Result<(Vec<DecodedToken>, DecoderState), String> {
+    if frames.is_empty() {
+        return Ok((Vec::new(), state));
     }
+
+    let mut beam = vec![BeamHypothesis {
+        tokens: Vec::new(),
+        state,
+        frame: frames.start,
+        symbols: 0,
+        score: 0.0,
+    }];
+    let mut best: Option<BeamHypothesis> = None;
+
+    while !beam.is_empty() {
+        let mut candidates = Vec::new();
+
+        for hyp in &mut beam {
+            let logits = joint.logits(hyp.frame, &mut hyp.state)?;
+            let (token_logits, duration_logits) = logits.split_at(vocab_size.min(logits.len()));
+            let durations = duration_log_probs(duration_logits);
+
+            for (id, token_log_prob) in top_k(&log_softmax(token_logits), beam_width) {
+                let id = id as i64;
+                for &(duration, duration_log_prob) in durations.iter() {
+                    let mut next = hyp.clone();
+                    next.score += token_log_prob + duration_log_prob;
+
+                    if id != blank_id {
+                        next.tokens.push(DecodedToken {
+                            id,
+                            frame: hyp.frame,
+                            duration,
+                            probability: token_log_prob.exp(),
+                        });
+                        next.state.emit(id);
+                        next.symbols += 1;
+                    }
+
+                    let advance = if duration == 0
+                        && (id == blank_id || next.symbols >= MAX_SYMBOLS_PER_FRAME)
+                    {
+                        1
+                    } else {
+                        duration
+                    };
+                    if advance > 0 {
+                        next.frame += advance;
+                        next.symbols = 0;
+                    }
+
+                    candidates.push(next);
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let mut seen = std::collections::HashSet::new();
+        beam = Vec::with_capacity(beam_width);
+        for hyp in candidates {
+            if beam.len() >= beam_width {
+                break;
+            }
+            let key = (
+                hyp.frame,
+                hyp.symbols,
+                hyp.tokens.iter().map(|token| token.id).collect::<Vec<_>>(),
+            );
+            if !seen.insert(key) {
+                continue;
+            }
+            if hyp.frame >= frames.end {
+                if best.as_ref().is_none_or(|b| hyp.score > b.score) {
+                    best = Some(hyp);
+                }
+            } else {
+                beam.push(hyp);
+            }
+        }
+
+        // Les scores ne font que baisser : inutile de prolonger les hypothèses
+        // déjà moins bonnes que la meilleure terminée
+        if let Some(ref best) = best {
+            beam.retain(|hyp| hyp.score > best.score);
+        }
+    }
+
+    best.map(|hyp| (hyp.tokens, hyp.state))
+        .ok_or_else(|| "Beam search ended without hypothesis".to_string())
 }
 
 impl SpeechEngine for ParakeetEngine {
     fn transcribe(&self, audio: &[f32], sample_rate: u32) -> Result<TranscriptionResult, String> {
-        let start_time = std::time::Instant::now();
-
         if sample_rate != 16000 {
             return Err(format!(
                 "Invalid sample rate: {}Hz (expected 16000Hz)",
@@ -364,78 +1085,402 @@ impl SpeechEngine for ParakeetEngine {
             ));
         }
 
-        let duration_seconds = audio.len() as f32 / sample_rate as f32;
+        let mut result = self.model.transcribe(
+            audio,
+            &self.vocabulary,
+            self.model_display_name(),
+            self.beam_width,
+        )?;
+        result.detected_language = self.language.resolve(audio);
+        Ok(result)
+    }
 
-        if duration_seconds < 0.1 {
-            return Err("Audio too short".to_string());
-        }
+    fn name(&self) -> &str {
+        "Parakeet"
+    }
 
-        // Compute mel-spectrogram features
-        let n_mels = 80;
-        let features = self.compute_features(audio, sample_rate);
-        let num_frames = features.len() / n_mels;
+    fn model_display_name(&self) -> String {
+        let name = self.custom_name.as_deref().unwrap_or(self.model_size.display_name());
+        format!("Parakeet {}", name)
+    }
 
-        // Create encoder input tensor [batch, time, features]
-        // Note: features is [n_mels, num_frames], need to transpose to [num_frames, n_mels]
-        let mut transposed = vec![0.0f32; num_frames * n_mels];
-        for mel in 0..n_mels {
-            for frame in 0..num_frames {
-                transposed[frame * n_mels + mel] = features[mel * num_frames + frame];
+    fn set_vocabulary(&mut self, words: &[String]) {
+        self.vocabulary = Vocabulary::new(words);
+    }
+
+    /// Le modèle reconnaît lui-même la langue parmi celles qu'il couvre : la
+    /// langue choisie ne sert qu'à étiqueter les transcriptions
+    fn set_language(&mut self, language: Option<String>) -> Result<(), String> {
+        let language = language.filter(|lang| lang != "auto");
+        if let Some(ref lang) = language {
+            if !SUPPORTED_LANGUAGES.contains(&lang.as_str()) {
+                return Err(format!("Language {} not supported by Parakeet", lang));
             }
         }
+        self.language.language = language;
+        Ok(())
+    }
 
-        let features_tensor: Tensor = tract_ndarray::Array3::from_shape_vec(
-            (1, num_frames, n_mels),
-            transposed,
-        )
-        .map_err(|e| format!("Failed to create features tensor: {}", e))?
-        .into();
+    fn detect_language(&self, audio: &[f32], sample_rate: u32) -> Result<Option<String>, String> {
+        match self.language.identifier {
+            Some(ref identifier) if sample_rate == 16000 => identifier.detect(audio),
+            Some(_) => Err(format!(
+                "Invalid sample rate: {}Hz (expected 16000Hz)",
+                sample_rate
+            )),
+            None => Ok(None),
+        }
+    }
 
-        // Run encoder
-        let encoder = self.encoder.lock().map_err(|e| e.to_string())?;
-        let encoder_outputs = encoder
-            .run(tvec![features_tensor.into()])
-            .map_err(|e| format!("Encoder error: {}", e))?;
+    fn start_streaming_session(&self) -> Option<Box<dyn StreamingSession>> {
+        Some(Box::new(ParakeetStreamingSession {
+            model: self.model.clone(),
+            vocabulary: self.vocabulary.clone(),
+            model_used: self.model_display_name(),
+            beam_width: self.beam_width,
+            language: self.language.clone(),
+            audio: Vec::new(),
+            committed_samples: 0,
+            decoder: self.model.initial_state(),
+            words: Vec::new(),
+            reported_words: 0,
+            last_partial: String::new(),
+        }))
+    }
+}
 
-        let encoder_out = encoder_outputs[0]
-            .to_array_view::<f32>()
-            .map_err(|e| format!("Encoder output error: {}", e))?;
+unsafe impl Send for ParakeetEngine {}
+unsafe impl Sync for ParakeetEngine {}
+unsafe impl Send for ParakeetModel {}
+unsafe impl Sync for ParakeetModel {}
+
+/// Durée validée à chaque passe du streaming
+const STREAM_CHUNK_SECS: f32 = 2.0;
+
+/// Audio précédant le morceau, donné à l'encodeur comme contexte
+const STREAM_LEFT_CONTEXT_SECS: f32 = 1.0;
+
+/// Audio suivant le morceau, attendu avant de le valider
+const STREAM_LOOKAHEAD_SECS: f32 = 0.5;
+
+/// Streaming par morceaux : chaque morceau est encodé avec un peu de contexte
+/// de part et d'autre, et le décodeur reprend avec l'état du dernier token
+/// validé au lieu de tout retranscrire. La fin non validée est décodée pour
+/// l'affichage.
+pub struct ParakeetStreamingSession {
+    model: Arc<ParakeetModel>,
+    vocabulary: Vocabulary,
+    model_used: String,
+    beam_width: usize,
+    language: LanguageTag,
+    audio: Vec<f32>,
+    /// Fin (échantillons) de l'audio déjà décodé et validé
+    committed_samples: usize,
+    /// État du décodeur après le dernier token validé
+    decoder: DecoderState,
+    /// Mots validés (le dernier peut encore être complété par un sous-mot)
+    words: Vec<WordTiming>,
+    /// Nombre de mots déjà retournés par `poll_partial`
+    reported_words: usize,
+    last_partial: String,
+}
 
-        let encoder_out_owned = encoder_out.to_owned().into_dyn();
+impl ParakeetStreamingSession {
+    fn samples(secs: f32) -> usize {
+        (secs * 16000.0) as usize
+    }
 
-        drop(encoder);
+    /// Encode `audio[from..to]` précédé du contexte gauche et décode, à partir
+    /// de l'état validé, les frames entre `from` et `keep_until`. Retourne les
+    /// tokens, la durée d'une frame, la position de la fenêtre (secondes) et
+    /// l'état du décodeur après le dernier token.
+    fn decode_span(
+        &self,
+        from: usize,
+        to: usize,
+        keep_until: usize,
+    ) -> Result<(Vec<DecodedToken>, f32, f32, DecoderState), String> {
+        let start = from.saturating_sub(Self::samples(STREAM_LEFT_CONTEXT_SECS));
+        let window = &self.audio[start..to];
+        let offset_secs = start as f32 / 16000.0;
+
+        if window.len() < Self::samples(0.1) {
+            return Ok((Vec::new(), 0.0, offset_secs, self.decoder.clone()));
+        }
 
-        // Greedy decode
-        let token_ids = self.greedy_decode(&encoder_out_owned)?;
-        let text = self.decode_tokens(&token_ids);
+        let encoder_out = self.model.encode(window)?;
+        let time_steps = encoder_out.shape()[1];
+        let frame_secs = window.len() as f32 / 16000.0 / time_steps.max(1) as f32;
 
-        let processing_time_ms = start_time.elapsed().as_millis() as u64;
+        // Le contexte gauche sert à l'encodeur ; ses tokens sont déjà validés
+        let frame_at = |sample: usize| {
+            let secs = sample.saturating_sub(start) as f32 / 16000.0;
+            ((secs / frame_secs).round() as usize).min(time_steps)
+        };
+        let frames = frame_at(from)..frame_at(keep_until.min(to));
 
-        log::info!(
-            "Parakeet transcription completed in {}ms: {} chars",
-            processing_time_ms,
-            text.len()
-        );
+        let (tokens, decoder) =
+            self.model
+                .decode(&encoder_out, frames, self.decoder.clone(), self.beam_width)?;
 
-        Ok(TranscriptionResult {
-            text,
-            confidence: 0.9,
-            duration_seconds,
-            processing_time_ms,
-            detected_language: Some("auto".to_string()),
-            timestamp: Utc::now().timestamp(),
-            model_used: Some(self.model_display_name()),
-        })
+        Ok((tokens, frame_secs, offset_secs, decoder))
     }
 
-    fn name(&self) -> &str {
-        "Parakeet"
+    fn join(words: &[WordTiming]) -> String {
+        words
+            .iter()
+            .map(|w| w.word.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl StreamingSession for ParakeetStreamingSession {
+    fn push_audio(&mut self, audio: &[f32]) -> Result<(), String> {
+        self.audio.extend_from_slice(audio);
+        Ok(())
     }
 
-    fn model_display_name(&self) -> String {
-        format!("Parakeet {}", self.model_size.display_name())
+    fn poll_partial(&mut self) -> Result<Option<StreamingUpdate>, String> {
+        let chunk = Self::samples(STREAM_CHUNK_SECS);
+        let lookahead = Self::samples(STREAM_LOOKAHEAD_SECS);
+
+        // Valider les morceaux complets
+        while self.audio.len() >= self.committed_samples + chunk + lookahead {
+            let end = self.committed_samples + chunk;
+            let (tokens, frame_secs, offset_secs, decoder) =
+                self.decode_span(self.committed_samples, end + lookahead, end)?;
+
+            self.decoder = decoder;
+            self.model
+                .push_words(&mut self.words, &tokens, frame_secs, offset_secs);
+            self.committed_samples = end;
+        }
+
+        // Le dernier mot validé peut encore recevoir un sous-mot : il reste provisoire
+        let stable = self.words.len().saturating_sub(1).max(self.reported_words);
+        let committed = Self::join(&self.words[self.reported_words..stable]);
+        self.reported_words = stable;
+
+        // Fin provisoire
+        let mut tail = self.words[stable..].to_vec();
+        let (tokens, frame_secs, offset_secs, _) =
+            self.decode_span(self.committed_samples, self.audio.len(), usize::MAX)?;
+        self.model
+            .push_words(&mut tail, &tokens, frame_secs, offset_secs);
+        let partial = self.vocabulary.correct(&Self::join(&tail));
+
+        if committed.is_empty() && partial == self.last_partial {
+            return Ok(None);
+        }
+        self.last_partial = partial.clone();
+
+        Ok(Some(StreamingUpdate {
+            committed: self.vocabulary.correct(&committed),
+            partial,
+        }))
+    }
+
+    fn committed_text(&self) -> String {
+        self.vocabulary
+            .correct(&Self::join(&self.words[..self.reported_words]))
+    }
+
+    /// La transcription finale est refaite sur tout l'enregistrement, comme
+    /// en mode non streaming
+    fn finish(self: Box<Self>) -> Result<TranscriptionResult, String> {
+        let mut result = self.model.transcribe(
+            &self.audio,
+            &self.vocabulary,
+            self.model_used,
+            self.beam_width,
+        )?;
+        result.detected_language = self.language.resolve(&self.audio);
+        Ok(result)
     }
 }
 
-unsafe impl Send for ParakeetEngine {}
-unsafe impl Sync for ParakeetEngine {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const BLANK: i64 = 0;
+    const VOCAB_SIZE: usize = 3;
+
+    fn start() -> DecoderState {
+        DecoderState::new(BLANK, Vec::new(), true)
+    }
+
+    /// Sortie factice du joiner : probabilités des tokens, durée quasi certaine
+    fn joint_output(token_probs: [f32; VOCAB_SIZE], duration: usize) -> Vec<f32> {
+        let mut logits: Vec<f32> = token_probs.iter().map(|p| p.ln()).collect();
+        logits.extend(TDT_DURATIONS.iter().map(|&d| if d == duration { 10.0 } else { -10.0 }));
+        logits
+    }
+
+    #[test]
+    fn test_greedy_emits_several_tokens_per_frame_and_skips_frames() {
+        let mut visited = Vec::new();
+        let (tokens, _) = tdt_greedy_decode(0..6, VOCAB_SIZE, BLANK, start(), |t, last| {
+            visited.push(t);
+            Ok(match (t, last) {
+                (0, BLANK) => joint_output([0.1, 0.8, 0.1], 0),
+                (0, 1) => joint_output([0.1, 0.1, 0.8], 3),
+                _ => joint_output([0.8, 0.1, 0.1], 2),
+            })
+        })
+        .unwrap();
+
+        let emitted: Vec<(i64, usize)> = tokens.iter().map(|t| (t.id, t.frame)).collect();
+        assert_eq!(emitted, vec![(1, 0), (2, 0)]);
+        assert_eq!(visited, vec![0, 0, 3, 5]);
+    }
+
+    #[test]
+    fn test_greedy_max_symbols_guard_terminates() {
+        let (tokens, _) = tdt_greedy_decode(0..3, VOCAB_SIZE, BLANK, start(), |_, _| {
+            Ok(joint_output([0.1, 0.8, 0.1], 0))
+        })
+        .unwrap();
+
+        assert_eq!(tokens.len(), 3 * MAX_SYMBOLS_PER_FRAME);
+    }
+
+    #[test]
+    fn test_beam_search_recovers_word_missed_by_greedy() {
+        let joint = |t: usize, last: i64| {
+            Ok(match (t, last) {
+                (0, _) => joint_output([0.55, 0.45, 0.0001], 1),
+                (_, BLANK) => joint_output([0.4, 0.3, 0.3], 1),
+                _ => joint_output([0.99, 0.005, 0.005], 1),
+            })
+        };
+
+        let (greedy, _) = tdt_greedy_decode(0..2, VOCAB_SIZE, BLANK, start(), joint).unwrap();
+        assert!(greedy.is_empty());
+
+        let (beam, state) = tdt_beam_decode(0..2, VOCAB_SIZE, BLANK, start(), 2, joint).unwrap();
+        let ids: Vec<i64> = beam.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![1]);
+        assert_eq!(state.last_token, 1);
+    }
+
+    /// Prédicteur factice : son état compte les tokens émis
+    struct CountingPredictor<'a> {
+        runs: &'a Cell<usize>,
+    }
+
+    impl Joint for CountingPredictor<'_> {
+        fn logits(&mut self, t: usize, state: &mut DecoderState) -> Result<Vec<f32>, String> {
+            if state.cache.is_none() {
+                self.runs.set(self.runs.get() + 1);
+                let count = state.states[0].as_slice::<f32>().unwrap()[0] + 1.0;
+                state.cache = Some(PredictorOutput {
+                    prediction: None,
+                    states: vec![tensor0(count)],
+                });
+            }
+            // Un token toutes les trois frames, blanks sinon
+            Ok(if [0, 3, 6].contains(&t) {
+                joint_output([0.1, 0.8, 0.1], 1)
+            } else {
+                joint_output([0.8, 0.1, 0.1], 1)
+            })
+        }
+    }
+
+    #[test]
+    fn test_decoder_state_is_carried_and_reused_across_blanks() {
+        let runs = Cell::new(0);
+        let state = DecoderState::new(BLANK, vec![tensor0(0.0f32)], true);
+        let predictor = CountingPredictor { runs: &runs };
+        let (tokens, state) = tdt_greedy_decode(0..9, VOCAB_SIZE, BLANK, state, predictor).unwrap();
+
+        assert_eq!(tokens.len(), 3);
+        // Une exécution par token émis, plus celle du dernier état
+        assert_eq!(runs.get(), 4);
+        assert_eq!(state.states[0].as_slice::<f32>().unwrap()[0], 3.0);
+
+        // Sans historique, les états restent ceux de départ
+        let state = DecoderState::new(BLANK, vec![tensor0(0.0f32)], false);
+        let predictor = CountingPredictor { runs: &runs };
+        let (_, state) = tdt_greedy_decode(0..9, VOCAB_SIZE, BLANK, state, predictor).unwrap();
+        assert_eq!(state.states[0].as_slice::<f32>().unwrap()[0], 0.0);
+    }
+
+    /// Taux d'erreur sur les mots (distance d'édition / mots de référence)
+    fn word_error_rate(reference: &str, hypothesis: &str) -> f32 {
+        let normalize = |text: &str| -> Vec<String> {
+            text.split_whitespace()
+                .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+                .filter(|w| !w.is_empty())
+                .collect()
+        };
+        let reference = normalize(reference);
+        let hypothesis = normalize(hypothesis);
+
+        let mut previous: Vec<usize> = (0..=hypothesis.len()).collect();
+        for (i, ref_word) in reference.iter().enumerate() {
+            let mut current = vec![i + 1; hypothesis.len() + 1];
+            for (j, hyp_word) in hypothesis.iter().enumerate() {
+                let substitution = previous[j] + usize::from(ref_word != hyp_word);
+                current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            }
+            previous = current;
+        }
+
+        previous[hypothesis.len()] as f32 / reference.len().max(1) as f32
+    }
+
+    /// Compare le décodeur sans historique au décodeur avec état sur
+    /// l'enregistrement de référence (`tests/fixtures/parakeet_benchmark.wav`,
+    /// 16 kHz mono, transcription attendue dans le `.txt` voisin) :
+    /// `PHONON_PARAKEET_MODEL=<modèle> cargo test parakeet_decoder_benchmark --
+    /// --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn parakeet_decoder_benchmark() {
+        let model_dir = std::env::var("PHONON_PARAKEET_MODEL")
+            .expect("PHONON_PARAKEET_MODEL must point to a Parakeet TDT 0.6B v3 model");
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let wav_path = fixtures.join("parakeet_benchmark.wav");
+        let reference = fs::read_to_string(fixtures.join("parakeet_benchmark.txt"))
+            .unwrap_or_else(|e| panic!("Benchmark transcript missing in {:?}: {}", fixtures, e));
+
+        let mut reader = hound::WavReader::open(&wav_path)
+            .unwrap_or_else(|e| panic!("Benchmark clip {:?} unreadable: {}", wav_path, e));
+        let spec = reader.spec();
+        assert_eq!((spec.sample_rate, spec.channels), (16000, 1));
+        let audio: Vec<f32> = match spec.sample_format {
+            hound::SampleFormat::Float => reader.samples::<f32>().map(|s| s.unwrap()).collect(),
+            hound::SampleFormat::Int => reader
+                .samples::<i32>()
+                .map(|s| s.unwrap() as f32 / (1i64 << (spec.bits_per_sample - 1)) as f32)
+                .collect(),
+        };
+
+        let engine =
+            ParakeetEngine::new(Path::new(&model_dir), ParakeetModelSize::Tdt06bV3).unwrap();
+        let model = &engine.model;
+        let encoder_out = model.encode(&audio).unwrap();
+        let frames = 0..encoder_out.shape()[1];
+
+        let mut error_rates = Vec::new();
+        for (label, keep_history) in [("stateless", false), ("stateful", true)] {
+            let mut state = model.initial_state();
+            state.keep_history = keep_history;
+
+            let start = std::time::Instant::now();
+            let (tokens, _) = model.decode(&encoder_out, frames.clone(), state, 1).unwrap();
+            let elapsed = start.elapsed();
+
+            let text = model.decode_tokens(&tokens);
+            let wer = word_error_rate(&reference, &text);
+            println!("{label}: WER {:.1}%, decoding {:?}\n  {text}", wer * 100.0, elapsed);
+            error_rates.push(wer);
+        }
+
+        assert!(error_rates[1] <= error_rates[0]);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::engines::traits::SpeechEngine;
+use crate::engines::vocabulary::Vocabulary;
 use crate::types::TranscriptionResult;
 use chrono::Utc;
 use serde::Deserialize;
@@ -16,6 +17,7 @@ struct SidecarOutput {
 
 pub struct ParakeetCoreMLEngine {
     sidecar_path: PathBuf,
+    vocabulary: Vocabulary,
 }
 
 impl ParakeetCoreMLEngine {
@@ -28,7 +30,10 @@ impl ParakeetCoreMLEngine {
         }
 
         log::info!("ParakeetCoreMLEngine initialized with sidecar: {:?}", sidecar_path);
-        Ok(Self { sidecar_path })
+        Ok(Self {
+            sidecar_path,
+            vocabulary: Vocabulary::default(),
+        })
     }
 
     fn write_temp_wav(&self, audio: &[f32], sample_rate: u32) -> Result<PathBuf, String> {
@@ -112,13 +117,15 @@ impl SpeechEngine for ParakeetCoreMLEngine {
         );
 
         Ok(TranscriptionResult {
-            text: result.text,
+            text: self.vocabulary.correct(&result.text),
             confidence: result.confidence as f32,
             duration_seconds,
             processing_time_ms,
             detected_language: Some("auto".to_string()),
             timestamp: Utc::now().timestamp(),
             model_used: Some(self.model_display_name()),
+            segments: Vec::new(),
+            low_confidence_spans: Vec::new(),
         })
     }
 
@@ -129,6 +136,10 @@ impl SpeechEngine for ParakeetCoreMLEngine {
     fn model_display_name(&self) -> String {
         "Parakeet TDT 0.6B v3 (CoreML)".to_string()
     }
+
+    fn set_vocabulary(&mut self, words: &[String]) {
+        self.vocabulary = Vocabulary::new(words);
+    }
 }
 
 unsafe impl Send for ParakeetCoreMLEngine {}
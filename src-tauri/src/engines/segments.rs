@@ -0,0 +1,214 @@
+//! Construction des mots et segments horodatés à partir des tokens décodés
+
+use crate::types::{Segment, WordTiming};
+
+/// Écart minimal (secondes) entre deux mots pour couper un segment
+const SEGMENT_GAP_SECS: f32 = 0.8;
+
+/// Ajoute un token sous-mot à la liste de mots.
+///
+/// Un token commençant par un espace ouvre un nouveau mot, sinon il est
+/// rattaché au mot précédent. La probabilité d'un mot est celle de son
+/// token le moins sûr.
+pub fn push_token(words: &mut Vec<WordTiming>, piece: &str, start: f32, end: f32, probability: f32) {
+    let starts_word = piece.starts_with(' ');
+    let piece = piece.trim();
+    if piece.is_empty() {
+        return;
+    }
+
+    match words.last_mut() {
+        Some(last) if !starts_word => {
+            last.word.push_str(piece);
+            last.end = last.end.max(end);
+            last.probability = last.probability.min(probability);
+        }
+        _ => words.push(WordTiming {
+            word: piece.to_string(),
+            start,
+            end: end.max(start),
+            probability,
+        }),
+    }
+}
+
+/// Regroupe des mots en segments, coupés en fin de phrase ou sur une pause
+pub fn group_into_segments(words: Vec<WordTiming>) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut current: Vec<WordTiming> = Vec::new();
+
+    for word in words {
+        if let Some(last) = current.last() {
+            if word.start - last.end >= SEGMENT_GAP_SECS {
+                segments.push(segment_from_words(std::mem::take(&mut current)));
+            }
+        }
+
+        let ends_sentence = word.word.ends_with(['.', '?', '!']);
+        current.push(word);
+
+        if ends_sentence {
+            segments.push(segment_from_words(std::mem::take(&mut current)));
+        }
+    }
+
+    if !current.is_empty() {
+        segments.push(segment_from_words(current));
+    }
+
+    segments
+}
+
+/// Crée un segment couvrant exactement les mots fournis (non vide)
+pub fn segment_from_words(words: Vec<WordTiming>) -> Segment {
+    let start = words.first().map(|w| w.start).unwrap_or(0.0);
+    let end = words.last().map(|w| w.end).unwrap_or(start);
+    let text = words
+        .iter()
+        .map(|w| w.word.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Segment {
+        start,
+        end,
+        text,
+        words,
+    }
+}
+
+/// Ajoute les segments d'une fenêtre transcrite (horodatés relativement à la
+/// fenêtre) à la transcription complète, en retirant ce qui a déjà été
+/// transcrit dans la zone de chevauchement avec la fenêtre précédente.
+pub fn append_chunk_segments(merged: &mut Vec<Segment>, chunk: Vec<Segment>, offset: f32) {
+    let covered_until = merged.last().map(|s| s.end).unwrap_or(f32::MIN);
+
+    for mut segment in chunk {
+        segment.start += offset;
+        segment.end += offset;
+
+        if segment.words.is_empty() {
+            // Moteur sans horodatage par mot : dédoublonnage sur le texte
+            if let Some(last) = merged.last() {
+                if segment.start < covered_until {
+                    segment.text = strip_repeated_prefix(&last.text, &segment.text);
+                }
+            }
+            if !segment.text.trim().is_empty() {
+                merged.push(segment);
+            }
+            continue;
+        }
+
+        // Un mot est gardé si son centre tombe après la fin de ce qui est déjà couvert
+        let words: Vec<WordTiming> = segment
+            .words
+            .into_iter()
+            .map(|mut w| {
+                w.start += offset;
+                w.end += offset;
+                w
+            })
+            .filter(|w| (w.start + w.end) / 2.0 >= covered_until)
+            .collect();
+
+        if !words.is_empty() {
+            merged.push(segment_from_words(words));
+        }
+    }
+}
+
+/// Retire de `next` le plus long préfixe qui répète la fin de `previous`
+fn strip_repeated_prefix(previous: &str, next: &str) -> String {
+    const MAX_OVERLAP_WORDS: usize = 20;
+
+    let key = |w: &str| {
+        w.trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase()
+    };
+    let prev: Vec<String> = previous.split_whitespace().map(key).collect();
+    let next_words: Vec<&str> = next.split_whitespace().collect();
+
+    let max = MAX_OVERLAP_WORDS.min(prev.len()).min(next_words.len());
+    let overlap = (1..=max)
+        .rev()
+        .find(|&n| {
+            prev[prev.len() - n..]
+                .iter()
+                .zip(&next_words[..n])
+                .all(|(a, b)| *a == key(b))
+        })
+        .unwrap_or(0);
+
+    next_words[overlap..].join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::vocabulary::Vocabulary;
+
+    fn word(word: &str, start: f32, end: f32) -> WordTiming {
+        WordTiming {
+            word: word.to_string(),
+            start,
+            end,
+            probability: 0.9,
+        }
+    }
+
+    #[test]
+    fn test_append_chunk_drops_overlapping_words() {
+        let mut merged = vec![segment_from_words(vec![
+            word("bonjour", 27.0, 27.5),
+            word("à", 27.6, 27.8),
+            word("tous", 28.0, 28.6),
+        ])];
+
+        // Fenêtre suivante commençant à 27.5s (1s de chevauchement)
+        let chunk = vec![segment_from_words(vec![
+            word("tous", 0.5, 1.1),
+            word("et", 1.3, 1.5),
+            word("merci", 1.6, 2.0),
+        ])];
+        append_chunk_segments(&mut merged, chunk, 27.5);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[1].text, "et merci");
+        assert!((merged[1].start - 28.8).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_append_chunk_keeps_dictionary_corrections() {
+        let vocabulary = Vocabulary::new(&["Phonon".to_string()]);
+        let mut merged = Vec::new();
+
+        // Segment de la deuxième fenêtre, corrigé par le moteur
+        let words =
+            vocabulary.correct_words(vec![word("merci", 0.5, 0.9), word("phonone.", 1.0, 1.4)]);
+        append_chunk_segments(&mut merged, vec![segment_from_words(words)], 30.0);
+
+        assert_eq!(merged[0].text, "merci Phonon.");
+        assert_eq!(merged[0].words[1].word, "Phonon.");
+    }
+
+    #[test]
+    fn test_append_chunk_text_only_dedupes_prefix() {
+        let mut merged = vec![Segment {
+            start: 0.0,
+            end: 30.0,
+            text: "on parle du projet Phonon.".to_string(),
+            words: Vec::new(),
+        }];
+        let chunk = vec![Segment {
+            start: 0.0,
+            end: 20.0,
+            text: "Phonon et de la suite".to_string(),
+            words: Vec::new(),
+        }];
+        append_chunk_segments(&mut merged, chunk, 29.0);
+
+        assert_eq!(merged[1].text, "et de la suite");
+        assert_eq!(merged[1].start, 29.0);
+    }
+}
@@ -0,0 +1,213 @@
+//! Sessions de transcription en continu
+//!
+//! Les moteurs capables de décoder au fil de l'eau (Vosk, Parakeet) fournissent
+//! leur propre `StreamingSession`. Pour les autres, `BatchSession` retranscrit
+//! la fenêtre non validée à chaque passage et ne valide que les mots
+//! confirmés par deux hypothèses successives.
+
+use std::sync::{Arc, RwLock};
+
+use crate::audio::resampling::resample_audio;
+use crate::audio::{vad, HypothesisWord, StreamingBuffer, StreamingConfig, StreamingUpdate};
+use crate::engines::traits::{SpeechEngine, StreamingSession};
+use crate::types::TranscriptionResult;
+
+/// Moteur de reconnaissance partagé (voir `AppState::engine`)
+pub type SharedEngine = Arc<RwLock<Option<Box<dyn SpeechEngine>>>>;
+
+/// Taux d'échantillonnage des sessions
+const TARGET_SAMPLE_RATE: u32 = 16000;
+
+/// Pause marquant la fin d'un énoncé
+const MIN_PAUSE_SECS: f32 = 0.5;
+
+/// Durée maximale de la fenêtre retranscrite avant de forcer la validation
+const MAX_WINDOW_SECS: f32 = 12.0;
+
+/// Session générique pour les moteurs qui ne transcrivent que par lots
+pub struct BatchSession {
+    engine: SharedEngine,
+    buffer: StreamingBuffer,
+    vad_enabled: bool,
+}
+
+impl BatchSession {
+    pub fn new(engine: SharedEngine, vad_enabled: bool) -> Self {
+        Self {
+            engine,
+            buffer: StreamingBuffer::new(StreamingConfig {
+                sample_rate: TARGET_SAMPLE_RATE,
+                min_pause_secs: MIN_PAUSE_SECS,
+                max_window_secs: MAX_WINDOW_SECS,
+                ..Default::default()
+            }),
+            vad_enabled,
+        }
+    }
+}
+
+fn transcribe_with(engine: &SharedEngine, audio: &[f32]) -> Result<TranscriptionResult, String> {
+    let engine_guard = engine.read().map_err(|e| e.to_string())?;
+    let engine = engine_guard
+        .as_ref()
+        .ok_or("Speech engine not initialized. Please download a model first.")?;
+    engine.transcribe(audio, TARGET_SAMPLE_RATE)
+}
+
+impl StreamingSession for BatchSession {
+    fn push_audio(&mut self, audio: &[f32]) -> Result<(), String> {
+        self.buffer.push_audio(audio);
+        Ok(())
+    }
+
+    fn poll_partial(&mut self) -> Result<Option<StreamingUpdate>, String> {
+        let engine = &self.engine;
+        self.buffer.step(self.vad_enabled, |window| {
+            transcribe_with(engine, window).map(|result| HypothesisWord::from_result(&result))
+        })
+    }
+
+    fn committed_text(&self) -> String {
+        self.buffer.get_accumulated_text()
+    }
+
+    fn finish(self: Box<Self>) -> Result<TranscriptionResult, String> {
+        let audio = vad::prepare_for_transcription(
+            self.buffer.get_all_audio(),
+            TARGET_SAMPLE_RATE,
+            self.vad_enabled,
+        )
+        .ok_or("No speech detected")?;
+        transcribe_with(&self.engine, &audio)
+    }
+}
+
+/// Session alimentée par les snapshots cumulés d'un enregistrement, à la
+/// fréquence de capture (la conversion en 16 kHz est faite ici)
+pub struct LiveSession {
+    session: Box<dyn StreamingSession>,
+    sample_rate: u32,
+    fed_samples: usize,
+}
+
+impl LiveSession {
+    /// Session native du moteur courant, ou `BatchSession` à défaut
+    pub fn start(
+        engine: &SharedEngine,
+        sample_rate: u32,
+        vad_enabled: bool,
+    ) -> Result<Self, String> {
+        let native = {
+            let engine_guard = engine.read().map_err(|e| e.to_string())?;
+            engine_guard
+                .as_ref()
+                .ok_or("Speech engine not initialized. Please download a model first.")?
+                .start_streaming_session()
+        };
+
+        let session =
+            native.unwrap_or_else(|| Box::new(BatchSession::new(engine.clone(), vad_enabled)));
+
+        Ok(Self {
+            session,
+            sample_rate,
+            fed_samples: 0,
+        })
+    }
+
+    /// Transmet la partie de l'enregistrement pas encore envoyée à la session
+    pub fn feed(&mut self, recording: &[f32]) -> Result<(), String> {
+        if recording.len() <= self.fed_samples {
+            return Ok(());
+        }
+
+        let new_audio = &recording[self.fed_samples..];
+        self.fed_samples = recording.len();
+
+        if self.sample_rate != TARGET_SAMPLE_RATE {
+            self.session.push_audio(&resample_audio(
+                new_audio,
+                self.sample_rate,
+                TARGET_SAMPLE_RATE,
+            ))
+        } else {
+            self.session.push_audio(new_audio)
+        }
+    }
+
+    pub fn poll(&mut self) -> Result<Option<StreamingUpdate>, String> {
+        self.session.poll_partial()
+    }
+
+    pub fn committed_text(&self) -> String {
+        self.session.committed_text()
+    }
+
+    /// Envoie la fin de l'enregistrement et retourne la transcription complète
+    pub fn finish(mut self, recording: &[f32]) -> Result<TranscriptionResult, String> {
+        self.feed(recording)?;
+        self.session.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Moteur factice : un mot par seconde d'audio
+    struct CountingEngine;
+
+    impl SpeechEngine for CountingEngine {
+        fn transcribe(
+            &self,
+            audio: &[f32],
+            sample_rate: u32,
+        ) -> Result<TranscriptionResult, String> {
+            let words = ["un", "deux", "trois", "quatre"];
+            let count = (audio.len() / sample_rate as usize).min(words.len());
+            Ok(TranscriptionResult {
+                text: words[..count].join(" "),
+                confidence: 1.0,
+                duration_seconds: audio.len() as f32 / sample_rate as f32,
+                processing_time_ms: 0,
+                detected_language: None,
+                timestamp: 0,
+                model_used: None,
+                segments: Vec::new(),
+                low_confidence_spans: Vec::new(),
+            })
+        }
+
+        fn name(&self) -> &str {
+            "Counting"
+        }
+
+        fn model_display_name(&self) -> String {
+            "Counting".to_string()
+        }
+    }
+
+    #[test]
+    fn test_batch_session_commits_agreed_words() {
+        let engine: SharedEngine = Arc::new(RwLock::new(Some(Box::new(CountingEngine))));
+        let mut session = LiveSession::start(&engine, TARGET_SAMPLE_RATE, false).unwrap();
+        let recording = vec![0.1f32; 3 * TARGET_SAMPLE_RATE as usize];
+
+        session
+            .feed(&recording[..TARGET_SAMPLE_RATE as usize])
+            .unwrap();
+        let update = session.poll().unwrap().unwrap();
+        assert_eq!(update.committed, "");
+        assert_eq!(update.partial, "un");
+
+        session
+            .feed(&recording[..2 * TARGET_SAMPLE_RATE as usize])
+            .unwrap();
+        let update = session.poll().unwrap().unwrap();
+        assert_eq!(update.committed, "un");
+        assert_eq!(update.partial, "deux");
+        assert_eq!(session.committed_text(), "un");
+
+        assert_eq!(session.finish(&recording).unwrap().text, "un deux trois");
+    }
+}
@@ -1,7 +1,60 @@
-use crate::types::TranscriptionResult;
+use crate::audio::StreamingUpdate;
+use crate::types::{TranscriptionResult, WhisperDecodeOptions};
 
 pub trait SpeechEngine: Send + Sync {
     fn transcribe(&self, audio: &[f32], sample_rate: u32) -> Result<TranscriptionResult, String>;
     fn name(&self) -> &str;
     fn model_display_name(&self) -> String;
+
+    /// Reçoit le dictionnaire personnalisé comme biais de reconnaissance
+    fn set_vocabulary(&mut self, _words: &[String]) {}
+
+    /// Options de décodage Whisper, appliquées à la prochaine transcription
+    /// sans recharger le modèle ; ignorées par les autres moteurs
+    fn set_decode_options(&mut self, _options: WhisperDecodeOptions) {}
+
+    /// Langue de transcription (code ISO 639-1), `None` pour la détection
+    /// automatique. Échoue si le moteur ne peut pas l'appliquer sans être
+    /// rechargé (un modèle Vosk ne couvre qu'une langue).
+    fn set_language(&mut self, _language: Option<String>) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Identifie la langue parlée (code ISO 639-1), ou `None` si le moteur
+    /// n'en est pas capable
+    fn detect_language(&self, _audio: &[f32], _sample_rate: u32) -> Result<Option<String>, String> {
+        Ok(None)
+    }
+
+    /// Transcrit en traduisant directement vers l'anglais, ou `None` si le
+    /// moteur ne sait pas traduire (la traduction passe alors par le LLM)
+    fn translate_to_english(
+        &self,
+        _audio: &[f32],
+        _sample_rate: u32,
+    ) -> Result<Option<TranscriptionResult>, String> {
+        Ok(None)
+    }
+
+    /// Session de reconnaissance incrémentale native, si le moteur en propose une.
+    /// Sinon `engines::streaming::BatchSession` retranscrit l'audio par fenêtres.
+    fn start_streaming_session(&self) -> Option<Box<dyn StreamingSession>> {
+        None
+    }
+}
+
+/// Transcription au fil de l'enregistrement (audio mono 16 kHz)
+pub trait StreamingSession: Send {
+    /// Ajoute la suite de l'audio enregistré
+    fn push_audio(&mut self, audio: &[f32]) -> Result<(), String>;
+
+    /// Décode l'audio reçu : mots validés depuis le dernier appel et hypothèse
+    /// en cours, ou `None` si rien n'a changé
+    fn poll_partial(&mut self) -> Result<Option<StreamingUpdate>, String>;
+
+    /// Tout le texte validé jusqu'ici
+    fn committed_text(&self) -> String;
+
+    /// Termine la session et retourne la transcription complète
+    fn finish(self: Box<Self>) -> Result<TranscriptionResult, String>;
 }
@@ -0,0 +1,311 @@
+//! Vocabulaire personnalisé injecté dans les moteurs de reconnaissance
+//!
+//! Le dictionnaire utilisateur (noms propres, produits, jargon) sert soit de
+//! prompt initial (Whisper), soit de passe de correction approximative après
+//! décodage (Vosk, Parakeet) pour les moteurs qui n'acceptent pas de biais.
+
+use crate::types::WordTiming;
+
+/// Nombre maximal de caractères injectés dans le prompt Whisper
+/// (le contexte texte de Whisper est limité à ~224 tokens)
+const MAX_PROMPT_CHARS: usize = 600;
+
+/// Similarité minimale pour remplacer un mot par une entrée du dictionnaire
+const MIN_SIMILARITY: f32 = 0.8;
+
+/// En dessous de cette longueur, seule une correspondance exacte (casse ignorée) est acceptée
+const MIN_FUZZY_LEN: usize = 4;
+
+/// Nombre maximal de mots transcrits regroupés pour former une entrée
+const MAX_WINDOW: usize = 3;
+
+#[derive(Debug, Clone)]
+struct Entry {
+    original: String,
+    normalized: String,
+    compact: String,
+}
+
+/// Liste de mots du dictionnaire préparée pour la reconnaissance
+#[derive(Debug, Clone, Default)]
+pub struct Vocabulary {
+    entries: Vec<Entry>,
+}
+
+impl Vocabulary {
+    pub fn new(words: &[String]) -> Self {
+        let entries = words
+            .iter()
+            .map(|w| w.trim())
+            .filter(|w| !w.is_empty())
+            .map(|w| {
+                let normalized = normalize(w);
+                let compact = normalized.replace(' ', "");
+                Entry {
+                    original: w.to_string(),
+                    normalized,
+                    compact,
+                }
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Prompt initial pour Whisper : une simple liste de termes, tronquée
+    pub fn initial_prompt(&self) -> Option<String> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let mut prompt = String::new();
+        for entry in &self.entries {
+            if prompt.len() + entry.original.len() + 2 > MAX_PROMPT_CHARS {
+                break;
+            }
+            if !prompt.is_empty() {
+                prompt.push_str(", ");
+            }
+            prompt.push_str(&entry.original);
+        }
+        prompt.push('.');
+
+        Some(prompt)
+    }
+
+    /// Remplace les mots proches d'une entrée du dictionnaire par leur graphie exacte
+    pub fn correct(&self, text: &str) -> String {
+        if self.entries.is_empty() || text.is_empty() {
+            return text.to_string();
+        }
+
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        self.corrections(&tokens)
+            .into_iter()
+            .map(|(_, _, word)| word)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Comme `correct`, sur des mots horodatés : des mots fusionnés en une
+    /// entrée du dictionnaire couvrent l'intervalle des mots d'origine
+    pub fn correct_words(&self, words: Vec<WordTiming>) -> Vec<WordTiming> {
+        if self.entries.is_empty() {
+            return words;
+        }
+
+        let tokens: Vec<&str> = words.iter().map(|w| w.word.as_str()).collect();
+        self.corrections(&tokens)
+            .into_iter()
+            .map(|(index, width, word)| {
+                let merged = &words[index..index + width];
+                WordTiming {
+                    word,
+                    start: merged[0].start,
+                    end: merged[width - 1].end,
+                    probability: merged
+                        .iter()
+                        .map(|w| w.probability)
+                        .fold(f32::MAX, f32::min),
+                }
+            })
+            .collect()
+    }
+
+    /// Découpe `tokens` en mots corrigés : (indice du premier token, nombre
+    /// de tokens couverts, texte)
+    fn corrections(&self, tokens: &[&str]) -> Vec<(usize, usize, String)> {
+        let mut output = Vec::with_capacity(tokens.len());
+        let mut i = 0;
+
+        while i < tokens.len() {
+            match self.best_match(&tokens[i..]) {
+                Some((width, replacement)) => {
+                    let (prefix, _, _) = split_punctuation(tokens[i]);
+                    let (_, _, suffix) = split_punctuation(tokens[i + width - 1]);
+                    output.push((i, width, format!("{}{}{}", prefix, replacement, suffix)));
+                    i += width;
+                }
+                None => {
+                    output.push((i, 1, tokens[i].to_string()));
+                    i += 1;
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Cherche la meilleure entrée pour les premiers mots de `tokens`
+    /// Retourne le nombre de mots consommés et le texte de remplacement
+    fn best_match(&self, tokens: &[&str]) -> Option<(usize, &str)> {
+        let mut best: Option<(usize, &str, f32)> = None;
+
+        for width in 1..=MAX_WINDOW.min(tokens.len()) {
+            let window = &tokens[..width];
+
+            // Ne pas fusionner à travers une ponctuation interne (ex. "foo. bar")
+            if window[..width - 1].iter().any(|t| !split_punctuation(t).2.is_empty()) {
+                break;
+            }
+
+            let core: Vec<String> = window
+                .iter()
+                .map(|t| normalize(split_punctuation(t).1))
+                .collect();
+            if core.iter().any(|c| c.is_empty()) {
+                break;
+            }
+
+            let candidate = core.join(" ");
+            let candidate_compact = core.concat();
+
+            for entry in &self.entries {
+                let score = if candidate_compact.chars().count() < MIN_FUZZY_LEN
+                    || entry.compact.chars().count() < MIN_FUZZY_LEN
+                {
+                    if candidate_compact == entry.compact { 1.0 } else { 0.0 }
+                } else {
+                    similarity(&candidate, &entry.normalized)
+                        .max(similarity(&candidate_compact, &entry.compact))
+                };
+
+                if score >= MIN_SIMILARITY && !matches!(best, Some((_, _, s)) if s >= score) {
+                    best = Some((width, entry.original.as_str(), score));
+                }
+            }
+        }
+
+        best.map(|(width, original, _)| (width, original))
+    }
+}
+
+/// Sépare la ponctuation en début et fin de mot
+fn split_punctuation(token: &str) -> (&str, &str, &str) {
+    let start = token
+        .char_indices()
+        .find(|(_, c)| c.is_alphanumeric())
+        .map(|(i, _)| i)
+        .unwrap_or(token.len());
+    let end = token
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_alphanumeric())
+        .map(|(i, c)| i + c.len_utf8())
+        .unwrap_or(start);
+
+    (&token[..start], &token[start..end], &token[end..])
+}
+
+/// Minuscules sans accents, pour comparer des graphies phonétiquement proches
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'à' | 'â' | 'ä' | 'á' | 'ã' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'î' | 'ï' | 'í' | 'ì' => 'i',
+            'ô' | 'ö' | 'ó' | 'ò' | 'õ' => 'o',
+            'ù' | 'û' | 'ü' | 'ú' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            '-' | '_' | '\'' => ' ',
+            other => other,
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Similarité normalisée basée sur la distance de Levenshtein (1.0 = identique)
+fn similarity(a: &str, b: &str) -> f32 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    1.0 - prev[b.len()] as f32 / max_len as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocab(words: &[&str]) -> Vocabulary {
+        Vocabulary::new(&words.iter().map(|w| w.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_correct_close_spelling() {
+        let v = vocab(&["Kubernetes", "Brisset"]);
+        assert_eq!(
+            v.correct("on déploie sur cubernetes avec monsieur brisse."),
+            "on déploie sur Kubernetes avec monsieur Brisset."
+        );
+    }
+
+    #[test]
+    fn test_correct_merges_split_words() {
+        let v = vocab(&["OpenAI"]);
+        assert_eq!(v.correct("le modèle open ai, vraiment"), "le modèle OpenAI, vraiment");
+    }
+
+    #[test]
+    fn test_correct_words_merges_timings() {
+        let v = vocab(&["OpenAI"]);
+        let word = |word: &str, start: f32, end: f32, probability: f32| WordTiming {
+            word: word.to_string(),
+            start,
+            end,
+            probability,
+        };
+        let words = v.correct_words(vec![
+            word("le", 0.0, 0.2, 0.9),
+            word("open", 0.3, 0.6, 0.8),
+            word("ai,", 0.6, 0.9, 0.7),
+        ]);
+
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[1].word, "OpenAI,");
+        assert_eq!((words[1].start, words[1].end), (0.3, 0.9));
+        assert_eq!(words[1].probability, 0.7);
+    }
+
+    #[test]
+    fn test_short_words_need_exact_match() {
+        let v = vocab(&["Tao"]);
+        assert_eq!(v.correct("le tao et le thé"), "le Tao et le thé");
+        assert_eq!(v.correct("le taxi"), "le taxi");
+    }
+
+    #[test]
+    fn test_unrelated_text_untouched() {
+        let v = vocab(&["Phonon"]);
+        let text = "Bonjour, comment allez-vous ?";
+        assert_eq!(v.correct(text), text);
+    }
+
+    #[test]
+    fn test_initial_prompt() {
+        assert!(vocab(&[]).initial_prompt().is_none());
+        assert_eq!(
+            vocab(&["Phonon", "Wakastellar"]).initial_prompt().as_deref(),
+            Some("Phonon, Wakastellar.")
+        );
+    }
+}
@@ -1,13 +1,20 @@
-use crate::engines::traits::SpeechEngine;
-use crate::types::{TranscriptionResult, VoskLanguage};
+use crate::audio::StreamingUpdate;
+use crate::engines::confidence::{low_confidence_spans, overall_confidence, LOW_CONFIDENCE_THRESHOLD};
+use crate::engines::traits::{SpeechEngine, StreamingSession};
+use crate::engines::vocabulary::Vocabulary;
+use crate::engines::segments::segment_from_words;
+use crate::types::{Segment, TranscriptionResult, VoskLanguage, WordTiming};
 use chrono::Utc;
 use std::path::Path;
 use std::sync::Mutex;
-use vosk::{Model, Recognizer};
+use vosk::{CompleteResult, DecodingState, Model, Recognizer};
 
 pub struct VoskEngine {
     model: Mutex<Model>,
     language: VoskLanguage,
+    /// Nom d'un modèle importé, à la place de la langue
+    custom_name: Option<String>,
+    vocabulary: Vocabulary,
 }
 
 impl VoskEngine {
@@ -26,18 +33,65 @@ impl VoskEngine {
         Ok(Self {
             model: Mutex::new(model),
             language,
+            custom_name: None,
+            vocabulary: Vocabulary::default(),
         })
     }
 
+    /// Nom d'affichage d'un modèle importé
+    pub fn set_display_name(&mut self, name: String) {
+        self.custom_name = Some(name);
+    }
+
     pub fn language(&self) -> VoskLanguage {
         self.language
     }
+
+    /// Ouvre une session de reconnaissance incrémentale (audio 16 kHz)
+    pub fn start_session(&self) -> Result<VoskStreamingSession, String> {
+        let model = self.model.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut recognizer = Recognizer::new(&model, 16000.0)
+            .ok_or("Failed to create recognizer")?;
+        recognizer.set_words(true);
+
+        Ok(VoskStreamingSession {
+            recognizer,
+            vocabulary: self.vocabulary.clone(),
+            language: self.language,
+            model_name: self.model_display_name(),
+            segments: Vec::new(),
+            unpolled: Vec::new(),
+            last_partial: String::new(),
+            samples: 0,
+            start_time: std::time::Instant::now(),
+        })
+    }
+
+    /// Convertit un résultat Vosk (avec `set_words(true)`) en segment horodaté
+    fn segment_from_result(result: CompleteResult) -> Option<Segment> {
+        let single = result.single()?;
+        let words: Vec<WordTiming> = single
+            .result
+            .iter()
+            .map(|w| WordTiming {
+                word: w.word.to_string(),
+                start: w.start,
+                end: w.end,
+                probability: w.conf,
+            })
+            .collect();
+
+        if words.is_empty() {
+            return None;
+        }
+
+        Some(segment_from_words(words))
+    }
 }
 
 impl SpeechEngine for VoskEngine {
     fn transcribe(&self, audio: &[f32], sample_rate: u32) -> Result<TranscriptionResult, String> {
-        let start_time = std::time::Instant::now();
-
         if sample_rate != 16000 {
             return Err(format!(
                 "Invalid sample rate: {}Hz (expected 16000Hz)",
@@ -45,55 +99,175 @@ impl SpeechEngine for VoskEngine {
             ));
         }
 
-        let duration_seconds = audio.len() as f32 / sample_rate as f32;
+        let mut session = self.start_session()?;
+        session.accept_waveform(audio);
+        let result = session.into_result();
 
-        let model = self.model.lock().map_err(|e| format!("Lock error: {}", e))?;
+        log::info!(
+            "Vosk transcription completed in {}ms: {} chars",
+            result.processing_time_ms,
+            result.text.len()
+        );
 
-        let mut recognizer = Recognizer::new(&model, sample_rate as f32)
-            .ok_or("Failed to create recognizer")?;
+        Ok(result)
+    }
 
-        recognizer.set_words(true);
+    fn name(&self) -> &str {
+        "Vosk"
+    }
+
+    fn model_display_name(&self) -> String {
+        let name = self.custom_name.as_deref().unwrap_or(self.language.display_name());
+        format!("Vosk {}", name)
+    }
+
+    /// Les petits modèles Vosk acceptent une grammaire, mais elle restreint la
+    /// reconnaissance aux seules phrases listées : inutilisable en dictée libre.
+    /// Le dictionnaire est donc appliqué en correction après décodage.
+    fn set_vocabulary(&mut self, words: &[String]) {
+        self.vocabulary = Vocabulary::new(words);
+    }
+
+    /// Un modèle Vosk ne couvre qu'une langue : en changer impose de charger
+    /// un autre modèle (voir `AppState::apply_language`)
+    fn set_language(&mut self, language: Option<String>) -> Result<(), String> {
+        let Some(code) = language.filter(|lang| lang != "auto") else {
+            return Ok(());
+        };
+        match VoskLanguage::from_language_code(&code) {
+            Some(lang) if lang == self.language => Ok(()),
+            Some(lang) => Err(format!("Vosk model for {} must be loaded", lang.display_name())),
+            None => Err(format!("Language {} not supported by Vosk", code)),
+        }
+    }
+
+    fn start_streaming_session(&self) -> Option<Box<dyn StreamingSession>> {
+        match self.start_session() {
+            Ok(session) => Some(Box::new(session)),
+            Err(e) => {
+                log::warn!("Vosk streaming session unavailable: {}", e);
+                None
+            }
+        }
+    }
+}
+
+unsafe impl Send for VoskEngine {}
+unsafe impl Sync for VoskEngine {}
+
+/// Reconnaissance incrémentale : un seul recognizer reçoit l'audio au fil de
+/// l'enregistrement. Les énoncés finalisés par Vosk (après une pause) sont
+/// définitifs, la fin en cours est lue avec `partial_result`.
+pub struct VoskStreamingSession {
+    recognizer: Recognizer,
+    vocabulary: Vocabulary,
+    language: VoskLanguage,
+    model_name: String,
+    segments: Vec<Segment>,
+    /// Énoncés finalisés pas encore retournés par `poll_partial`
+    unpolled: Vec<String>,
+    last_partial: String,
+    samples: usize,
+    start_time: std::time::Instant,
+}
 
+impl VoskStreamingSession {
+    /// Décode un morceau d'audio 16 kHz
+    pub fn accept_waveform(&mut self, audio: &[f32]) {
         // Process audio in chunks to avoid full i16 buffer allocation
+        // Vosk finalise un énoncé à chaque pause : il faut le lire avant de continuer
         const CHUNK_SIZE: usize = 4096;
+
         for chunk in audio.chunks(CHUNK_SIZE) {
             let chunk_i16: Vec<i16> = chunk
                 .iter()
                 .map(|&s| (s * 32767.0).clamp(-32768.0, 32767.0) as i16)
                 .collect();
-            recognizer.accept_waveform(&chunk_i16);
+            if let DecodingState::Finalized = self.recognizer.accept_waveform(&chunk_i16) {
+                let segment = VoskEngine::segment_from_result(self.recognizer.result());
+                if let Some(text) = self.push_segment(segment) {
+                    self.unpolled.push(text);
+                }
+            }
         }
+        self.samples += audio.len();
+    }
 
-        let result = recognizer.final_result();
-        let text = result.single().map(|r| r.text.to_string()).unwrap_or_default();
+    /// Hypothèse en cours (pas encore finalisée par Vosk)
+    pub fn partial_result(&mut self) -> String {
+        let partial = self.recognizer.partial_result().partial.to_string();
+        self.vocabulary.correct(partial.trim())
+    }
 
-        let processing_time_ms = start_time.elapsed().as_millis() as u64;
+    /// Finalise le dernier énoncé et retourne la transcription complète
+    pub fn into_result(mut self) -> TranscriptionResult {
+        let segment = VoskEngine::segment_from_result(self.recognizer.final_result());
+        self.push_segment(segment);
 
-        log::info!(
-            "Vosk transcription completed in {}ms: {} chars",
-            processing_time_ms,
-            text.len()
-        );
+        let text = self.finalized_text();
+        let segments = self.segments;
 
-        Ok(TranscriptionResult {
+        TranscriptionResult {
             text: text.trim().to_string(),
-            confidence: 0.9,
-            duration_seconds,
-            processing_time_ms,
+            confidence: overall_confidence(&segments),
+            low_confidence_spans: low_confidence_spans(&segments, LOW_CONFIDENCE_THRESHOLD),
+            duration_seconds: self.samples as f32 / 16000.0,
+            processing_time_ms: self.start_time.elapsed().as_millis() as u64,
             detected_language: Some(format!("{:?}", self.language).to_lowercase()),
             timestamp: Utc::now().timestamp(),
-            model_used: Some(self.model_display_name()),
-        })
+            model_used: Some(self.model_name),
+            segments,
+        }
     }
 
-    fn name(&self) -> &str {
-        "Vosk"
+    /// Texte des énoncés finalisés jusqu'ici
+    fn finalized_text(&self) -> String {
+        self.segments
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
     }
 
-    fn model_display_name(&self) -> String {
-        format!("Vosk {}", self.language.display_name())
+    /// Ajoute un énoncé finalisé ; retourne son texte s'il n'est pas vide
+    fn push_segment(&mut self, segment: Option<Segment>) -> Option<String> {
+        let mut segment = segment?;
+        if segment.words.is_empty() {
+            segment.text = self.vocabulary.correct(&segment.text);
+        } else {
+            segment = segment_from_words(self.vocabulary.correct_words(segment.words));
+        }
+        let text = segment.text.clone();
+        self.segments.push(segment);
+        Some(text)
     }
 }
 
-unsafe impl Send for VoskEngine {}
-unsafe impl Sync for VoskEngine {}
+impl StreamingSession for VoskStreamingSession {
+    fn push_audio(&mut self, audio: &[f32]) -> Result<(), String> {
+        self.accept_waveform(audio);
+        Ok(())
+    }
+
+    fn poll_partial(&mut self) -> Result<Option<StreamingUpdate>, String> {
+        let committed = self.unpolled.drain(..).collect::<Vec<_>>().join(" ");
+        let partial = self.partial_result();
+
+        if committed.is_empty() && partial == self.last_partial {
+            return Ok(None);
+        }
+        self.last_partial = partial.clone();
+
+        Ok(Some(StreamingUpdate { committed, partial }))
+    }
+
+    fn committed_text(&self) -> String {
+        self.finalized_text()
+    }
+
+    fn finish(self: Box<Self>) -> Result<TranscriptionResult, String> {
+        Ok(self.into_result())
+    }
+}
+
+unsafe impl Send for VoskStreamingSession {}
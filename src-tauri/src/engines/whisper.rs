@@ -1,5 +1,11 @@
+use crate::engines::confidence::{low_confidence_spans, overall_confidence, LOW_CONFIDENCE_THRESHOLD};
+use crate::engines::language_id::detect_language;
 use crate::engines::traits::SpeechEngine;
-use crate::types::{ModelSize, TranscriptionResult};
+use crate::engines::segments::push_token;
+use crate::engines::vocabulary::Vocabulary;
+use crate::types::{
+    ModelSize, Segment, TranscriptionResult, WhisperDecodeOptions, WordTiming, GPU_MIN_MODEL_BYTES,
+};
 use chrono::Utc;
 use std::path::Path;
 use std::sync::Mutex;
@@ -8,11 +14,33 @@ use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextPar
 pub struct WhisperEngine {
     ctx: Mutex<WhisperContext>,
     language: Option<String>,
-    model_size: ModelSize,
+    model_name: String,
+    initial_prompt: Option<String>,
+    decode_options: WhisperDecodeOptions,
 }
 
 impl WhisperEngine {
     pub fn new(model_path: &Path, language: Option<String>, model_size: ModelSize) -> Result<Self, String> {
+        Self::load(
+            model_path,
+            language,
+            model_size.display_name().to_string(),
+            model_size.prefers_gpu(),
+        )
+    }
+
+    /// Modèle ggml importé par l'utilisateur, affiché sous son nom
+    pub fn custom(model_path: &Path, language: Option<String>, name: &str) -> Result<Self, String> {
+        let size_bytes = std::fs::metadata(model_path).map(|m| m.len()).unwrap_or(0);
+        Self::load(model_path, language, name.to_string(), size_bytes >= GPU_MIN_MODEL_BYTES)
+    }
+
+    fn load(
+        model_path: &Path,
+        language: Option<String>,
+        model_name: String,
+        use_gpu: bool,
+    ) -> Result<Self, String> {
         log::info!("Loading Whisper model from {:?}", model_path);
 
         if !model_path.exists() {
@@ -20,8 +48,7 @@ impl WhisperEngine {
         }
 
         let mut ctx_params = WhisperContextParameters::default();
-        // GPU (Metal/CUDA) only benefits medium+ models; tiny/small are faster on CPU
-        ctx_params.use_gpu = matches!(model_size, ModelSize::Medium);
+        ctx_params.use_gpu = use_gpu;
 
         let ctx = WhisperContext::new_with_params(
             model_path.to_str().ok_or("Invalid model path")?,
@@ -34,21 +61,39 @@ impl WhisperEngine {
         Ok(Self {
             ctx: Mutex::new(ctx),
             language,
-            model_size,
+            model_name,
+            initial_prompt: None,
+            decode_options: WhisperDecodeOptions::default(),
         })
     }
 
-    pub fn model_size(&self) -> ModelSize {
-        self.model_size
+    /// Prompt initial : celui des réglages suivi des termes du dictionnaire
+    fn prompt(&self) -> Option<String> {
+        let user_prompt = self
+            .decode_options
+            .initial_prompt
+            .as_deref()
+            .map(str::trim)
+            .filter(|prompt| !prompt.is_empty());
+
+        match (user_prompt, self.initial_prompt.as_deref()) {
+            (Some(user), Some(vocabulary)) => Some(format!("{} {}", user, vocabulary)),
+            (Some(user), None) => Some(user.to_string()),
+            (None, vocabulary) => vocabulary.map(str::to_string),
+        }
     }
 
-    pub fn set_language(&mut self, language: Option<String>) {
-        self.language = language;
+    /// Les modèles `.en` ne transcrivent que l'anglais
+    fn is_multilingual(&self) -> bool {
+        self.ctx
+            .lock()
+            .map(|ctx| ctx.is_multilingual())
+            .unwrap_or(true)
     }
-}
 
-impl SpeechEngine for WhisperEngine {
-    fn transcribe(&self, audio: &[f32], sample_rate: u32) -> Result<TranscriptionResult, String> {
+    /// Décode `audio` ; avec `translate`, Whisper produit directement une
+    /// traduction anglaise (tâche `translate`)
+    fn run(&self, audio: &[f32], sample_rate: u32, translate: bool) -> Result<TranscriptionResult, String> {
         let start_time = std::time::Instant::now();
 
         if sample_rate != 16000 {
@@ -65,7 +110,17 @@ impl SpeechEngine for WhisperEngine {
 
         let ctx = self.ctx.lock().map_err(|e| format!("Lock error: {}", e))?;
 
-        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+        let options = &self.decode_options;
+        let prompt = self.prompt();
+        let strategy = if options.beam_size > 1 {
+            SamplingStrategy::BeamSearch {
+                beam_size: options.beam_size as i32,
+                patience: -1.0,
+            }
+        } else {
+            SamplingStrategy::Greedy { best_of: 1 }
+        };
+        let mut params = FullParams::new(strategy);
 
         // Utiliser les coeurs physiques pour l'inférence (min 1, max 8)
         let n_threads = std::thread::available_parallelism()
@@ -80,13 +135,29 @@ impl SpeechEngine for WhisperEngine {
             }
         }
 
+        params.set_translate(translate);
+
+        // Prompt des réglages et biais vers le vocabulaire du dictionnaire personnalisé
+        if let Some(ref prompt) = prompt {
+            params.set_initial_prompt(prompt);
+        }
+
+        // Repli en température et seuils de rejet d'un segment
+        params.set_temperature(options.temperature);
+        params.set_temperature_inc(options.temperature_increment);
+        params.set_entropy_thold(options.entropy_threshold);
+        params.set_logprob_thold(options.logprob_threshold);
+        params.set_no_speech_thold(options.no_speech_threshold);
+
         // Optimisations
         params.set_print_special(false);
         params.set_print_progress(false);
         params.set_print_realtime(false);
         params.set_print_timestamps(false);
-        params.set_single_segment(true);
-        params.set_no_context(true);
+        params.set_no_context(!options.condition_on_previous_text);
+
+        // Horodatage par token pour les segments et les mots
+        params.set_token_timestamps(true);
 
         // Éviter les hallucinations (musique, sous-titres, etc.)
         params.set_suppress_nst(true);
@@ -103,11 +174,51 @@ impl SpeechEngine for WhisperEngine {
 
         // Récupérer le résultat
         let num_segments = state.full_n_segments().map_err(|e| format!("Error: {}", e))?;
+        let eot = ctx.token_eot();
         let mut text = String::new();
+        let mut segments = Vec::with_capacity(num_segments.max(0) as usize);
 
         for i in 0..num_segments {
-            if let Ok(segment) = state.full_get_segment_text(i) {
-                text.push_str(&segment);
+            let segment_text = match state.full_get_segment_text(i) {
+                Ok(segment) => segment,
+                Err(_) => continue,
+            };
+            text.push_str(&segment_text);
+
+            // Les horodatages Whisper sont en centièmes de seconde
+            let start = state.full_get_segment_t0(i).unwrap_or(0) as f32 / 100.0;
+            let end = state.full_get_segment_t1(i).unwrap_or(0) as f32 / 100.0;
+
+            let mut words: Vec<WordTiming> = Vec::new();
+            let num_tokens = state.full_n_tokens(i).unwrap_or(0);
+            for j in 0..num_tokens {
+                let data = match state.full_get_token_data(i, j) {
+                    Ok(data) => data,
+                    Err(_) => continue,
+                };
+                // Ignorer les tokens spéciaux ([_BEG_], [_TT_xxx], <|endoftext|>...)
+                if data.id >= eot {
+                    continue;
+                }
+                if let Ok(piece) = state.full_get_token_text_lossy(i, j) {
+                    push_token(
+                        &mut words,
+                        &piece,
+                        data.t0 as f32 / 100.0,
+                        data.t1 as f32 / 100.0,
+                        data.p,
+                    );
+                }
+            }
+
+            let segment_text = segment_text.trim();
+            if !segment_text.is_empty() {
+                segments.push(Segment {
+                    start,
+                    end,
+                    text: segment_text.to_string(),
+                    words,
+                });
             }
         }
 
@@ -126,21 +237,70 @@ impl SpeechEngine for WhisperEngine {
 
         Ok(TranscriptionResult {
             text: text.trim().to_string(),
-            confidence: 0.95,
+            confidence: overall_confidence(&segments),
+            low_confidence_spans: low_confidence_spans(&segments, LOW_CONFIDENCE_THRESHOLD),
             duration_seconds,
             processing_time_ms,
             detected_language,
             timestamp: Utc::now().timestamp(),
             model_used: Some(self.model_display_name()),
+            segments,
         })
     }
+}
+
+impl SpeechEngine for WhisperEngine {
+    fn transcribe(&self, audio: &[f32], sample_rate: u32) -> Result<TranscriptionResult, String> {
+        self.run(audio, sample_rate, false)
+    }
+
+    fn translate_to_english(
+        &self,
+        audio: &[f32],
+        sample_rate: u32,
+    ) -> Result<Option<TranscriptionResult>, String> {
+        // Les modèles `.en` n'ont pas été entraînés à la tâche de traduction
+        if !self.is_multilingual() {
+            return Ok(None);
+        }
+        self.run(audio, sample_rate, true).map(Some)
+    }
 
     fn name(&self) -> &str {
         "Whisper"
     }
 
     fn model_display_name(&self) -> String {
-        format!("Whisper {}", self.model_size.display_name())
+        format!("Whisper {}", self.model_name)
+    }
+
+    fn set_vocabulary(&mut self, words: &[String]) {
+        self.initial_prompt = Vocabulary::new(words).initial_prompt();
+    }
+
+    fn set_decode_options(&mut self, options: WhisperDecodeOptions) {
+        self.decode_options = options;
+    }
+
+    fn set_language(&mut self, language: Option<String>) -> Result<(), String> {
+        let language = language.filter(|lang| lang != "auto");
+        if language.as_deref().is_some_and(|lang| lang != "en") && !self.is_multilingual() {
+            return Err(format!("{} only supports English", self.model_display_name()));
+        }
+        self.language = language;
+        Ok(())
+    }
+
+    fn detect_language(&self, audio: &[f32], sample_rate: u32) -> Result<Option<String>, String> {
+        if sample_rate != 16000 {
+            return Err(format!(
+                "Invalid sample rate: {}Hz (expected 16000Hz)",
+                sample_rate
+            ));
+        }
+
+        let ctx = self.ctx.lock().map_err(|e| format!("Lock error: {}", e))?;
+        detect_language(&ctx, audio)
     }
 }
 
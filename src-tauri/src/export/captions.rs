@@ -0,0 +1,295 @@
+//! Découpage des segments en sous-titres (cues) respectant les contraintes d'affichage
+
+use crate::types::{ExportConfig, Segment, TranscriptionResult, WordTiming};
+
+/// Un sous-titre : intervalle de temps et lignes de texte
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub start: f32,
+    pub end: f32,
+    pub lines: Vec<String>,
+}
+
+/// Construit les sous-titres d'une transcription
+pub fn build_cues(result: &TranscriptionResult, config: &ExportConfig) -> Vec<Cue> {
+    let max_line = config.max_line_length.max(10);
+    let max_lines = config.max_lines_per_caption.max(1);
+    let max_duration = config.max_caption_duration_secs.max(1.0);
+
+    // Sans segments (moteur sans horodatage), un seul sous-titre couvre l'audio
+    if result.segments.is_empty() {
+        let text = result.text.trim();
+        if text.is_empty() {
+            return Vec::new();
+        }
+        return vec![Cue {
+            start: 0.0,
+            end: result.duration_seconds,
+            lines: wrap_lines(text, max_line),
+        }];
+    }
+
+    let mut cues = Vec::new();
+    for segment in &result.segments {
+        if segment.words.is_empty() {
+            cues.extend(split_segment_text(segment, max_line, max_lines));
+        } else {
+            cues.extend(split_words(
+                &segment.words,
+                max_line,
+                max_lines,
+                max_duration,
+            ));
+        }
+    }
+
+    cues
+}
+
+/// Regroupe les mots en sous-titres limités en longueur et en durée
+fn split_words(
+    words: &[WordTiming],
+    max_line: usize,
+    max_lines: usize,
+    max_duration: f32,
+) -> Vec<Cue> {
+    let mut cues = Vec::new();
+    let mut current: Vec<&WordTiming> = Vec::new();
+    let mut current_text = String::new();
+
+    for word in words {
+        let candidate = if current_text.is_empty() {
+            word.word.clone()
+        } else {
+            format!("{} {}", current_text, word.word)
+        };
+        let too_long = wrap_lines(&candidate, max_line).len() > max_lines;
+        let too_slow = current
+            .first()
+            .map(|first| word.end - first.start > max_duration)
+            .unwrap_or(false);
+
+        if !current.is_empty() && (too_long || too_slow) {
+            cues.push(cue_from_words(&current, max_line));
+            current.clear();
+            current_text = word.word.clone();
+        } else {
+            current_text = candidate;
+        }
+
+        current.push(word);
+    }
+
+    if !current.is_empty() {
+        cues.push(cue_from_words(&current, max_line));
+    }
+
+    cues
+}
+
+fn cue_from_words(words: &[&WordTiming], max_line: usize) -> Cue {
+    let text = words
+        .iter()
+        .map(|w| w.word.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    Cue {
+        start: words.first().map(|w| w.start).unwrap_or(0.0),
+        end: words.last().map(|w| w.end).unwrap_or(0.0),
+        lines: wrap_lines(&text, max_line),
+    }
+}
+
+/// Segment sans mots horodatés : durée répartie au prorata du texte
+fn split_segment_text(segment: &Segment, max_line: usize, max_lines: usize) -> Vec<Cue> {
+    let lines = wrap_lines(&segment.text, max_line);
+    if lines.is_empty() {
+        return Vec::new();
+    }
+
+    let total_chars: usize = lines
+        .iter()
+        .map(|l| l.chars().count())
+        .sum::<usize>()
+        .max(1);
+    let duration = (segment.end - segment.start).max(0.0);
+    let mut cues = Vec::new();
+    let mut start = segment.start;
+
+    for chunk in lines.chunks(max_lines) {
+        let chars: usize = chunk.iter().map(|l| l.chars().count()).sum();
+        let end = start + duration * chars as f32 / total_chars as f32;
+        cues.push(Cue {
+            start,
+            end,
+            lines: chunk.to_vec(),
+        });
+        start = end;
+    }
+
+    cues
+}
+
+/// Coupe un texte en lignes d'au plus `max_line` caractères (sans couper les mots)
+pub fn wrap_lines(text: &str, max_line: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > max_line {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start: f32, end: f32) -> WordTiming {
+        WordTiming {
+            word: text.to_string(),
+            start,
+            end,
+            probability: 1.0,
+        }
+    }
+
+    fn result(text: &str, segments: Vec<Segment>) -> TranscriptionResult {
+        TranscriptionResult {
+            text: text.to_string(),
+            confidence: 1.0,
+            duration_seconds: 10.0,
+            processing_time_ms: 0,
+            detected_language: None,
+            timestamp: 0,
+            model_used: None,
+            segments,
+            low_confidence_spans: Vec::new(),
+        }
+    }
+
+    fn config(max_line_length: usize, max_lines: usize, max_duration: f32) -> ExportConfig {
+        ExportConfig {
+            max_line_length,
+            max_lines_per_caption: max_lines,
+            max_caption_duration_secs: max_duration,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_wrap_lines() {
+        assert_eq!(
+            wrap_lines("un deux trois quatre cinq", 10),
+            vec!["un deux", "trois", "quatre", "cinq"]
+        );
+        // Un mot plus long que la ligne n'est pas coupé
+        assert_eq!(
+            wrap_lines("anticonstitutionnellement", 10),
+            vec!["anticonstitutionnellement"]
+        );
+        assert!(wrap_lines("  ", 10).is_empty());
+    }
+
+    #[test]
+    fn test_cues_respect_line_length_and_count() {
+        let words: Vec<WordTiming> = (0..12)
+            .map(|i| word("mot", i as f32 * 0.3, i as f32 * 0.3 + 0.25))
+            .collect();
+        let segment = Segment {
+            start: 0.0,
+            end: 3.6,
+            text: String::new(),
+            words,
+        };
+
+        let cues = build_cues(&result("", vec![segment]), &config(11, 2, 60.0));
+
+        // 3 mots par ligne de 11 caractères, 2 lignes par sous-titre
+        assert_eq!(cues.len(), 2);
+        for cue in &cues {
+            assert_eq!(cue.lines, vec!["mot mot mot", "mot mot mot"]);
+        }
+        assert_eq!((cues[0].start, cues[0].end), (0.0, 1.75));
+        assert!((cues[1].start - 1.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cues_respect_max_duration() {
+        let words: Vec<WordTiming> = (0..6)
+            .map(|i| word("mot", i as f32, i as f32 + 0.8))
+            .collect();
+        let segment = Segment {
+            start: 0.0,
+            end: 6.0,
+            text: String::new(),
+            words,
+        };
+
+        let cues = build_cues(&result("", vec![segment]), &config(42, 2, 2.0));
+
+        assert_eq!(cues.len(), 3);
+        for cue in &cues {
+            assert!(cue.end - cue.start <= 2.0, "{:?}", cue);
+        }
+    }
+
+    #[test]
+    fn test_segment_without_words_splits_duration() {
+        let segment = Segment {
+            start: 2.0,
+            end: 6.0,
+            text: "aaaa bbbb cccc dddd".to_string(),
+            words: Vec::new(),
+        };
+
+        let cues = build_cues(&result("", vec![segment]), &config(10, 1, 6.0));
+
+        // Deux lignes de même longueur : durée partagée en deux
+        assert_eq!(cues.len(), 2);
+        assert_eq!(
+            cues[0],
+            Cue {
+                start: 2.0,
+                end: 4.0,
+                lines: vec!["aaaa bbbb".to_string()]
+            }
+        );
+        assert_eq!(
+            cues[1],
+            Cue {
+                start: 4.0,
+                end: 6.0,
+                lines: vec!["cccc dddd".to_string()]
+            }
+        );
+    }
+
+    #[test]
+    fn test_text_without_segments_is_one_cue() {
+        let cues = build_cues(
+            &result(" bonjour tout le monde ", Vec::new()),
+            &config(42, 2, 6.0),
+        );
+        assert_eq!(
+            cues,
+            vec![Cue {
+                start: 0.0,
+                end: 10.0,
+                lines: vec!["bonjour tout le monde".to_string()]
+            }]
+        );
+        assert!(build_cues(&result("", Vec::new()), &ExportConfig::default()).is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+//! Rendu des transcriptions en SRT, WebVTT, texte horodaté et JSON
+
+use super::captions::Cue;
+use crate::types::TranscriptionResult;
+
+/// Formate un temps en `HH:MM:SS{sep}mmm`
+fn format_timestamp(seconds: f32, separator: char) -> String {
+    let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    let hours = total_ms / 3_600_000;
+    let minutes = (total_ms % 3_600_000) / 60_000;
+    let secs = (total_ms % 60_000) / 1000;
+    let millis = total_ms % 1000;
+    format!("{:02}:{:02}:{:02}{}{:03}", hours, minutes, secs, separator, millis)
+}
+
+pub fn to_srt(cues: &[Cue]) -> String {
+    let mut out = String::new();
+    for (i, cue) in cues.iter().enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            format_timestamp(cue.start, ','),
+            format_timestamp(cue.end, ','),
+            cue.lines.join("\n")
+        ));
+    }
+    out
+}
+
+pub fn to_vtt(cues: &[Cue]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in cues {
+        out.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            format_timestamp(cue.start, '.'),
+            format_timestamp(cue.end, '.'),
+            cue.lines.join("\n")
+        ));
+    }
+    out
+}
+
+/// Texte brut avec un horodatage `[HH:MM:SS]` par segment
+pub fn to_txt(result: &TranscriptionResult) -> String {
+    if result.segments.is_empty() {
+        return format!("{}\n", result.text.trim());
+    }
+
+    let mut out = String::new();
+    for segment in &result.segments {
+        let stamp = format_timestamp(segment.start, '.');
+        out.push_str(&format!("[{}] {}\n", &stamp[..8], segment.text.trim()));
+    }
+    out
+}
+
+pub fn to_json(result: &TranscriptionResult) -> Result<String, String> {
+    serde_json::to_string_pretty(result).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamps() {
+        assert_eq!(format_timestamp(0.0, ','), "00:00:00,000");
+        assert_eq!(format_timestamp(3725.5, ','), "01:02:05,500");
+        assert_eq!(format_timestamp(61.042, '.'), "00:01:01.042");
+    }
+
+    #[test]
+    fn test_srt_and_vtt_layout() {
+        let cues = vec![
+            Cue { start: 0.0, end: 1.5, lines: vec!["Bonjour à tous".to_string()] },
+            Cue { start: 1.5, end: 3.0, lines: vec!["ligne un".to_string(), "ligne deux".to_string()] },
+        ];
+
+        assert_eq!(
+            to_srt(&cues),
+            "1\n00:00:00,000 --> 00:00:01,500\nBonjour à tous\n\n\
+             2\n00:00:01,500 --> 00:00:03,000\nligne un\nligne deux\n\n"
+        );
+        assert!(to_vtt(&cues).starts_with("WEBVTT\n\n00:00:00.000 --> 00:00:01.500\nBonjour à tous\n\n"));
+    }
+}
@@ -0,0 +1,109 @@
+//! Export des transcriptions de fichiers (SRT, WebVTT, TXT horodaté, JSON)
+
+pub mod captions;
+pub mod formats;
+
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use crate::types::{ExportConfig, ExportFormat, TranscriptionResult};
+use captions::build_cues;
+
+/// Écrit la transcription dans chaque format configuré.
+///
+/// Les fichiers portent le nom du fichier source et sont placés à côté de
+/// celui-ci, ou dans `config.output_dir` s'il est défini. Un fichier existant
+/// n'est jamais remplacé : l'export prend le nom `<source> (2).srt`, etc.
+pub fn export_transcription(
+    result: &TranscriptionResult,
+    source_path: &Path,
+    config: &ExportConfig,
+) -> Result<Vec<PathBuf>, String> {
+    let output_dir = match config.output_dir {
+        Some(ref dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => source_path
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from(".")),
+    };
+
+    std::fs::create_dir_all(&output_dir)
+        .map_err(|e| format!("Failed to create export directory {:?}: {}", output_dir, e))?;
+
+    let stem = source_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("transcription");
+
+    let cues = build_cues(result, config);
+    let mut written = Vec::new();
+
+    for format in &config.formats {
+        let content = match format {
+            ExportFormat::Srt => formats::to_srt(&cues),
+            ExportFormat::Vtt => formats::to_vtt(&cues),
+            ExportFormat::Txt => formats::to_txt(result),
+            ExportFormat::Json => formats::to_json(result)?,
+        };
+
+        let path = write_new_file(&output_dir, stem, format.extension(), &content)?;
+
+        log::info!("Exported transcription to {:?}", path);
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// Écrit `content` sous le premier nom libre parmi `<stem>.<ext>`,
+/// `<stem> (2).<ext>`, `<stem> (3).<ext>`...
+fn write_new_file(
+    dir: &Path,
+    stem: &str,
+    extension: &str,
+    content: &str,
+) -> Result<PathBuf, String> {
+    let mut index = 1;
+    loop {
+        let name = match index {
+            1 => format!("{}.{}", stem, extension),
+            _ => format!("{} ({}).{}", stem, index, extension),
+        };
+        let path = dir.join(name);
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                file.write_all(content.as_bytes())
+                    .map_err(|e| format!("Failed to write {:?}: {}", path, e))?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => index += 1,
+            Err(e) => return Err(format!("Failed to write {:?}: {}", path, e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_keeps_existing_files() {
+        let dir = std::env::temp_dir().join(format!("phonon-export-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("interview.txt"), "notes").unwrap();
+
+        let first = write_new_file(&dir, "interview", "txt", "export 1").unwrap();
+        let second = write_new_file(&dir, "interview", "txt", "export 2").unwrap();
+
+        assert_eq!(first, dir.join("interview (2).txt"));
+        assert_eq!(second, dir.join("interview (3).txt"));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("interview.txt")).unwrap(),
+            "notes"
+        );
+        assert_eq!(std::fs::read_to_string(&second).unwrap(), "export 2");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,8 +1,10 @@
 mod audio;
 mod commands;
 mod engines;
+mod export;
 mod hotkeys;
 mod llm;
+mod pipeline;
 mod platform;
 mod ptt;
 mod state;
@@ -14,11 +16,9 @@ mod voice_commands;
 pub use audio::AudioCapture;
 pub use types::*;
 
-use llm::LocalLlmEngine;
+use llm::LlmWorker;
 use state::AppState;
-use std::sync::Arc;
 use tauri::Manager;
-use tokio::sync::RwLock;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -58,7 +58,15 @@ pub fn run() {
             commands::get_current_model,
             commands::download_model,
             commands::delete_model,
+            commands::pause_download,
+            commands::cancel_download,
             commands::switch_model,
+            commands::get_custom_models,
+            commands::import_model,
+            commands::remove_custom_model,
+            commands::get_catalog_models,
+            commands::download_catalog_model,
+            commands::refresh_model_manifest,
             commands::is_engine_ready,
             commands::get_vosk_models,
             commands::download_vosk_model,
@@ -75,6 +83,7 @@ pub fn run() {
             commands::validate_groq_api_key,
             commands::delete_groq_api_key,
             commands::get_groq_quota,
+            commands::test_openai_provider,
             commands::translate_text,
             commands::summarize_text,
             commands::is_llm_model_available,
@@ -83,6 +92,8 @@ pub fn run() {
             commands::delete_llm_model,
             commands::summarize_text_local,
             commands::summarize_text_smart,
+            commands::cancel_llm_request,
+            commands::cancel_all_llm_requests,
             commands::auto_paste,
             commands::show_floating_window,
             commands::hide_floating_window,
@@ -95,6 +106,8 @@ pub fn run() {
             commands::toggle_subtitles,
             commands::file_transcription::transcribe_files,
             commands::file_transcription::get_supported_audio_formats,
+            commands::file_transcription::export_transcription,
+            commands::file_transcription::cancel_file_transcription,
             commands::get_snippets,
             commands::add_snippet,
             commands::update_snippet,
@@ -115,11 +128,31 @@ pub fn run() {
             };
 
             let model_manager = app_state.model_manager.clone();
+            let settings = app_state
+                .settings
+                .read()
+                .map(|settings| settings.clone())
+                .unwrap_or_default();
+            let manifest_url = settings.model_manifest_url.clone();
             app.manage(app_state);
-            app.manage(model_manager);
+            app.manage(model_manager.clone());
 
-            let llm_engine: Arc<RwLock<Option<LocalLlmEngine>>> = Arc::new(RwLock::new(None));
-            app.manage(llm_engine);
+            let llm_worker =
+                LlmWorker::spawn(model_manager.clone(), settings.llm_idle_timeout_minutes);
+            if settings.llm_provider == LlmProvider::Local {
+                llm_worker.preload(settings.local_llm_model);
+            }
+            llm::provider::init_local(llm_worker.clone());
+            app.manage(llm_worker);
+
+            // Le manifeste embarqué (ou en cache) reste utilisé hors ligne
+            if let Some(url) = manifest_url {
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = model_manager.refresh_manifest(&url).await {
+                        log::warn!("Model manifest refresh failed: {}", e);
+                    }
+                });
+            }
 
             // Initialiser les threads audio
             ptt::init_ptt_audio_thread();
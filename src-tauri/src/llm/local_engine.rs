@@ -2,19 +2,51 @@ use std::num::NonZeroU32;
 use std::path::Path;
 
 use llama_cpp_2::context::params::LlamaContextParams;
+use llama_cpp_2::context::LlamaContext;
 use llama_cpp_2::llama_backend::LlamaBackend;
 use llama_cpp_2::llama_batch::LlamaBatch;
 use llama_cpp_2::model::params::LlamaModelParams;
-use llama_cpp_2::model::{AddBos, LlamaModel};
+use llama_cpp_2::model::{AddBos, LlamaChatMessage, LlamaChatTemplate, LlamaModel};
 use llama_cpp_2::sampling::LlamaSampler;
 
 use crate::types::LocalLlmModel;
 
+/// Upper bound for the context window: the training context of recent
+/// models (32k+) would allocate gigabytes of KV cache for short dictations
+const MAX_CONTEXT_TOKENS: u32 = 8192;
+
+/// Error returned when a generation is interrupted by `is_cancelled`
+pub const CANCELLED: &str = "LLM request cancelled";
+
+/// Generation settings for a single completion
+#[derive(Debug, Clone)]
+pub struct CompletionParams {
+    /// Limited by the room left in the context window after the prompt
+    pub max_tokens: u32,
+    /// 0 samples greedily (deterministic output)
+    pub temperature: f32,
+    /// Generation stops before the first occurrence of any of these
+    pub stop: Vec<String>,
+}
+
+impl Default for CompletionParams {
+    fn default() -> Self {
+        Self {
+            max_tokens: 512,
+            temperature: 0.3,
+            stop: Vec::new(),
+        }
+    }
+}
+
 /// Moteur LLM local via llama.cpp (GGUF)
 pub struct LocalLlmEngine {
     backend: LlamaBackend,
     model: LlamaModel,
     model_type: LocalLlmModel,
+    /// Template embedded in the GGUF metadata (`tokenizer.chat_template`)
+    chat_template: Option<LlamaChatTemplate>,
+    n_ctx: u32,
 }
 
 impl LocalLlmEngine {
@@ -34,41 +66,136 @@ impl LocalLlmEngine {
         let model = LlamaModel::load_from_file(&backend, model_path, &model_params)
             .map_err(|e| format!("Failed to load model from {:?}: {}", model_path, e))?;
 
+        let chat_template = match model.chat_template(None) {
+            Ok(template) => Some(template),
+            Err(e) => {
+                log::warn!(
+                    "No chat template in GGUF metadata ({}), using built-in format",
+                    e
+                );
+                None
+            }
+        };
+        let n_ctx = model.n_ctx_train().clamp(512, MAX_CONTEXT_TOKENS);
+
         log::info!(
-            "Local LLM loaded: {} params, vocab size {}",
+            "Local LLM loaded: {} params, vocab size {}, context {} tokens",
             model.n_params(),
-            model.n_vocab()
+            model.n_vocab(),
+            n_ctx
         );
 
         Ok(Self {
             backend,
             model,
             model_type,
+            chat_template,
+            n_ctx,
         })
     }
 
-    /// Generates a summary of the given text
-    pub fn summarize(&self, text: &str) -> Result<String, String> {
-        let prompt = self.model_type.format_prompt("", text);
-
-        // Create a fresh context for this inference
+    /// Creates the inference context used for every request on this
+    /// model; only its KV cache is reset between requests
+    pub fn session(&self) -> Result<LlmSession<'_>, String> {
         let ctx_params = LlamaContextParams::default()
-            .with_n_ctx(Some(NonZeroU32::new(2048).unwrap()));
+            .with_n_ctx(NonZeroU32::new(self.n_ctx))
+            .with_n_batch(self.n_ctx);
 
-        let mut ctx = self
+        let ctx = self
             .model
             .new_context(&self.backend, ctx_params)
             .map_err(|e| format!("Failed to create inference context: {}", e))?;
 
+        Ok(LlmSession { engine: self, ctx })
+    }
+
+    /// Applies the model's chat template, falling back to the known format
+    /// of the built-in models when the GGUF has none or llama.cpp does not
+    /// support it
+    fn format_chat(&self, system: &str, user: &str) -> String {
+        if let Some(template) = &self.chat_template {
+            let mut roles = Vec::new();
+            if !system.is_empty() {
+                roles.push(("system", system));
+            }
+            roles.push(("user", user));
+
+            let messages: Result<Vec<_>, _> = roles
+                .into_iter()
+                .map(|(role, content)| LlamaChatMessage::new(role.to_string(), content.to_string()))
+                .collect();
+            let prompt = messages.map_err(|e| e.to_string()).and_then(|messages| {
+                self.model
+                    .apply_chat_template(template, &messages, true)
+                    .map_err(|e| e.to_string())
+            });
+            match prompt {
+                Ok(prompt) => return prompt,
+                Err(e) => log::warn!("Failed to apply chat template: {}", e),
+            }
+        }
+        self.model_type.format_prompt(system, user)
+    }
+
+    pub fn model_type(&self) -> LocalLlmModel {
+        self.model_type
+    }
+
+    pub fn display_name(&self) -> String {
+        format!("Local LLM ({})", self.model_type.display_name())
+    }
+}
+
+/// Inference context of a loaded model, reused from one request to the next
+pub struct LlmSession<'a> {
+    engine: &'a LocalLlmEngine,
+    ctx: LlamaContext<'a>,
+}
+
+impl LlmSession<'_> {
+    /// Answers the `user` message following the `system` instructions
+    /// (empty for none), passing each piece of text to `on_token` as soon as
+    /// it can no longer be cut by a stop sequence. Generation stops with
+    /// `CANCELLED` once `is_cancelled` returns true.
+    pub fn complete(
+        &mut self,
+        system: &str,
+        user: &str,
+        params: &CompletionParams,
+        on_token: &dyn Fn(&str),
+        is_cancelled: &dyn Fn() -> bool,
+    ) -> Result<String, String> {
+        let prompt = self.engine.format_chat(system, user);
+        self.generate(&prompt, params, on_token, is_cancelled)
+    }
+
+    fn generate(
+        &mut self,
+        prompt: &str,
+        params: &CompletionParams,
+        on_token: &dyn Fn(&str),
+        is_cancelled: &dyn Fn() -> bool,
+    ) -> Result<String, String> {
+        let engine = self.engine;
+        // Drop the previous request from the context
+        self.ctx.clear_kv_cache();
+
         // Tokenize the prompt
-        let tokens = self
+        let tokens = engine
             .model
-            .str_to_token(&prompt, AddBos::Always)
+            .str_to_token(prompt, AddBos::Always)
             .map_err(|e| format!("Failed to tokenize prompt: {}", e))?;
 
         if tokens.is_empty() {
             return Err("Prompt tokenized to zero tokens".to_string());
         }
+        if tokens.len() >= engine.n_ctx as usize {
+            return Err(format!(
+                "Prompt too long: {} tokens for a {} token context",
+                tokens.len(),
+                engine.n_ctx
+            ));
+        }
 
         log::info!("Prompt tokenized to {} tokens", tokens.len());
 
@@ -84,36 +211,67 @@ impl LocalLlmEngine {
         }
 
         // Evaluate the prompt
-        ctx.decode(&mut batch)
+        self.ctx
+            .decode(&mut batch)
             .map_err(|e| format!("Failed to decode prompt: {}", e))?;
 
-        // Set up sampling: temperature 0.3 for focused output
-        let mut sampler = LlamaSampler::chain_simple([
-            LlamaSampler::temp(0.3),
-            LlamaSampler::dist(42),
-        ]);
+        let mut sampler = if params.temperature <= 0.0 {
+            LlamaSampler::greedy()
+        } else {
+            LlamaSampler::chain_simple([
+                LlamaSampler::temp(params.temperature),
+                LlamaSampler::dist(42),
+            ])
+        };
 
         // UTF-8 decoder for token-to-text conversion
         let mut decoder = encoding_rs::UTF_8.new_decoder();
 
-        // Generate up to 512 output tokens
-        let max_output_tokens = 512;
+        // Generate until the token budget or the context is exhausted
+        let room = engine.n_ctx as usize - tokens.len();
+        let max_output_tokens = (params.max_tokens as usize).min(room);
         let mut output = String::new();
+        let mut emitted = 0;
+        // Text that may be the start of a stop sequence is held back
+        let held_back = params
+            .stop
+            .iter()
+            .map(String::len)
+            .max()
+            .unwrap_or(0)
+            .saturating_sub(1);
         let mut n_cur = tokens.len() as i32;
 
         for _ in 0..max_output_tokens {
-            let new_token = sampler.sample(&ctx, batch.n_tokens() - 1);
+            if is_cancelled() {
+                log::info!("Local LLM generation cancelled");
+                return Err(CANCELLED.to_string());
+            }
+
+            let new_token = sampler.sample(&self.ctx, batch.n_tokens() - 1);
             sampler.accept(new_token);
 
             // Stop at end-of-generation
-            if self.model.is_eog_token(new_token) {
+            if engine.model.is_eog_token(new_token) {
                 break;
             }
 
-            // Decode token to text
-            match self.model.token_to_piece(new_token, &mut decoder, true, None) {
-                Ok(piece) => output.push_str(&piece),
-                Err(_) => {} // Skip invalid tokens
+            // Decode token to text (invalid tokens are skipped)
+            if let Ok(piece) = engine
+                .model
+                .token_to_piece(new_token, &mut decoder, true, None)
+            {
+                if push_until_stop(&mut output, &piece, &params.stop) {
+                    break;
+                }
+                let mut confirmed = output.len().saturating_sub(held_back);
+                while !output.is_char_boundary(confirmed) {
+                    confirmed -= 1;
+                }
+                if confirmed > emitted {
+                    on_token(&output[emitted..confirmed]);
+                    emitted = confirmed;
+                }
             }
 
             // Prepare next iteration
@@ -123,10 +281,15 @@ impl LocalLlmEngine {
                 .map_err(|e| format!("Failed to add generated token: {}", e))?;
             n_cur += 1;
 
-            ctx.decode(&mut batch)
+            self.ctx
+                .decode(&mut batch)
                 .map_err(|e| format!("Failed to decode generated token: {}", e))?;
         }
 
+        if output.len() > emitted {
+            on_token(&output[emitted..]);
+        }
+
         let result = output.trim().to_string();
         if result.is_empty() {
             return Err("Model generated empty output".to_string());
@@ -135,15 +298,47 @@ impl LocalLlmEngine {
         log::info!("Local LLM generated {} chars", result.len());
         Ok(result)
     }
+}
 
-    pub fn model_type(&self) -> LocalLlmModel {
-        self.model_type
+/// Appends `piece` to `output`; if this completes a stop sequence, cuts
+/// `output` before it and returns true
+fn push_until_stop(output: &mut String, piece: &str, stop: &[String]) -> bool {
+    // Only the tail can contain a stop sequence completed by this piece
+    let longest_stop = stop.iter().map(String::len).max().unwrap_or(0);
+    let mut from = output.len().saturating_sub(longest_stop);
+    while !output.is_char_boundary(from) {
+        from -= 1;
     }
+    output.push_str(piece);
 
-    pub fn display_name(&self) -> String {
-        format!("Local LLM ({})", self.model_type.display_name())
+    let stop_at = stop
+        .iter()
+        .filter(|stop| !stop.is_empty())
+        .filter_map(|stop| output[from..].find(stop.as_str()))
+        .min();
+    if let Some(position) = stop_at {
+        output.truncate(from + position);
     }
+    stop_at.is_some()
 }
 
-unsafe impl Send for LocalLlmEngine {}
-unsafe impl Sync for LocalLlmEngine {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_until_stop() {
+        let stop = vec!["\n\nUser:".to_string(), "###".to_string()];
+        let mut output = String::new();
+        for piece in ["Bonjour", " à tous.", "\n\nUs"] {
+            assert!(!push_until_stop(&mut output, piece, &stop));
+        }
+        assert!(push_until_stop(&mut output, "er: suite", &stop));
+        assert_eq!(output, "Bonjour à tous.");
+
+        let mut output = String::from("é");
+        assert!(!push_until_stop(&mut output, "##", &stop));
+        assert!(push_until_stop(&mut output, "#", &stop));
+        assert_eq!(output, "é");
+    }
+}
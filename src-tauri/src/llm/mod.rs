@@ -1,7 +1,14 @@
 pub mod groq_client;
 pub mod local_engine;
+pub mod openai_client;
 pub mod post_processor;
+pub mod provider;
+pub mod translator;
+pub mod worker;
 
 pub use groq_client::GroqError;
 pub use local_engine::LocalLlmEngine;
+pub use openai_client::{ChatEndpoint, LlmError};
 pub use post_processor::process;
+pub use provider::CompletionProvider;
+pub use worker::LlmWorker;
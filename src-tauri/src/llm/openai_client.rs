@@ -0,0 +1,401 @@
+//! Client de chat compatible OpenAI (`POST {base_url}/chat/completions`)
+//!
+//! Sert pour Groq comme pour un serveur auto-hébergé (vLLM, Ollama,
+//! llama-server) : seuls l'URL, le modèle, la clé et les en-têtes changent.
+
+use futures_util::StreamExt;
+use reqwest::header::HeaderMap;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::types::OpenAiProviderConfig;
+
+const TIMEOUT_SECONDS: u64 = 30;
+
+/// Point d'accès d'une API de chat compatible OpenAI
+#[derive(Clone)]
+pub struct ChatEndpoint {
+    pub base_url: String,
+    pub model: String,
+    pub api_key: Option<String>,
+    pub headers: Vec<(String, String)>,
+    /// Appelé avec les en-têtes de chaque réponse (quota Groq)
+    pub on_response: Option<fn(&HeaderMap)>,
+}
+
+impl ChatEndpoint {
+    pub fn from_config(config: &OpenAiProviderConfig) -> Self {
+        Self {
+            base_url: config.base_url.clone(),
+            model: config.model.clone(),
+            api_key: config.api_key.clone().filter(|key| !key.is_empty()),
+            headers: config
+                .headers
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            on_response: None,
+        }
+    }
+
+    fn completions_url(&self) -> String {
+        format!("{}/chat/completions", self.base_url.trim_end_matches('/'))
+    }
+}
+
+#[derive(Debug)]
+pub enum LlmError {
+    InvalidApiKey,
+    RateLimit,
+    Timeout,
+    NetworkError(String),
+    ParseError(String),
+}
+
+impl std::fmt::Display for LlmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LlmError::InvalidApiKey => write!(f, "Invalid API key"),
+            LlmError::RateLimit => write!(f, "Rate limit exceeded"),
+            LlmError::Timeout => write!(f, "Request timeout"),
+            LlmError::NetworkError(msg) => write!(f, "Network error: {}", msg),
+            LlmError::ParseError(msg) => write!(f, "Parse error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for LlmError {}
+
+#[derive(Serialize)]
+struct ChatMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Serialize)]
+struct ChatCompletionRequest {
+    model: String,
+    messages: Vec<ChatMessage>,
+    temperature: f32,
+    max_tokens: u32,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
+}
+
+#[derive(Deserialize)]
+struct ChatCompletionResponse {
+    choices: Vec<Choice>,
+}
+
+#[derive(Deserialize)]
+struct Choice {
+    message: ResponseMessage,
+}
+
+#[derive(Deserialize)]
+struct ResponseMessage {
+    content: String,
+}
+
+#[derive(Deserialize)]
+struct ChatCompletionChunk {
+    choices: Vec<ChunkChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChunkChoice {
+    #[serde(default)]
+    delta: ChunkDelta,
+}
+
+#[derive(Deserialize, Default)]
+struct ChunkDelta {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+pub async fn send_completion(
+    endpoint: &ChatEndpoint,
+    system_prompt: &str,
+    text: &str,
+) -> Result<String, LlmError> {
+    let client = Client::builder()
+        .timeout(Duration::from_secs(TIMEOUT_SECONDS))
+        .build()
+        .map_err(|e| LlmError::NetworkError(e.to_string()))?;
+
+    let response = post(&client, endpoint, system_prompt, text, false).await?;
+
+    let response_body: ChatCompletionResponse = response
+        .json()
+        .await
+        .map_err(|e| LlmError::ParseError(e.to_string()))?;
+
+    response_body
+        .choices
+        .into_iter()
+        .next()
+        .map(|choice| choice.message.content)
+        .ok_or_else(|| LlmError::ParseError("No choices in response".to_string()))
+}
+
+/// Comme `send_completion`, en recevant la réponse en Server-Sent Events :
+/// `on_token` reçoit chaque fragment dès son arrivée
+pub async fn send_completion_streaming(
+    endpoint: &ChatEndpoint,
+    system_prompt: &str,
+    text: &str,
+    on_token: &(dyn Fn(&str) + Send + Sync),
+) -> Result<String, LlmError> {
+    // Une génération longue dépasse le délai global : le délai s'applique
+    // à la connexion puis à chaque fragment
+    let client = Client::builder()
+        .connect_timeout(Duration::from_secs(TIMEOUT_SECONDS))
+        .build()
+        .map_err(|e| LlmError::NetworkError(e.to_string()))?;
+
+    let response = post(&client, endpoint, system_prompt, text, true).await?;
+
+    let mut stream = response.bytes_stream();
+    let mut buffer = Vec::new();
+    let mut output = String::new();
+    loop {
+        let chunk = tokio::time::timeout(Duration::from_secs(TIMEOUT_SECONDS), stream.next())
+            .await
+            .map_err(|_| LlmError::Timeout)?;
+        let Some(chunk) = chunk else {
+            break;
+        };
+        let chunk = chunk.map_err(|e| LlmError::NetworkError(e.to_string()))?;
+        buffer.extend_from_slice(&chunk);
+
+        for data in drain_sse_data(&mut buffer) {
+            if data == "[DONE]" {
+                return Ok(output);
+            }
+            let chunk: ChatCompletionChunk =
+                serde_json::from_str(&data).map_err(|e| LlmError::ParseError(e.to_string()))?;
+            let content = chunk
+                .choices
+                .into_iter()
+                .next()
+                .and_then(|choice| choice.delta.content);
+            if let Some(content) = content.filter(|content| !content.is_empty()) {
+                on_token(&content);
+                output.push_str(&content);
+            }
+        }
+    }
+    Ok(output)
+}
+
+async fn post(
+    client: &Client,
+    endpoint: &ChatEndpoint,
+    system_prompt: &str,
+    text: &str,
+    stream: bool,
+) -> Result<reqwest::Response, LlmError> {
+    let request_body = ChatCompletionRequest {
+        model: endpoint.model.clone(),
+        messages: vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: system_prompt.to_string(),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: text.to_string(),
+            },
+        ],
+        temperature: 0.3,
+        max_tokens: 2048,
+        stream,
+    };
+
+    let mut request = client
+        .post(endpoint.completions_url())
+        .header("Content-Type", "application/json");
+    if let Some(api_key) = &endpoint.api_key {
+        request = request.header("Authorization", format!("Bearer {}", api_key));
+    }
+    for (name, value) in &endpoint.headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+
+    let response = request.json(&request_body).send().await.map_err(|e| {
+        if e.is_timeout() {
+            LlmError::Timeout
+        } else {
+            LlmError::NetworkError(e.to_string())
+        }
+    })?;
+
+    let status = response.status();
+
+    if let Some(on_response) = endpoint.on_response {
+        on_response(response.headers());
+    }
+
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(LlmError::InvalidApiKey);
+    }
+
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(LlmError::RateLimit);
+    }
+
+    if !status.is_success() {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(LlmError::NetworkError(format!(
+            "HTTP {}: {}",
+            status, error_text
+        )));
+    }
+
+    Ok(response)
+}
+
+/// Retire de `buffer` les lignes complètes et retourne le contenu de leurs
+/// champs `data:` ; une ligne inachevée reste dans le buffer
+fn drain_sse_data(buffer: &mut Vec<u8>) -> Vec<String> {
+    let Some(end) = buffer.iter().rposition(|&byte| byte == b'\n') else {
+        return Vec::new();
+    };
+    let lines: Vec<u8> = buffer.drain(..=end).collect();
+    String::from_utf8_lossy(&lines)
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|data| data.trim().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    /// Serveur HTTP minimal : répond `status`/`body` à une requête et
+    /// retourne la requête reçue
+    fn mock_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut received = Vec::new();
+            let mut buffer = [0u8; 4096];
+            loop {
+                let read = stream.read(&mut buffer).unwrap();
+                if read == 0 {
+                    break;
+                }
+                received.extend_from_slice(&buffer[..read]);
+                let request = String::from_utf8_lossy(&received);
+                if let Some(end) = request.find("\r\n\r\n") {
+                    let length = request[..end]
+                        .lines()
+                        .find_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length")
+                                .then(|| value.trim().parse::<usize>().ok())?
+                        })
+                        .unwrap_or(0);
+                    if received.len() >= end + 4 + length {
+                        break;
+                    }
+                }
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            String::from_utf8_lossy(&received).into_owned()
+        });
+        (base_url, handle)
+    }
+
+    fn endpoint(base_url: String) -> ChatEndpoint {
+        let mut config = OpenAiProviderConfig {
+            base_url: format!("{}/", base_url),
+            model: "qwen2.5-7b-instruct".to_string(),
+            api_key: Some("secret".to_string()),
+            ..Default::default()
+        };
+        config
+            .headers
+            .insert("X-Team".to_string(), "phonon".to_string());
+        ChatEndpoint::from_config(&config)
+    }
+
+    #[tokio::test]
+    async fn test_send_completion_to_custom_server() {
+        let (base_url, server) = mock_server(
+            "200 OK",
+            r#"{"choices":[{"message":{"role":"assistant","content":"Bonjour."}}]}"#,
+        );
+
+        let reply = send_completion(&endpoint(base_url), "Corrige", "bonjour")
+            .await
+            .unwrap();
+        assert_eq!(reply, "Bonjour.");
+
+        let request = server.join().unwrap();
+        let lowercase = request.to_lowercase();
+        assert!(request.starts_with("POST /v1/chat/completions HTTP/1.1"));
+        assert!(lowercase.contains("authorization: bearer secret"));
+        assert!(lowercase.contains("x-team: phonon"));
+        assert!(request.contains(r#""model":"qwen2.5-7b-instruct""#));
+    }
+
+    #[tokio::test]
+    async fn test_send_completion_maps_unauthorized() {
+        let (base_url, server) = mock_server("401 Unauthorized", r#"{"error":"bad key"}"#);
+
+        let result = send_completion(&endpoint(base_url), "Corrige", "bonjour").await;
+        assert!(matches!(result, Err(LlmError::InvalidApiKey)));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_drain_sse_data_keeps_partial_line() {
+        let mut buffer = "data: {\"a\":1}\n\n: ping\ndata: [DO".as_bytes().to_vec();
+        assert_eq!(drain_sse_data(&mut buffer), vec![r#"{"a":1}"#.to_string()]);
+        buffer.extend_from_slice(b"NE]\n\n");
+        assert_eq!(drain_sse_data(&mut buffer), vec!["[DONE]".to_string()]);
+        assert!(buffer.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_completion_streaming() {
+        let (base_url, server) = mock_server(
+            "200 OK",
+            "data: {\"choices\":[{\"delta\":{\"role\":\"assistant\"}}]}\n\n\
+             data: {\"choices\":[{\"delta\":{\"content\":\"Bon\"}}]}\n\n\
+             data: {\"choices\":[{\"delta\":{\"content\":\"jour.\"}}]}\n\n\
+             data: [DONE]\n\n",
+        );
+
+        let tokens = std::sync::Mutex::new(Vec::new());
+        let on_token = |token: &str| tokens.lock().unwrap().push(token.to_string());
+        let reply = send_completion_streaming(&endpoint(base_url), "Corrige", "bonjour", &on_token)
+            .await
+            .unwrap();
+        assert_eq!(reply, "Bonjour.");
+        assert_eq!(*tokens.lock().unwrap(), vec!["Bon", "jour."]);
+
+        let request = server.join().unwrap();
+        assert!(request.contains(r#""stream":true"#));
+    }
+}
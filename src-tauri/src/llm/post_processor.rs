@@ -1,6 +1,7 @@
 use crate::types::{DictationMode, LlmMode};
 
-use super::groq_client;
+use super::provider::TokenCallback;
+use super::CompletionProvider;
 
 const PROMPT_BASIC: &str = "Tu es un correcteur de texte. Corrige uniquement la ponctuation, les majuscules et les fautes de grammaire évidentes. Ne modifie pas le sens ni le style. Retourne uniquement le texte corrigé, sans explication.";
 
@@ -30,7 +31,8 @@ pub async fn process(
     text: &str,
     llm_mode: LlmMode,
     dictation_mode: DictationMode,
-    api_key: &str,
+    provider: &dyn CompletionProvider,
+    on_token: Option<TokenCallback>,
 ) -> Result<String, String> {
     // If LLM mode is Off, return text as-is
     if llm_mode == LlmMode::Off {
@@ -40,7 +42,16 @@ pub async fn process(
     let prompt = get_prompt(llm_mode, dictation_mode);
     let user_message = format!("Texte: {}", text);
 
-    match groq_client::send_completion(api_key, prompt, &user_message).await {
+    let result = match on_token {
+        Some(on_token) => {
+            provider
+                .complete_streaming(prompt, &user_message, on_token)
+                .await
+        }
+        None => provider.complete(prompt, &user_message).await,
+    };
+
+    match result {
         Ok(processed_text) => Ok(processed_text),
         Err(e) => {
             // Log the error and return original text (graceful fallback)
@@ -0,0 +1,98 @@
+//! Fournisseurs de complétion
+//!
+//! Correction, formatage contextuel, traduction et actions vocales passent
+//! par `CompletionProvider`, quel que soit le provider des réglages : API
+//! compatible OpenAI (Groq ou serveur auto-hébergé) ou LLM local hors ligne.
+
+use async_trait::async_trait;
+use std::sync::{Arc, OnceLock};
+
+use super::local_engine::CompletionParams;
+use super::openai_client::{self, ChatEndpoint};
+use super::worker::LlmWorker;
+use crate::types::LocalLlmModel;
+
+/// Reçoit chaque fragment de texte dès qu'il est généré
+pub type TokenCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
+#[async_trait]
+pub trait CompletionProvider: Send + Sync {
+    /// Réponse du modèle à `text` selon les consignes de `system_prompt`
+    async fn complete(&self, system_prompt: &str, text: &str) -> Result<String, String> {
+        self.complete_streaming(system_prompt, text, Arc::new(|_: &str| {}))
+            .await
+    }
+
+    /// Comme `complete`, en transmettant la réponse au fil de la génération
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        text: &str,
+        on_token: TokenCallback,
+    ) -> Result<String, String>;
+}
+
+#[async_trait]
+impl CompletionProvider for ChatEndpoint {
+    async fn complete(&self, system_prompt: &str, text: &str) -> Result<String, String> {
+        openai_client::send_completion(self, system_prompt, text)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        text: &str,
+        on_token: TokenCallback,
+    ) -> Result<String, String> {
+        openai_client::send_completion_streaming(self, system_prompt, text, &*on_token)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// LLM local (llama.cpp), sans connexion réseau
+pub struct LocalProvider {
+    worker: LlmWorker,
+    model: LocalLlmModel,
+}
+
+impl LocalProvider {
+    pub fn new(worker: LlmWorker, model: LocalLlmModel) -> Self {
+        Self { worker, model }
+    }
+}
+
+#[async_trait]
+impl CompletionProvider for LocalProvider {
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        text: &str,
+        on_token: TokenCallback,
+    ) -> Result<String, String> {
+        // Même budget que les API de chat
+        let params = CompletionParams {
+            max_tokens: 2048,
+            ..Default::default()
+        };
+        self.worker
+            .complete(self.model, system_prompt, text, params, on_token)?
+            .response()
+            .await
+    }
+}
+
+static LOCAL: OnceLock<LlmWorker> = OnceLock::new();
+
+/// Rend le LLM local accessible hors des commandes (pipeline, raccourcis)
+pub fn init_local(worker: LlmWorker) {
+    let _ = LOCAL.set(worker);
+}
+
+/// Provider local pour `model`, une fois `init_local` appelé
+pub fn local(model: LocalLlmModel) -> Result<LocalProvider, String> {
+    let worker = LOCAL.get().ok_or("Local LLM not initialized")?;
+    Ok(LocalProvider::new(worker.clone(), model))
+}
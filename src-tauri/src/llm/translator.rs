@@ -0,0 +1,50 @@
+use super::provider::TokenCallback;
+use super::CompletionProvider;
+
+/// Nom anglais d'une langue cible (code ISO 639-1), pour le prompt
+pub fn language_name(code: &str) -> &str {
+    match code {
+        "fr" => "French",
+        "en" => "English",
+        "de" => "German",
+        "es" => "Spanish",
+        "it" => "Italian",
+        "pt" => "Portuguese",
+        "nl" => "Dutch",
+        "ru" => "Russian",
+        "zh" => "Chinese",
+        "ja" => "Japanese",
+        "ko" => "Korean",
+        "ar" => "Arabic",
+        _ => code,
+    }
+}
+
+fn system_prompt(target_language: &str) -> String {
+    let language_name = language_name(target_language);
+    format!(
+        "You are a professional translator. Translate the following text to {}. \
+         Only output the translation, nothing else. Preserve the original formatting, \
+         punctuation and tone. If the text is already in {}, return it unchanged.",
+        language_name, language_name
+    )
+}
+
+/// Traduit `text` vers `target_language` via le provider LLM
+pub async fn translate(
+    text: &str,
+    target_language: &str,
+    provider: &dyn CompletionProvider,
+    on_token: Option<TokenCallback>,
+) -> Result<String, String> {
+    log::info!("Translating {} chars to {}", text.len(), language_name(target_language));
+
+    let prompt = system_prompt(target_language);
+    let translated = match on_token {
+        Some(on_token) => provider.complete_streaming(&prompt, text, on_token).await,
+        None => provider.complete(&prompt, text).await,
+    }
+    .map_err(|e| format!("Translation failed: {}", e))?;
+    Ok(translated.trim().to_string())
+}
+
@@ -0,0 +1,287 @@
+//! Worker du LLM local
+//!
+//! Un thread dédié possède le moteur llama.cpp et traite les requêtes dans
+//! leur ordre d'arrivée. Le modèle (et son contexte d'inférence) reste chargé
+//! d'une requête à l'autre ; il peut être préchargé au démarrage et est
+//! libéré après une période d'inactivité configurable. Chaque requête porte
+//! un identifiant qui permet de l'annuler sans toucher aux autres.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+use super::local_engine::{CompletionParams, LocalLlmEngine, CANCELLED};
+use super::provider::TokenCallback;
+use crate::engines::ModelManager;
+use crate::types::LocalLlmModel;
+
+const STOPPED: &str = "Local LLM worker stopped";
+
+/// Drapeaux d'annulation des requêtes en attente ou en cours, par identifiant
+type ActiveRequests = Arc<Mutex<HashMap<u64, Arc<AtomicBool>>>>;
+
+/// Inscription d'une requête parmi les requêtes actives, retirée quand la
+/// requête est abandonnée ou traitée
+struct Ticket {
+    id: u64,
+    cancelled: Arc<AtomicBool>,
+    active: ActiveRequests,
+}
+
+impl Drop for Ticket {
+    fn drop(&mut self) {
+        if let Ok(mut active) = self.active.lock() {
+            active.remove(&self.id);
+        }
+    }
+}
+
+struct Request {
+    model: LocalLlmModel,
+    path: PathBuf,
+    system_prompt: String,
+    text: String,
+    params: CompletionParams,
+    on_token: TokenCallback,
+    ticket: Ticket,
+    reply: oneshot::Sender<Result<String, String>>,
+}
+
+/// Requête mise en file par `LlmWorker::complete`
+pub struct LlmRequest {
+    /// Identifiant à passer à `LlmWorker::cancel`
+    pub id: u64,
+    response: oneshot::Receiver<Result<String, String>>,
+}
+
+impl LlmRequest {
+    /// Attend la fin de la génération
+    pub async fn response(self) -> Result<String, String> {
+        self.response.await.map_err(|_| STOPPED.to_string())?
+    }
+}
+
+enum Job {
+    Load(LocalLlmModel, PathBuf),
+    Complete(Box<Request>),
+    Unload(LocalLlmModel),
+}
+
+impl Job {
+    /// Modèle à charger pour traiter cette tâche
+    fn target(&self) -> Option<(LocalLlmModel, PathBuf)> {
+        match self {
+            Job::Load(model, path) => Some((*model, path.clone())),
+            Job::Complete(request) => Some((request.model, request.path.clone())),
+            Job::Unload(_) => None,
+        }
+    }
+
+    fn fail(self, error: String) {
+        if let Job::Complete(request) = self {
+            let _ = request.reply.send(Err(error));
+        }
+    }
+}
+
+/// File de requêtes vers le thread du LLM local
+#[derive(Clone)]
+pub struct LlmWorker {
+    jobs: Sender<Job>,
+    model_manager: Arc<ModelManager>,
+    next_id: Arc<AtomicU64>,
+    active: ActiveRequests,
+    idle_timeout_minutes: Arc<AtomicU32>,
+}
+
+impl LlmWorker {
+    /// Démarre le thread du LLM local ; `idle_timeout_minutes` à 0 garde le
+    /// modèle chargé indéfiniment
+    pub fn spawn(model_manager: Arc<ModelManager>, idle_timeout_minutes: u32) -> Self {
+        let (jobs, receiver) = mpsc::channel();
+        let worker = Self {
+            jobs,
+            model_manager,
+            next_id: Arc::new(AtomicU64::new(1)),
+            active: ActiveRequests::default(),
+            idle_timeout_minutes: Arc::new(AtomicU32::new(idle_timeout_minutes)),
+        };
+
+        let idle_timeout_minutes = worker.idle_timeout_minutes.clone();
+        std::thread::spawn(move || run(receiver, &idle_timeout_minutes));
+        worker
+    }
+
+    /// Charge `model` en arrière-plan pour que la première requête n'attende pas
+    pub fn preload(&self, model: LocalLlmModel) {
+        match self.model_path(model) {
+            Ok(path) => {
+                let _ = self.jobs.send(Job::Load(model, path));
+            }
+            Err(e) => log::info!("Local LLM not preloaded: {}", e),
+        }
+    }
+
+    /// Libère `model` s'il est chargé (avant sa suppression par exemple)
+    pub fn unload(&self, model: LocalLlmModel) {
+        let _ = self.jobs.send(Job::Unload(model));
+    }
+
+    /// Met la requête en file ; `model` est chargé à la place du modèle
+    /// courant si besoin
+    pub fn complete(
+        &self,
+        model: LocalLlmModel,
+        system_prompt: &str,
+        text: &str,
+        params: CompletionParams,
+        on_token: TokenCallback,
+    ) -> Result<LlmRequest, String> {
+        let path = self.model_path(model)?;
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.active
+            .lock()
+            .map_err(|e| e.to_string())?
+            .insert(id, cancelled.clone());
+
+        let (reply, response) = oneshot::channel();
+        let request = Request {
+            model,
+            path,
+            system_prompt: system_prompt.to_string(),
+            text: text.to_string(),
+            params,
+            on_token,
+            ticket: Ticket {
+                id,
+                cancelled,
+                active: self.active.clone(),
+            },
+            reply,
+        };
+        self.jobs
+            .send(Job::Complete(Box::new(request)))
+            .map_err(|_| STOPPED.to_string())?;
+        Ok(LlmRequest { id, response })
+    }
+
+    /// Interrompt la requête `id`, en cours ou en attente ; sans effet si
+    /// elle est déjà terminée
+    pub fn cancel(&self, id: u64) {
+        if let Ok(active) = self.active.lock() {
+            if let Some(cancelled) = active.get(&id) {
+                cancelled.store(true, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Interrompt la génération en cours et abandonne toutes les requêtes en attente
+    pub fn cancel_all(&self) {
+        if let Ok(active) = self.active.lock() {
+            for cancelled in active.values() {
+                cancelled.store(true, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Prend effet à la prochaine période d'attente
+    pub fn set_idle_timeout(&self, minutes: u32) {
+        self.idle_timeout_minutes.store(minutes, Ordering::SeqCst);
+    }
+
+    fn model_path(&self, model: LocalLlmModel) -> Result<PathBuf, String> {
+        self.model_manager.get_llm_model_path(model).ok_or_else(|| {
+            format!(
+                "Modèle LLM {} non installé. Téléchargez-le dans les paramètres.",
+                model.display_name()
+            )
+        })
+    }
+}
+
+fn run(jobs: Receiver<Job>, idle_timeout_minutes: &AtomicU32) {
+    let mut next = jobs.recv().ok();
+    while let Some(job) = next {
+        next = match job.target() {
+            Some((model, path)) => match LocalLlmEngine::new(&path, model) {
+                Ok(engine) => serve(&engine, job, &jobs, idle_timeout_minutes),
+                Err(e) => {
+                    log::error!("Failed to load local LLM: {}", e);
+                    job.fail(e);
+                    None
+                }
+            },
+            // Rien n'est chargé
+            None => None,
+        };
+        // Le moteur précédent est libéré avant d'en charger un autre
+        if next.is_none() {
+            next = jobs.recv().ok();
+        }
+    }
+    log::info!("{}", STOPPED);
+}
+
+/// Traite les tâches tant qu'elles portent sur le modèle chargé ; retourne
+/// la première qui en demande un autre, ou `None` une fois le modèle libéré
+fn serve(
+    engine: &LocalLlmEngine,
+    first: Job,
+    jobs: &Receiver<Job>,
+    idle_timeout_minutes: &AtomicU32,
+) -> Option<Job> {
+    let mut session = match engine.session() {
+        Ok(session) => session,
+        Err(e) => {
+            log::error!("{}", e);
+            first.fail(e);
+            return None;
+        }
+    };
+    let loaded = engine.model_type();
+
+    let mut job = first;
+    loop {
+        match job {
+            Job::Load(model, _) if model == loaded => {}
+            Job::Complete(request) if request.model == loaded => {
+                let is_cancelled = || request.ticket.cancelled.load(Ordering::SeqCst);
+                let result = if is_cancelled() {
+                    Err(CANCELLED.to_string())
+                } else {
+                    session.complete(
+                        &request.system_prompt,
+                        &request.text,
+                        &request.params,
+                        &*request.on_token,
+                        &is_cancelled,
+                    )
+                };
+                let _ = request.reply.send(result);
+            }
+            Job::Unload(model) if model == loaded => {
+                log::info!("Unloading local LLM {:?}", loaded);
+                return None;
+            }
+            Job::Unload(_) => {}
+            other => return Some(other),
+        }
+
+        job = match idle_timeout_minutes.load(Ordering::SeqCst) {
+            0 => jobs.recv().ok()?,
+            minutes => match jobs.recv_timeout(Duration::from_secs(minutes as u64 * 60)) {
+                Ok(job) => job,
+                Err(RecvTimeoutError::Timeout) => {
+                    log::info!("Local LLM idle for {} min, unloading", minutes);
+                    return None;
+                }
+                Err(RecvTimeoutError::Disconnected) => return None,
+            },
+        };
+    }
+}
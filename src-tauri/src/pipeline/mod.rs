@@ -0,0 +1,173 @@
+//! Pipeline de dictée commun à toutes les entrées
+//!
+//! Push-to-talk, enregistrement depuis l'interface et transcription de
+//! fichiers passent par les mêmes étapes (VAD, transcription, dictionnaire,
+//! commandes vocales, snippets, LLM) puis les mêmes sorties (historique,
+//! statistiques, collage...), pour se comporter de façon identique.
+
+pub mod sinks;
+pub mod stages;
+
+use crate::engines::LiveSession;
+use crate::llm::provider::TokenCallback;
+use crate::types::{AppSettings, Snippet, TranscriptionResult};
+use crate::voice_commands::Action;
+
+pub use crate::engines::SharedEngine;
+pub use sinks::{ActionsSink, CursorTyper, EventSink, HistorySink, PasteSink, StatsSink};
+pub use stages::{
+    DictionaryStage, FinishSessionStage, LlmStage, ResampleStage, SnippetsStage, TranscribeStage,
+    TranslateSpeechStage, VadStage, VoiceCommandsStage,
+};
+
+/// Données qui traversent le pipeline
+pub struct PipelineContext {
+    pub settings: AppSettings,
+    pub audio: Vec<f32>,
+    pub sample_rate: u32,
+    /// Résultat brut du moteur, renseigné par l'étape de transcription
+    pub result: Option<TranscriptionResult>,
+    /// Texte en cours de post-traitement
+    pub text: String,
+    /// Actions extraites par les commandes vocales, exécutées par `ActionsSink`
+    pub actions: Vec<Action>,
+    pub snippets: Vec<Snippet>,
+    /// Reçoit la réponse du LLM au fil du post-traitement
+    pub llm_tokens: Option<TokenCallback>,
+}
+
+impl PipelineContext {
+    /// Contexte pour un enregistrement à transcrire
+    pub fn from_audio(settings: AppSettings, audio: Vec<f32>, sample_rate: u32) -> Self {
+        Self {
+            settings,
+            audio,
+            sample_rate,
+            result: None,
+            text: String::new(),
+            actions: Vec::new(),
+            snippets: Vec::new(),
+            llm_tokens: None,
+        }
+    }
+
+    /// Contexte pour une transcription déjà effectuée (fichiers découpés en fenêtres)
+    pub fn from_result(settings: AppSettings, result: TranscriptionResult) -> Self {
+        let text = result.text.clone();
+        Self {
+            settings,
+            audio: Vec::new(),
+            sample_rate: 0,
+            result: Some(result),
+            text,
+            actions: Vec::new(),
+            snippets: Vec::new(),
+            llm_tokens: None,
+        }
+    }
+}
+
+/// Étape de traitement ; une erreur interrompt le pipeline
+pub trait Stage: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn process(&self, ctx: &mut PipelineContext) -> Result<(), String>;
+}
+
+/// Sortie du pipeline ; une erreur est journalisée sans bloquer les autres sorties
+pub trait Sink: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn deliver(&self, result: &TranscriptionResult, ctx: &PipelineContext) -> Result<(), String>;
+}
+
+#[derive(Default)]
+pub struct Pipeline {
+    stages: Vec<Box<dyn Stage>>,
+    sinks: Vec<Box<dyn Sink>>,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stage(mut self, stage: impl Stage + 'static) -> Self {
+        self.stages.push(Box::new(stage));
+        self
+    }
+
+    pub fn sink(mut self, sink: impl Sink + 'static) -> Self {
+        self.sinks.push(Box::new(sink));
+        self
+    }
+
+    /// Audio brut → transcription (resampling, VAD, moteur)
+    pub fn transcription(engine: SharedEngine) -> Self {
+        Self::new()
+            .stage(ResampleStage)
+            .stage(VadStage)
+            .stage(TranscribeStage::new(engine))
+    }
+
+    /// Étapes de post-traitement du texte communes à toutes les entrées
+    pub fn post_processing(self) -> Self {
+        self.stage(DictionaryStage)
+            .stage(VoiceCommandsStage)
+            .stage(SnippetsStage)
+            .stage(LlmStage)
+    }
+
+    /// Post-traitement d'une transcription de fichier : seul le dictionnaire
+    /// s'applique, au texte comme aux segments, pour que les exports restent
+    /// fidèles au texte. Le LLM tronquerait ou réécrirait un long
+    /// enregistrement, et les commandes vocales modifieraient des propos
+    /// qui ne leur sont pas adressés.
+    pub fn file_post_processing(self) -> Self {
+        self.stage(DictionaryStage)
+    }
+
+    /// Pipeline complet d'une dictée (push-to-talk ou interface)
+    pub fn dictation(engine: SharedEngine) -> Self {
+        Self::transcription(engine).post_processing()
+    }
+
+    /// Dictée transcrite en streaming : la session reçoit la fin de
+    /// l'enregistrement et fournit la transcription
+    pub fn streamed_dictation(session: LiveSession) -> Self {
+        Self::new()
+            .stage(FinishSessionStage::new(session))
+            .post_processing()
+    }
+
+    /// Dictée traduite vers la langue cible des réglages. Le post-traitement
+    /// LLM est omis : ses prompts corrigent le texte dans la langue dictée.
+    pub fn translated_dictation(engine: SharedEngine) -> Self {
+        Self::new()
+            .stage(ResampleStage)
+            .stage(VadStage)
+            .stage(TranslateSpeechStage::new(engine))
+            .stage(DictionaryStage)
+            .stage(VoiceCommandsStage)
+            .stage(SnippetsStage)
+    }
+
+    /// Exécute les étapes puis les sorties ; retourne le résultat final
+    pub fn run(&self, mut ctx: PipelineContext) -> Result<TranscriptionResult, String> {
+        for stage in &self.stages {
+            stage.process(&mut ctx).map_err(|e| {
+                log::warn!("Pipeline stage '{}' stopped: {}", stage.name(), e);
+                e
+            })?;
+        }
+
+        let mut result = ctx.result.take().ok_or("No transcription produced")?;
+        result.text = ctx.text.trim().to_string();
+
+        for sink in &self.sinks {
+            if let Err(e) = sink.deliver(&result, &ctx) {
+                log::warn!("Pipeline sink '{}' failed: {}", sink.name(), e);
+            }
+        }
+
+        Ok(result)
+    }
+}
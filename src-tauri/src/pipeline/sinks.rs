@@ -0,0 +1,315 @@
+//! Sorties du pipeline de dictée
+
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter};
+
+use super::{PipelineContext, Sink};
+use crate::audio::uncommitted_suffix;
+use crate::platform::{paste_text, type_text_incremental};
+use crate::storage::{history, stats};
+use crate::types::TranscriptionResult;
+use crate::voice_commands;
+
+/// Émet le texte final sur `transcription-chunk` (fenêtre principale, sous-titres)
+pub struct EventSink {
+    app: AppHandle,
+}
+
+impl EventSink {
+    pub fn new(app: AppHandle) -> Self {
+        Self { app }
+    }
+}
+
+#[derive(serde::Serialize, Clone)]
+struct FinalChunk {
+    text: String,
+    is_final: bool,
+    duration_seconds: f32,
+}
+
+impl Sink for EventSink {
+    fn name(&self) -> &'static str {
+        "event"
+    }
+
+    fn deliver(&self, result: &TranscriptionResult, _ctx: &PipelineContext) -> Result<(), String> {
+        self.app
+            .emit(
+                "transcription-chunk",
+                FinalChunk {
+                    text: result.text.clone(),
+                    is_final: true,
+                    duration_seconds: result.duration_seconds,
+                },
+            )
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Sépare une réponse interrompue du texte tapé à sa suite
+const INTERRUPTED_SEPARATOR: &str = " [...] ";
+
+/// Tape au curseur, mot par mot, le texte généré par le LLM.
+/// Si la génération échoue ensuite, `interrupt` tape le texte de repli à la
+/// suite de la réponse partielle.
+pub struct CursorTyper {
+    pending: String,
+    typed: String,
+    output: fn(&str),
+}
+
+impl Default for CursorTyper {
+    fn default() -> Self {
+        Self::with_output(type_text_incremental)
+    }
+}
+
+impl CursorTyper {
+    fn with_output(output: fn(&str)) -> Self {
+        Self {
+            pending: String::new(),
+            typed: String::new(),
+            output,
+        }
+    }
+
+    pub fn push(&mut self, token: &str) {
+        self.pending.push_str(token);
+        // Le dernier mot peut encore s'allonger : seuls les mots complets sont tapés
+        if let Some(end) = self.pending.rfind(char::is_whitespace) {
+            let words: String = self.pending.drain(..end).collect();
+            self.type_text(&words);
+        }
+    }
+
+    pub fn finish(&mut self) {
+        let rest = std::mem::take(&mut self.pending);
+        self.type_text(rest.trim_end());
+    }
+
+    /// Termine une réponse interrompue : `fallback` (la transcription brute)
+    /// est tapé après un séparateur, le dernier mot en attente est abandonné
+    pub fn interrupt(&mut self, fallback: &str) {
+        self.pending.clear();
+        let fallback = fallback.trim();
+        if !fallback.is_empty() {
+            self.type_text(&format!("{}{}", INTERRUPTED_SEPARATOR, fallback));
+        }
+    }
+
+    /// Texte déjà tapé au curseur
+    pub fn typed(&self) -> &str {
+        &self.typed
+    }
+
+    fn type_text(&mut self, text: &str) {
+        // Comme le texte collé en fin de génération, la réponse est tapée sans
+        // les espaces de tête
+        let text = if self.typed.is_empty() {
+            text.trim_start()
+        } else {
+            text
+        };
+        if !text.is_empty() {
+            (self.output)(text);
+            self.typed.push_str(text);
+        }
+    }
+}
+
+/// Colle le texte dans l'application active.
+/// Si le streaming a déjà tapé les mots validés, seule la suite est tapée ;
+/// si le LLM a tapé sa réponse au fil de la génération, seul le dernier mot
+/// reste à taper (ou la transcription brute, si la génération a échoué).
+pub struct PasteSink {
+    streamed_text: String,
+    typer: Option<Arc<Mutex<CursorTyper>>>,
+}
+
+impl PasteSink {
+    pub fn new(streamed_text: String) -> Self {
+        Self {
+            streamed_text,
+            typer: None,
+        }
+    }
+
+    /// Typer qui reçoit la réponse du LLM (`llm_stream_typing`)
+    pub fn with_typer(mut self, typer: Option<Arc<Mutex<CursorTyper>>>) -> Self {
+        self.typer = typer;
+        self
+    }
+}
+
+impl Sink for PasteSink {
+    fn name(&self) -> &'static str {
+        "paste"
+    }
+
+    fn deliver(&self, result: &TranscriptionResult, ctx: &PipelineContext) -> Result<(), String> {
+        let text = result.text.trim();
+        if text.is_empty() || !ctx.settings.auto_paste_enabled {
+            return Ok(());
+        }
+
+        if let Some(mut typer) = self.typer.as_ref().and_then(|typer| typer.lock().ok()) {
+            if !typer.typed().is_empty() {
+                if text.starts_with(typer.typed()) {
+                    typer.finish();
+                } else {
+                    // Le LLM a échoué en cours de génération et le texte final
+                    // est la transcription brute : elle est tapée à la suite
+                    // de la réponse partielle pour que la dictée ne soit pas perdue
+                    log::warn!("LLM output interrupted, typing the raw transcription after it");
+                    typer.interrupt(text);
+                }
+                return Ok(());
+            }
+        }
+
+        let streamed = self.streamed_text.trim();
+        if streamed.is_empty() {
+            paste_text(text);
+        } else {
+            let remaining = uncommitted_suffix(streamed, text);
+            if !remaining.is_empty() {
+                type_text_incremental(&format!(" {}", remaining));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Exécute les actions extraites des commandes vocales
+pub struct ActionsSink;
+
+impl Sink for ActionsSink {
+    fn name(&self) -> &'static str {
+        "actions"
+    }
+
+    fn deliver(&self, _result: &TranscriptionResult, ctx: &PipelineContext) -> Result<(), String> {
+        if !ctx.actions.is_empty() {
+            voice_commands::execute_actions(&ctx.actions, &ctx.snippets);
+        }
+        Ok(())
+    }
+}
+
+/// Enregistre la transcription dans l'historique
+pub struct HistorySink;
+
+impl Sink for HistorySink {
+    fn name(&self) -> &'static str {
+        "history"
+    }
+
+    fn deliver(&self, result: &TranscriptionResult, _ctx: &PipelineContext) -> Result<(), String> {
+        if result.text.is_empty() {
+            return Ok(());
+        }
+        history::add_transcription(result.clone())
+    }
+}
+
+/// Met à jour les statistiques d'utilisation
+pub struct StatsSink;
+
+impl Sink for StatsSink {
+    fn name(&self) -> &'static str {
+        "stats"
+    }
+
+    fn deliver(&self, result: &TranscriptionResult, ctx: &PipelineContext) -> Result<(), String> {
+        if !ctx.settings.stats_tracking_enabled || result.text.is_empty() {
+            return Ok(());
+        }
+        let word_count = result.text.split_whitespace().count() as u64;
+        stats::record_transcription(
+            word_count,
+            result.duration_seconds as f64,
+            result.detected_language.as_deref(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AppSettings;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static TYPED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Remplace la saisie au curseur : enregistre chaque appel
+    fn record(text: &str) {
+        TYPED.with(|typed| typed.borrow_mut().push(text.to_string()));
+    }
+
+    fn typed_calls() -> Vec<String> {
+        TYPED.with(|typed| typed.borrow().clone())
+    }
+
+    fn typer(tokens: &[&str]) -> Arc<Mutex<CursorTyper>> {
+        let mut typer = CursorTyper::with_output(record);
+        for token in tokens {
+            typer.push(token);
+        }
+        Arc::new(Mutex::new(typer))
+    }
+
+    fn deliver(typer: &Arc<Mutex<CursorTyper>>, text: &str) {
+        let result = TranscriptionResult {
+            text: text.to_string(),
+            confidence: 1.0,
+            duration_seconds: 1.0,
+            processing_time_ms: 0,
+            detected_language: None,
+            timestamp: 0,
+            model_used: None,
+            segments: Vec::new(),
+            low_confidence_spans: Vec::new(),
+        };
+        let ctx = PipelineContext::from_result(AppSettings::default(), result.clone());
+        PasteSink::new(String::new())
+            .with_typer(Some(typer.clone()))
+            .deliver(&result, &ctx)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_cursor_typer_types_complete_words() {
+        let typer = typer(&[" Bon", "jour", " le", " mon", "de"]);
+        // Le dernier mot peut encore s'allonger
+        assert_eq!(typed_calls(), vec!["Bonjour", " le"]);
+
+        typer.lock().unwrap().finish();
+        assert_eq!(typed_calls(), vec!["Bonjour", " le", " monde"]);
+        assert_eq!(typer.lock().unwrap().typed(), "Bonjour le monde");
+    }
+
+    #[test]
+    fn test_paste_sink_finishes_llm_reply() {
+        let typer = typer(&["Bonjour", " le", " monde"]);
+
+        deliver(&typer, "Bonjour le monde");
+
+        assert_eq!(typed_calls(), vec!["Bonjour", " le", " monde"]);
+    }
+
+    #[test]
+    fn test_paste_sink_types_transcription_after_interrupted_reply() {
+        let typer = typer(&["Cher", " Paul,", " je"]);
+
+        // Génération interrompue : le texte final est la transcription brute
+        deliver(&typer, "cher paul je serai en retard");
+
+        assert_eq!(
+            typed_calls(),
+            vec!["Cher", " Paul,", " [...] cher paul je serai en retard"]
+        );
+    }
+}
@@ -0,0 +1,404 @@
+//! Étapes du pipeline de dictée
+
+use std::sync::Mutex;
+
+use super::{PipelineContext, SharedEngine, Stage};
+use crate::audio::{resampling, vad};
+use crate::engines::segments::segment_from_words;
+use crate::engines::{LiveSession, Vocabulary};
+use crate::llm::{self, translator};
+use crate::storage::{dictionary, snippets};
+use crate::types::LlmMode;
+use crate::voice_commands::{self, Action};
+
+/// Taux d'échantillonnage requis par les moteurs
+pub const TARGET_SAMPLE_RATE: u32 = 16000;
+
+/// Convertit l'audio capturé en 16kHz
+pub struct ResampleStage;
+
+impl Stage for ResampleStage {
+    fn name(&self) -> &'static str {
+        "resample"
+    }
+
+    fn process(&self, ctx: &mut PipelineContext) -> Result<(), String> {
+        if ctx.sample_rate != TARGET_SAMPLE_RATE {
+            log::info!(
+                "Resampling audio from {}Hz to {}Hz",
+                ctx.sample_rate,
+                TARGET_SAMPLE_RATE
+            );
+            ctx.audio = resampling::resample_audio(&ctx.audio, ctx.sample_rate, TARGET_SAMPLE_RATE);
+            ctx.sample_rate = TARGET_SAMPLE_RATE;
+        }
+        Ok(())
+    }
+}
+
+/// Retire les silences de début et de fin (si activé dans les réglages)
+pub struct VadStage;
+
+impl Stage for VadStage {
+    fn name(&self) -> &'static str {
+        "vad"
+    }
+
+    fn process(&self, ctx: &mut PipelineContext) -> Result<(), String> {
+        let audio = std::mem::take(&mut ctx.audio);
+        ctx.audio =
+            vad::prepare_for_transcription(audio, ctx.sample_rate, ctx.settings.vad_enabled)
+                .ok_or("No speech detected")?;
+        Ok(())
+    }
+}
+
+/// Transcrit l'audio avec le moteur courant
+pub struct TranscribeStage {
+    engine: SharedEngine,
+}
+
+impl TranscribeStage {
+    pub fn new(engine: SharedEngine) -> Self {
+        Self { engine }
+    }
+}
+
+impl Stage for TranscribeStage {
+    fn name(&self) -> &'static str {
+        "transcribe"
+    }
+
+    fn process(&self, ctx: &mut PipelineContext) -> Result<(), String> {
+        let result = {
+            let engine_guard = self.engine.read().map_err(|e| e.to_string())?;
+            let engine = engine_guard
+                .as_ref()
+                .ok_or("Speech engine not initialized. Please download a model first.")?;
+            engine.transcribe(&ctx.audio, ctx.sample_rate)?
+        };
+
+        log::info!("Transcribed: '{}'", result.text);
+        ctx.text = result.text.clone();
+        ctx.result = Some(result);
+        Ok(())
+    }
+}
+
+/// Dictée traduite vers la langue cible des réglages : Whisper traduit
+/// directement vers l'anglais, sinon la transcription est traduite par le LLM
+pub struct TranslateSpeechStage {
+    engine: SharedEngine,
+}
+
+impl TranslateSpeechStage {
+    pub fn new(engine: SharedEngine) -> Self {
+        Self { engine }
+    }
+}
+
+impl Stage for TranslateSpeechStage {
+    fn name(&self) -> &'static str {
+        "translate_speech"
+    }
+
+    fn process(&self, ctx: &mut PipelineContext) -> Result<(), String> {
+        let target = ctx.settings.translation_target_language.clone();
+        let (result, translated) = {
+            let engine_guard = self.engine.read().map_err(|e| e.to_string())?;
+            let engine = engine_guard
+                .as_ref()
+                .ok_or("Speech engine not initialized. Please download a model first.")?;
+
+            let native = if target == "en" {
+                engine.translate_to_english(&ctx.audio, ctx.sample_rate)?
+            } else {
+                None
+            };
+            match native {
+                Some(result) => (result, true),
+                None => (engine.transcribe(&ctx.audio, ctx.sample_rate)?, false),
+            }
+        };
+
+        log::info!("Transcribed (translate to {}): '{}'", target, result.text);
+        ctx.text = result.text.clone();
+
+        let already_in_target = result.detected_language.as_deref() == Some(target.as_str());
+        if !translated && !already_in_target && !ctx.text.trim().is_empty() {
+            // Comme le post-traitement LLM, un échec laisse le texte transcrit
+            match translate_with_llm(&ctx.text, &target) {
+                Ok(text) => ctx.text = text,
+                Err(e) => log::warn!("{}, keeping original transcription", e),
+            }
+        }
+
+        ctx.result = Some(result);
+        Ok(())
+    }
+}
+
+/// Traduit le texte transcrit via le provider LLM
+fn translate_with_llm(text: &str, target: &str) -> Result<String, String> {
+    let provider = crate::commands::llm::get_llm_provider_internal()?;
+    tauri::async_runtime::block_on(translator::translate(text, target, provider.as_ref(), None))
+}
+
+/// Termine la session de streaming de l'enregistrement ; remplace le
+/// resampling, la VAD et la transcription
+pub struct FinishSessionStage {
+    session: Mutex<Option<LiveSession>>,
+}
+
+impl FinishSessionStage {
+    pub fn new(session: LiveSession) -> Self {
+        Self {
+            session: Mutex::new(Some(session)),
+        }
+    }
+}
+
+impl Stage for FinishSessionStage {
+    fn name(&self) -> &'static str {
+        "finish_session"
+    }
+
+    fn process(&self, ctx: &mut PipelineContext) -> Result<(), String> {
+        let session = self
+            .session
+            .lock()
+            .map_err(|e| e.to_string())?
+            .take()
+            .ok_or("Streaming session already finished")?;
+        let result = session.finish(&ctx.audio)?;
+
+        log::info!("Transcribed: '{}'", result.text);
+        ctx.text = result.text.clone();
+        ctx.result = Some(result);
+        Ok(())
+    }
+}
+
+/// Corrige l'orthographe des mots du dictionnaire personnalisé, dans le
+/// texte et dans les segments (dont sont construits les sous-titres exportés)
+pub struct DictionaryStage;
+
+impl Stage for DictionaryStage {
+    fn name(&self) -> &'static str {
+        "dictionary"
+    }
+
+    fn process(&self, ctx: &mut PipelineContext) -> Result<(), String> {
+        let words = dictionary::load_dictionary().words;
+        if words.is_empty() {
+            return Ok(());
+        }
+
+        let vocabulary = Vocabulary::new(&words);
+        ctx.text = vocabulary.correct(&ctx.text);
+        if let Some(result) = ctx.result.as_mut() {
+            for segment in &mut result.segments {
+                if segment.words.is_empty() {
+                    segment.text = vocabulary.correct(&segment.text);
+                } else {
+                    *segment = segment_from_words(
+                        vocabulary.correct_words(std::mem::take(&mut segment.words)),
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Ponctuation dictée et commandes vocales (les actions sont exécutées par `ActionsSink`)
+pub struct VoiceCommandsStage;
+
+impl Stage for VoiceCommandsStage {
+    fn name(&self) -> &'static str {
+        "voice_commands"
+    }
+
+    fn process(&self, ctx: &mut PipelineContext) -> Result<(), String> {
+        if !ctx.settings.voice_commands_enabled {
+            return Ok(());
+        }
+
+        ctx.snippets = snippets::load_snippets().snippets;
+        let parse_result = voice_commands::parse(
+            &ctx.text,
+            ctx.settings.dictation_mode,
+            &ctx.snippets,
+            ctx.settings.system_commands_enabled,
+        );
+        ctx.text = parse_result.text;
+
+        if !parse_result.actions.is_empty() {
+            log::info!("Voice commands detected: {:?}", parse_result.actions);
+            ctx.actions.extend(parse_result.actions);
+        }
+        Ok(())
+    }
+}
+
+/// Insère le contenu des snippets demandés directement dans le texte,
+/// pour qu'il soit collé avec la dictée plutôt que séparément
+pub struct SnippetsStage;
+
+impl Stage for SnippetsStage {
+    fn name(&self) -> &'static str {
+        "snippets"
+    }
+
+    fn process(&self, ctx: &mut PipelineContext) -> Result<(), String> {
+        let mut remaining = Vec::with_capacity(ctx.actions.len());
+
+        for action in std::mem::take(&mut ctx.actions) {
+            let snippet = match &action {
+                Action::InsertSnippet(trigger) => {
+                    ctx.snippets.iter().find(|s| s.trigger == *trigger)
+                }
+                _ => None,
+            };
+
+            match snippet {
+                Some(snippet) => {
+                    if !ctx.text.is_empty() && !ctx.text.ends_with(char::is_whitespace) {
+                        ctx.text.push(' ');
+                    }
+                    ctx.text.push_str(&snippet.content);
+                }
+                None => remaining.push(action),
+            }
+        }
+
+        ctx.actions = remaining;
+        Ok(())
+    }
+}
+
+/// Post-traitement LLM (correction, reformulation selon le mode de dictée)
+pub struct LlmStage;
+
+impl Stage for LlmStage {
+    fn name(&self) -> &'static str {
+        "llm"
+    }
+
+    fn process(&self, ctx: &mut PipelineContext) -> Result<(), String> {
+        if !ctx.settings.llm_enabled
+            || ctx.settings.llm_mode == LlmMode::Off
+            || ctx.text.trim().is_empty()
+        {
+            return Ok(());
+        }
+
+        let provider = match crate::commands::llm::get_llm_provider_internal() {
+            Ok(provider) => provider,
+            Err(e) => {
+                log::warn!("LLM enabled but unavailable: {}", e);
+                return Ok(());
+            }
+        };
+
+        let processed = tauri::async_runtime::block_on(llm::process(
+            &ctx.text,
+            ctx.settings.llm_mode,
+            ctx.settings.dictation_mode,
+            provider.as_ref(),
+            ctx.llm_tokens.clone(),
+        ))?;
+
+        log::info!("LLM processing successful");
+        ctx.text = processed;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::SpeechEngine;
+    use crate::types::{AppSettings, Snippet, TranscriptionResult};
+    use std::sync::{Arc, RwLock};
+
+    fn result(text: &str, language: Option<&str>) -> TranscriptionResult {
+        TranscriptionResult {
+            text: text.to_string(),
+            confidence: 1.0,
+            duration_seconds: 1.0,
+            processing_time_ms: 0,
+            detected_language: language.map(str::to_string),
+            timestamp: 0,
+            model_used: None,
+            segments: Vec::new(),
+            low_confidence_spans: Vec::new(),
+        }
+    }
+
+    fn context(text: &str) -> PipelineContext {
+        PipelineContext::from_result(AppSettings::default(), result(text, None))
+    }
+
+    /// Moteur factice qui entend du français et sait le traduire en anglais
+    struct FrenchEngine;
+
+    impl SpeechEngine for FrenchEngine {
+        fn transcribe(&self, _audio: &[f32], _rate: u32) -> Result<TranscriptionResult, String> {
+            Ok(result("bonjour", Some("fr")))
+        }
+
+        fn translate_to_english(
+            &self,
+            _audio: &[f32],
+            _rate: u32,
+        ) -> Result<Option<TranscriptionResult>, String> {
+            Ok(Some(result("hello", Some("fr"))))
+        }
+
+        fn name(&self) -> &str {
+            "French"
+        }
+
+        fn model_display_name(&self) -> String {
+            "French".to_string()
+        }
+    }
+
+    fn translate_speech(target: &str) -> PipelineContext {
+        let engine: SharedEngine = Arc::new(RwLock::new(Some(Box::new(FrenchEngine))));
+        let mut settings = AppSettings::default();
+        settings.translation_target_language = target.to_string();
+        let mut ctx = PipelineContext::from_audio(settings, vec![0.0; 16000], TARGET_SAMPLE_RATE);
+
+        TranslateSpeechStage::new(engine).process(&mut ctx).unwrap();
+        ctx
+    }
+
+    #[test]
+    fn test_translate_speech_uses_engine_translation_to_english() {
+        assert_eq!(translate_speech("en").text, "hello");
+    }
+
+    #[test]
+    fn test_translate_speech_skips_llm_when_already_in_target() {
+        assert_eq!(translate_speech("fr").text, "bonjour");
+    }
+
+    #[test]
+    fn test_snippets_are_inlined() {
+        let mut ctx = context("Merci pour votre retour.");
+        ctx.snippets = vec![Snippet {
+            id: "1".to_string(),
+            name: "Signature".to_string(),
+            trigger: "signature".to_string(),
+            content: "Cordialement, Cyprien".to_string(),
+        }];
+        ctx.actions = vec![Action::InsertSnippet("signature".to_string()), Action::Copy];
+
+        SnippetsStage.process(&mut ctx).unwrap();
+
+        assert_eq!(ctx.text, "Merci pour votre retour. Cordialement, Cyprien");
+        assert_eq!(ctx.actions, vec![Action::Copy]);
+    }
+}
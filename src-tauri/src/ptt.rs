@@ -1,26 +1,38 @@
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{mpsc, Mutex};
+use std::sync::{mpsc, Arc, Mutex};
 use tauri::{Emitter, Manager};
 use tauri_plugin_clipboard_manager::ClipboardExt;
 use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
 
-use crate::audio::AudioCapture;
+use crate::audio::{vad, AudioCapture, StreamingUpdate};
+use crate::commands::llm::llm_token_emitter;
+use crate::commands::transcription::emit_streaming_update;
+use crate::engines::LiveSession;
 use crate::hotkeys::parse_hotkey;
+use crate::llm::provider::TokenCallback;
+use crate::llm::translator;
+use crate::pipeline::{
+    ActionsSink, CursorTyper, EventSink, HistorySink, PasteSink, Pipeline, PipelineContext,
+    StatsSink,
+};
 use crate::platform::{copy_selected_text, paste_text, type_text_incremental};
 use crate::state::AppState;
 use crate::storage;
 use crate::tray::{set_tray_recording, set_tray_state, TrayState};
 
-/// Taux d'échantillonnage requis par le modèle
-const TARGET_SAMPLE_RATE: u32 = 16000;
-
 // Raccourcis globaux
 static PTT_SHORTCUT: Mutex<Option<Shortcut>> = Mutex::new(None);
 static TRANSLATE_SHORTCUT: Mutex<Option<Shortcut>> = Mutex::new(None);
+static TRANSLATE_DICTATION_SHORTCUT: Mutex<Option<Shortcut>> = Mutex::new(None);
 static VOICE_ACTION_SHORTCUT: Mutex<Option<Shortcut>> = Mutex::new(None);
+static TOGGLE_SHORTCUT: Mutex<Option<Shortcut>> = Mutex::new(None);
 
 // État global pour le push-to-talk
 static IS_PTT_ACTIVE: AtomicBool = AtomicBool::new(false);
+/// La dictée en cours a été lancée par le raccourci toggle (arrêt au second appui ou sur silence)
+static IS_TOGGLE_MODE: AtomicBool = AtomicBool::new(false);
+/// La dictée en cours a été lancée par le raccourci de dictée traduite
+static IS_TRANSLATED_DICTATION: AtomicBool = AtomicBool::new(false);
 static IS_VOICE_ACTION_ACTIVE: AtomicBool = AtomicBool::new(false);
 static SELECTED_TEXT_FOR_ACTION: Mutex<String> = Mutex::new(String::new());
 
@@ -30,6 +42,8 @@ static PTT_AUDIO_RECEIVER: Mutex<Option<mpsc::Receiver<PttResult>>> = Mutex::new
 
 // Pour le streaming temps réel
 static STREAMING_TEXT: Mutex<String> = Mutex::new(String::new());
+/// Session de streaming de la dictée en cours, terminée par `stop_ptt_and_paste`
+static PTT_SESSION: Mutex<Option<LiveSession>> = Mutex::new(None);
 
 #[derive(Debug)]
 enum PttCommand {
@@ -117,120 +131,194 @@ fn start_ptt_recording() {
     }
 }
 
-/// Streaming temps réel : transcrit et tape le texte pendant l'enregistrement
-fn start_streaming_transcription(app: &tauri::AppHandle) {
-    log::info!("[STREAMING] Starting streaming transcription");
+/// Demande une copie de l'audio en cours d'enregistrement
+fn request_audio_snapshot() -> Option<(Vec<f32>, u32)> {
+    // Le verrou de l'émetteur est gardé jusqu'à la réponse : un Stop ne peut pas
+    // s'intercaler, donc son AudioComplete ne peut pas être consommé ici
+    let sender_guard = PTT_AUDIO_SENDER.lock().ok()?;
+    if !IS_PTT_ACTIVE.load(Ordering::SeqCst) {
+        return None;
+    }
+    sender_guard.as_ref()?.send(PttCommand::GetSnapshot).ok()?;
+
+    let receiver_guard = PTT_AUDIO_RECEIVER.lock().ok()?;
+    match receiver_guard.as_ref()?.recv_timeout(std::time::Duration::from_millis(500)) {
+        Ok(PttResult::AudioSnapshot { audio, sample_rate }) => Some((audio, sample_rate)),
+        _ => None,
+    }
+}
 
+/// Suivi de l'enregistrement en cours : streaming temps réel (transcrit et tape
+/// le texte) et, en mode toggle, arrêt automatique après un silence
+fn monitor_recording(app: &tauri::AppHandle) {
     let settings = storage::config::load_settings();
-    if !settings.streaming_enabled {
+    let auto_stop_secs = if IS_TOGGLE_MODE.load(Ordering::SeqCst) {
+        settings.toggle_auto_stop_secs
+    } else {
+        0.0
+    };
+    // Le texte tapé au fil de l'eau serait dans la langue parlée
+    let streaming_enabled =
+        settings.streaming_enabled && !IS_TRANSLATED_DICTATION.load(Ordering::SeqCst);
+
+    if !streaming_enabled && auto_stop_secs <= 0.0 {
         log::info!("[STREAMING] Streaming disabled in settings");
         return;
     }
 
+    log::info!(
+        "[STREAMING] Monitoring recording (streaming: {}, auto-stop: {}s)",
+        streaming_enabled,
+        auto_stop_secs
+    );
+
     const STREAMING_INTERVAL_MS: u64 = 1000;
-    let mut last_text_len = 0;
+    const AUTO_STOP_INTERVAL_MS: u64 = 250;
+
+    let interval_ms = if auto_stop_secs > 0.0 {
+        AUTO_STOP_INTERVAL_MS
+    } else {
+        STREAMING_INTERVAL_MS
+    };
+
+    let mut last_streamed = std::time::Instant::now();
+    let mut auto_stop: Option<vad::SilenceAutoStop> = None;
+    let mut checked_samples = 0;
 
     while IS_PTT_ACTIVE.load(Ordering::SeqCst) {
-        std::thread::sleep(std::time::Duration::from_millis(STREAMING_INTERVAL_MS));
+        std::thread::sleep(std::time::Duration::from_millis(interval_ms));
 
         if !IS_PTT_ACTIVE.load(Ordering::SeqCst) {
             break;
         }
 
-        if let Ok(guard) = PTT_AUDIO_SENDER.lock() {
-            if let Some(ref sender) = *guard {
-                let _ = sender.send(PttCommand::GetSnapshot);
-            }
-        }
-
-        let snapshot = if let Ok(guard) = PTT_AUDIO_RECEIVER.lock() {
-            if let Some(ref receiver) = *guard {
-                match receiver.recv_timeout(std::time::Duration::from_millis(500)) {
-                    Ok(PttResult::AudioSnapshot { audio, sample_rate }) => Some((audio, sample_rate)),
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-
-        let (audio_data, sample_rate) = match snapshot {
+        let (audio_data, sample_rate) = match request_audio_snapshot() {
             Some(data) => data,
             None => continue,
         };
 
-        let duration = audio_data.len() as f32 / sample_rate as f32;
-        if duration < 1.0 {
-            continue;
+        if auto_stop_secs > 0.0 {
+            let detector = auto_stop.get_or_insert_with(|| vad::SilenceAutoStop::new(sample_rate, auto_stop_secs));
+            let silent = detector.push(&audio_data[checked_samples.min(audio_data.len())..]);
+            checked_samples = audio_data.len();
+
+            if silent {
+                log::info!("[TOGGLE] {}s of silence, stopping recording", auto_stop_secs);
+                if IS_PTT_ACTIVE.swap(false, Ordering::SeqCst) {
+                    finish_recording(app);
+                }
+                break;
+            }
         }
 
-        let resampled = if sample_rate != TARGET_SAMPLE_RATE {
-            crate::audio::resampling::resample_audio(&audio_data, sample_rate, TARGET_SAMPLE_RATE)
-        } else {
-            audio_data
-        };
+        if streaming_enabled && last_streamed.elapsed() >= std::time::Duration::from_millis(STREAMING_INTERVAL_MS) {
+            last_streamed = std::time::Instant::now();
+            stream_snapshot(app, &audio_data, sample_rate, settings.vad_enabled);
+        }
+    }
+
+    log::info!("[STREAMING] Streaming transcription ended");
+}
+
+/// Transmet l'audio enregistré jusqu'ici à la session de streaming et tape
+/// uniquement les mots validés, qui ne changeront plus
+fn stream_snapshot(app: &tauri::AppHandle, audio_data: &[f32], sample_rate: u32, vad_enabled: bool) {
+    let duration = audio_data.len() as f32 / sample_rate as f32;
+    if duration < 1.0 {
+        return;
+    }
 
+    let mut live_session = match PTT_SESSION.lock() {
+        Ok(guard) => guard,
+        Err(_) => return,
+    };
+
+    // La session a pu être reprise par stop_ptt_and_paste entre-temps
+    if !IS_PTT_ACTIVE.load(Ordering::SeqCst) {
+        return;
+    }
+
+    if live_session.is_none() {
         let state: tauri::State<'_, AppState> = app.state();
-        let engine_guard = match state.engine.read() {
-            Ok(guard) => guard,
-            Err(_) => continue,
-        };
-        let engine = match engine_guard.as_ref() {
-            Some(e) => e,
-            None => continue,
-        };
-        let result = match engine.transcribe(&resampled, TARGET_SAMPLE_RATE) {
-            Ok(r) => r,
+        match LiveSession::start(&state.engine, sample_rate, vad_enabled) {
+            Ok(session) => *live_session = Some(session),
             Err(e) => {
-                log::warn!("[STREAMING] Transcription error: {}", e);
-                continue;
+                log::warn!("[STREAMING] Streaming session unavailable: {}", e);
+                return;
             }
-        };
-
-        if result.text.is_empty() {
-            continue;
         }
+    }
 
-        log::info!("[STREAMING] Transcribed: '{}'", result.text);
+    let session = match live_session.as_mut() {
+        Some(session) => session,
+        None => return,
+    };
 
-        #[derive(serde::Serialize, Clone)]
-        struct StreamingChunk {
-            text: String,
-            is_final: bool,
-            duration_seconds: f32,
-        }
-        let chunk = StreamingChunk {
-            text: result.text.clone(),
-            is_final: false,
-            duration_seconds: duration,
-        };
-        let _ = app.emit("transcription-chunk", chunk);
+    match session.feed(audio_data).and_then(|_| session.poll()) {
+        Ok(Some(update)) => apply_streaming_update(app, &update, session.committed_text(), duration),
+        Ok(None) => {}
+        Err(e) => log::warn!("[STREAMING] Transcription error: {}", e),
+    }
+}
 
-        let current_text = result.text.trim();
-        if current_text.len() > last_text_len {
-            let new_text = &current_text[last_text_len..];
-            if !new_text.trim().is_empty() {
-                type_text_incremental(new_text);
-            }
-            last_text_len = current_text.len();
+/// Affiche la transcription en cours et tape les mots nouvellement validés
+fn apply_streaming_update(
+    app: &tauri::AppHandle,
+    update: &StreamingUpdate,
+    committed_text: String,
+    duration: f32,
+) {
+    emit_streaming_update(app, update, &committed_text, duration);
+
+    if !update.committed.is_empty() {
+        log::info!("[STREAMING] Committed: '{}'", update.committed);
+
+        let already_typed = committed_text.len() > update.committed.len();
+        if already_typed {
+            type_text_incremental(&format!(" {}", update.committed));
+        } else {
+            type_text_incremental(&update.committed);
+        }
 
-            if let Ok(mut streaming_text) = STREAMING_TEXT.lock() {
-                *streaming_text = current_text.to_string();
-            }
+        if let Ok(mut streaming_text) = STREAMING_TEXT.lock() {
+            *streaming_text = committed_text;
         }
     }
+}
 
-    log::info!("[STREAMING] Streaming transcription ended");
+/// Démarre une dictée (push-to-talk ou toggle) ; l'appelant a déjà activé `IS_PTT_ACTIVE`
+fn begin_recording(app: &tauri::AppHandle) {
+    if let Ok(mut text) = STREAMING_TEXT.lock() {
+        text.clear();
+    }
+    if let Ok(mut session) = PTT_SESSION.lock() {
+        *session = None;
+    }
+    set_tray_recording(true);
+    start_ptt_recording();
+    let _ = app.emit("recording-status", "recording");
+
+    let handle = app.clone();
+    std::thread::spawn(move || {
+        monitor_recording(&handle);
+    });
 }
 
-/// Arrête l'enregistrement et colle le texte transcrit
-fn stop_ptt_and_paste(app: &tauri::AppHandle) {
-    log::info!("[PTT] stop_ptt_and_paste() called");
+/// Termine une dictée : transcription, post-traitement et collage.
+/// L'appelant a déjà désactivé `IS_PTT_ACTIVE`.
+fn finish_recording(app: &tauri::AppHandle) {
+    IS_TOGGLE_MODE.store(false, Ordering::SeqCst);
+    let translated = IS_TRANSLATED_DICTATION.swap(false, Ordering::SeqCst);
+    set_tray_recording(false);
+    let _ = app.emit("recording-status", "processing");
+    stop_ptt_and_paste(app, translated);
+    let _ = app.emit("recording-status", "idle");
+}
 
-    let streaming_text = STREAMING_TEXT.lock().ok().map(|t| t.clone()).unwrap_or_default();
-    let had_streaming = !streaming_text.is_empty();
+/// Arrête l'enregistrement et colle le texte transcrit (ou traduit pour une
+/// dictée traduite)
+fn stop_ptt_and_paste(app: &tauri::AppHandle, translated: bool) {
+    log::info!("[PTT] stop_ptt_and_paste() called");
 
     if let Ok(guard) = PTT_AUDIO_SENDER.lock() {
         if let Some(ref sender) = *guard {
@@ -272,65 +360,42 @@ fn stop_ptt_and_paste(app: &tauri::AppHandle) {
         return;
     }
 
-    let resampled_audio = if sample_rate != TARGET_SAMPLE_RATE {
-        crate::audio::resampling::resample_audio(&audio_data, sample_rate, TARGET_SAMPLE_RATE)
-    } else {
-        audio_data
-    };
-
     let state: tauri::State<'_, AppState> = app.state();
-    let engine_guard = match state.engine.read() {
-        Ok(guard) => guard,
-        Err(e) => {
-            log::error!("Failed to lock engine: {}", e);
-            return;
-        }
-    };
-    let engine = match engine_guard.as_ref() {
-        Some(e) => e,
-        None => {
-            log::error!("Whisper engine not initialized");
-            return;
-        }
-    };
-    let result = match engine.transcribe(&resampled_audio, TARGET_SAMPLE_RATE) {
-        Ok(r) => r,
+    let settings = match state.settings.read() {
+        Ok(settings) => settings.clone(),
         Err(e) => {
-            log::error!("Transcription failed: {}", e);
+            log::error!("Failed to read settings: {}", e);
             return;
         }
     };
 
-    if result.text.is_empty() {
-        log::warn!("Transcription returned empty text");
-        return;
-    }
-
-    log::info!("Transcribed: '{}'", result.text);
-
-    #[derive(serde::Serialize, Clone)]
-    struct TranscriptionChunk {
-        text: String,
-        is_final: bool,
-        duration_seconds: f32,
-    }
-    let chunk = TranscriptionChunk {
-        text: result.text.clone(),
-        is_final: true,
-        duration_seconds: result.duration_seconds,
+    // La session de streaming a déjà décodé l'essentiel de l'enregistrement
+    // (le texte déjà tapé est lu après, une passe en cours ayant pu le compléter)
+    let live_session = PTT_SESSION.lock().ok().and_then(|mut session| session.take());
+    let streaming_text = STREAMING_TEXT.lock().ok().map(|t| t.clone()).unwrap_or_default();
+    let pipeline = match live_session {
+        // Pas de streaming pour une dictée traduite
+        _ if translated => Pipeline::translated_dictation(state.engine.clone()),
+        Some(session) => Pipeline::streamed_dictation(session),
+        None => Pipeline::dictation(state.engine.clone()),
     };
-    let _ = app.emit("transcription-chunk", chunk);
-
-    let _ = storage::history::add_transcription(result.clone());
-
-    let final_text = result.text.trim();
-    if had_streaming && final_text.len() > streaming_text.len() {
-        let remaining = &final_text[streaming_text.len()..];
-        if !remaining.trim().is_empty() {
-            type_text_incremental(remaining.trim());
-        }
-    } else if !had_streaming {
-        paste_text(&result.text);
+    // La réponse du LLM n'est tapée au fil de l'eau que si rien n'a encore
+    // été tapé par le streaming de la transcription
+    let typer = (settings.llm_stream_typing
+        && settings.auto_paste_enabled
+        && streaming_text.trim().is_empty())
+    .then(|| Arc::new(Mutex::new(CursorTyper::default())));
+    let pipeline = pipeline
+        .sink(EventSink::new(app.clone()))
+        .sink(PasteSink::new(streaming_text).with_typer(typer.clone()))
+        .sink(ActionsSink)
+        .sink(HistorySink)
+        .sink(StatsSink);
+
+    let mut context = PipelineContext::from_audio(settings, audio_data, sample_rate);
+    context.llm_tokens = Some(llm_token_output(app, "post_processing", typer));
+    if let Err(e) = pipeline.run(context) {
+        log::warn!("Dictation pipeline stopped: {}", e);
     }
 
     if let Ok(mut text) = STREAMING_TEXT.lock() {
@@ -367,35 +432,21 @@ fn translate_clipboard_and_paste(app: &tauri::AppHandle) {
 
     let settings = storage::config::load_settings();
     let target_language = settings.translation_target_language;
+    let typer = settings
+        .llm_stream_typing
+        .then(|| Arc::new(Mutex::new(CursorTyper::default())));
 
-    let api_key = match crate::commands::llm::get_groq_api_key_internal() {
-        Some(key) => key,
-        None => {
-            log::warn!("[TRANSLATE] No Groq API key configured");
+    let provider = match crate::commands::llm::get_llm_provider_internal() {
+        Ok(provider) => provider,
+        Err(e) => {
+            log::warn!("[TRANSLATE] {}", e);
             set_tray_state(TrayState::Idle);
-            let _ = app.emit("translation_error", "Clé API Groq non configurée");
+            let _ = app.emit("translation_error", e);
             let _ = app.emit("translation-status", "idle");
             return;
         }
     };
 
-    let language_name = match target_language.as_str() {
-        "fr" => "French", "en" => "English", "de" => "German",
-        "es" => "Spanish", "it" => "Italian", "pt" => "Portuguese",
-        "nl" => "Dutch", "ru" => "Russian", "zh" => "Chinese",
-        "ja" => "Japanese", "ko" => "Korean", "ar" => "Arabic",
-        _ => &target_language,
-    };
-
-    let system_prompt = format!(
-        "You are a professional translator. Translate the following text to {}. \
-         Only output the translation, nothing else. Preserve the original formatting, \
-         punctuation and tone. If the text is already in {}, return it unchanged.",
-        language_name, language_name
-    );
-
-    log::info!("[TRANSLATE] Calling Groq API for translation to {}...", language_name);
-
     let rt = match tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
@@ -409,19 +460,30 @@ fn translate_clipboard_and_paste(app: &tauri::AppHandle) {
         }
     };
 
-    let translated = rt.block_on(async {
-        crate::llm::groq_client::send_completion(&api_key, &system_prompt, &clipboard_text).await
-    });
+    let on_token = llm_token_output(app, "translation", typer.clone());
+    let translated = rt.block_on(translator::translate(
+        &clipboard_text,
+        &target_language,
+        provider.as_ref(),
+        Some(on_token),
+    ));
 
     match translated {
         Ok(text) => {
-            let trimmed = text.trim().to_string();
             log::info!("[TRANSLATE] Translation successful");
-            paste_text(&trimmed);
-            let _ = app.emit("translation_complete", &trimmed);
+            match &typer {
+                Some(typer) => {
+                    if let Ok(mut typer) = typer.lock() {
+                        typer.finish();
+                    }
+                }
+                None => paste_text(&text),
+            }
+            let _ = app.emit("translation_complete", &text);
         }
+        // Les mots déjà tapés par `typer` restent au curseur
         Err(e) => {
-            log::error!("[TRANSLATE] Translation failed: {}", e);
+            log::error!("[TRANSLATE] {}", e);
             let _ = app.emit("translation_error", format!("Erreur de traduction: {}", e));
         }
     }
@@ -516,32 +578,11 @@ fn stop_voice_action_and_execute(app: &tauri::AppHandle) {
         return;
     }
 
-    let resampled = if sample_rate != TARGET_SAMPLE_RATE {
-        crate::audio::resampling::resample_audio(&audio_data, sample_rate, TARGET_SAMPLE_RATE)
-    } else {
-        audio_data
-    };
-
     let state: tauri::State<'_, crate::state::AppState> = app.state();
-    let engine_guard = match state.engine.read() {
-        Ok(guard) => guard,
-        Err(_) => {
-            set_tray_state(TrayState::Idle);
-            let _ = app.emit("voice-action-status", "idle");
-            return;
-        }
-    };
-
-    let engine = match engine_guard.as_ref() {
-        Some(e) => e,
-        None => {
-            set_tray_state(TrayState::Idle);
-            let _ = app.emit("voice-action-status", "idle");
-            return;
-        }
-    };
+    let settings = state.settings.read().map(|s| s.clone()).unwrap_or_default();
+    let context = PipelineContext::from_audio(settings, audio_data, sample_rate);
 
-    let transcription = match engine.transcribe(&resampled, TARGET_SAMPLE_RATE) {
+    let transcription = match Pipeline::transcription(state.engine.clone()).run(context) {
         Ok(r) => r.text,
         Err(e) => {
             log::error!("[VOICE_ACTION] Transcription failed: {}", e);
@@ -551,8 +592,6 @@ fn stop_voice_action_and_execute(app: &tauri::AppHandle) {
         }
     };
 
-    drop(engine_guard);
-
     if transcription.is_empty() {
         set_tray_state(TrayState::Idle);
         let _ = app.emit("voice-action-status", "idle");
@@ -561,10 +600,10 @@ fn stop_voice_action_and_execute(app: &tauri::AppHandle) {
 
     log::info!("[VOICE_ACTION] Instruction: '{}'", transcription);
 
-    let api_key = match crate::commands::llm::get_groq_api_key_internal() {
-        Some(key) => key,
-        None => {
-            let _ = app.emit("voice-action-error", "Clé API Groq non configurée");
+    let provider = match crate::commands::llm::get_llm_provider_internal() {
+        Ok(provider) => provider,
+        Err(e) => {
+            let _ = app.emit("voice-action-error", e);
             set_tray_state(TrayState::Idle);
             let _ = app.emit("voice-action-status", "idle");
             return;
@@ -595,19 +634,29 @@ Retourne UNIQUEMENT le résultat, sans explication ni commentaire."#;
         }
     };
 
-    let result = rt.block_on(async {
-        crate::llm::groq_client::send_completion(&api_key, system_prompt, &user_prompt).await
-    });
+    let typer = storage::config::load_settings()
+        .llm_stream_typing
+        .then(|| Arc::new(Mutex::new(CursorTyper::default())));
+    let on_token = llm_token_output(app, "voice_action", typer.clone());
+    let result = rt.block_on(provider.complete_streaming(system_prompt, &user_prompt, on_token));
 
     match result {
         Ok(response) => {
             let trimmed = response.trim().to_string();
             log::info!("[VOICE_ACTION] Success");
-            paste_text(&trimmed);
+            match &typer {
+                Some(typer) => {
+                    if let Ok(mut typer) = typer.lock() {
+                        typer.finish();
+                    }
+                }
+                None => paste_text(&trimmed),
+            }
             let _ = app.emit("voice-action-complete", &trimmed);
         }
+        // Les mots déjà tapés par `typer` restent au curseur
         Err(e) => {
-            log::error!("[VOICE_ACTION] Groq error: {}", e);
+            log::error!("[VOICE_ACTION] LLM error: {}", e);
             let _ = app.emit("voice-action-error", format!("Erreur: {}", e));
         }
     }
@@ -616,6 +665,22 @@ Retourne UNIQUEMENT le résultat, sans explication ni commentaire."#;
     let _ = app.emit("voice-action-status", "idle");
 }
 
+/// Émet chaque fragment généré en `llm-token` et le tape au curseur si `typer`
+/// est fourni
+fn llm_token_output(
+    app: &tauri::AppHandle,
+    source: &'static str,
+    typer: Option<Arc<Mutex<CursorTyper>>>,
+) -> TokenCallback {
+    let emit = llm_token_emitter(app.clone(), source);
+    Arc::new(move |token: &str| {
+        emit(token);
+        if let Some(mut typer) = typer.as_ref().and_then(|typer| typer.lock().ok()) {
+            typer.push(token);
+        }
+    })
+}
+
 /// Configure le global shortcut handler et enregistre les raccourcis
 pub fn setup_shortcuts(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>> {
     let settings = storage::config::load_settings();
@@ -645,6 +710,28 @@ pub fn setup_shortcuts(app: &mut tauri::App) -> Result<(), Box<dyn std::error::E
                 Err(e) => log::error!("[TRANSLATE] ERROR registering shortcut: {:?}", e),
             }
         }
+
+        // Dictée traduite : maintenir, parler dans n'importe quelle langue
+        let dictation_hotkey = settings.hotkey_translate_dictation.clone();
+        if let Some(dictation_shortcut) = parse_hotkey(&dictation_hotkey) {
+            set_translate_dictation_shortcut(Some(dictation_shortcut));
+            match app.global_shortcut().register(dictation_shortcut) {
+                Ok(_) => log::info!("[TRANSLATE] Dictation shortcut '{}' registered!", dictation_hotkey),
+                Err(e) => log::error!("[TRANSLATE] ERROR registering dictation shortcut: {:?}", e),
+            }
+        }
+    }
+
+    // Raccourci toggle : un appui démarre, un second appui (ou un silence) arrête
+    let toggle_hotkey = settings.hotkey_toggle_record.clone();
+    if let Some(toggle_shortcut) = parse_hotkey(&toggle_hotkey) {
+        if let Ok(mut guard) = TOGGLE_SHORTCUT.lock() {
+            *guard = Some(toggle_shortcut);
+        }
+        match app.global_shortcut().register(toggle_shortcut) {
+            Ok(_) => log::info!("[TOGGLE] Shortcut '{}' registered!", toggle_hotkey),
+            Err(e) => log::error!("[TOGGLE] ERROR registering shortcut: {:?}", e),
+        }
     }
 
     // Raccourci Voice Action
@@ -662,6 +749,20 @@ pub fn setup_shortcuts(app: &mut tauri::App) -> Result<(), Box<dyn std::error::E
     Ok(())
 }
 
+/// Remplace le raccourci toggle reconnu par `handle_shortcut`
+pub fn set_toggle_shortcut(shortcut: Option<Shortcut>) {
+    if let Ok(mut guard) = TOGGLE_SHORTCUT.lock() {
+        *guard = shortcut;
+    }
+}
+
+/// Remplace le raccourci de dictée traduite reconnu par `handle_shortcut`
+pub fn set_translate_dictation_shortcut(shortcut: Option<Shortcut>) {
+    if let Ok(mut guard) = TRANSLATE_DICTATION_SHORTCUT.lock() {
+        *guard = shortcut;
+    }
+}
+
 /// Handler pour les événements de raccourcis globaux
 pub fn handle_shortcut(app: &tauri::AppHandle, shortcut: &Shortcut, event: &tauri_plugin_global_shortcut::ShortcutEvent) {
     let is_ptt = PTT_SHORTCUT.lock().ok()
@@ -673,32 +774,58 @@ pub fn handle_shortcut(app: &tauri::AppHandle, shortcut: &Shortcut, event: &taur
     let is_voice_action = VOICE_ACTION_SHORTCUT.lock().ok()
         .and_then(|guard| guard.as_ref().map(|s| *s == *shortcut))
         .unwrap_or(false);
+    let is_toggle = TOGGLE_SHORTCUT.lock().ok()
+        .and_then(|guard| guard.as_ref().map(|s| *s == *shortcut))
+        .unwrap_or(false);
+    let is_translate_dictation = TRANSLATE_DICTATION_SHORTCUT.lock().ok()
+        .and_then(|guard| guard.as_ref().map(|s| *s == *shortcut))
+        .unwrap_or(false);
 
     if is_ptt {
         match event.state() {
             ShortcutState::Pressed => {
                 if !IS_PTT_ACTIVE.swap(true, Ordering::SeqCst) {
-                    if let Ok(mut text) = STREAMING_TEXT.lock() {
-                        text.clear();
-                    }
-                    set_tray_recording(true);
-                    start_ptt_recording();
-                    let _ = app.emit("recording-status", "recording");
-
+                    begin_recording(app);
+                }
+            }
+            ShortcutState::Released => {
+                // Une dictée en mode toggle (ou traduite) ne s'arrête pas au relâchement du push-to-talk
+                if !IS_TOGGLE_MODE.load(Ordering::SeqCst)
+                    && !IS_TRANSLATED_DICTATION.load(Ordering::SeqCst)
+                    && IS_PTT_ACTIVE.swap(false, Ordering::SeqCst)
+                {
                     let handle = app.clone();
                     std::thread::spawn(move || {
-                        start_streaming_transcription(&handle);
+                        finish_recording(&handle);
                     });
                 }
             }
+        }
+    } else if is_toggle {
+        if let ShortcutState::Pressed = event.state() {
+            if !IS_PTT_ACTIVE.swap(true, Ordering::SeqCst) {
+                IS_TOGGLE_MODE.store(true, Ordering::SeqCst);
+                begin_recording(app);
+            } else if IS_TOGGLE_MODE.load(Ordering::SeqCst) && IS_PTT_ACTIVE.swap(false, Ordering::SeqCst) {
+                let handle = app.clone();
+                std::thread::spawn(move || {
+                    finish_recording(&handle);
+                });
+            }
+        }
+    } else if is_translate_dictation {
+        match event.state() {
+            ShortcutState::Pressed => {
+                if !IS_PTT_ACTIVE.swap(true, Ordering::SeqCst) {
+                    IS_TRANSLATED_DICTATION.store(true, Ordering::SeqCst);
+                    begin_recording(app);
+                }
+            }
             ShortcutState::Released => {
-                if IS_PTT_ACTIVE.swap(false, Ordering::SeqCst) {
-                    set_tray_recording(false);
-                    let _ = app.emit("recording-status", "processing");
+                if IS_TRANSLATED_DICTATION.load(Ordering::SeqCst) && IS_PTT_ACTIVE.swap(false, Ordering::SeqCst) {
                     let handle = app.clone();
                     std::thread::spawn(move || {
-                        stop_ptt_and_paste(&handle);
-                        let _ = handle.emit("recording-status", "idle");
+                        finish_recording(&handle);
                     });
                 }
             }
@@ -3,8 +3,8 @@ use std::sync::{Arc, RwLock};
 use tauri::{AppHandle, Manager};
 
 use crate::engines::{ModelManager, ParakeetCoreMLEngine, ParakeetEngine, SpeechEngine, VoskEngine, WhisperEngine};
-use crate::storage::config;
-use crate::types::{AppSettings, EngineType, ModelSize, ParakeetModelSize, VoskLanguage};
+use crate::storage::{config, dictionary};
+use crate::types::{AppSettings, CustomModel, EngineType, ModelSize, ParakeetModelSize, VoskLanguage};
 
 pub struct AppState {
     pub is_recording: Arc<RwLock<bool>>,
@@ -75,14 +75,11 @@ impl AppState {
         // Helper to load Whisper engine
         let load_whisper = |model_manager: &ModelManager, settings: &AppSettings| -> Option<Box<dyn SpeechEngine>> {
             if let Some(model_path) = model_manager.get_model_path(settings.whisper_model) {
-                let lang = if settings.auto_detect_language {
-                    None
-                } else {
-                    Some(settings.transcription_language.clone())
-                };
+                let lang = Self::language_setting(settings);
 
                 match WhisperEngine::new(&model_path, lang, settings.whisper_model) {
-                    Ok(engine) => {
+                    Ok(mut engine) => {
+                        engine.set_decode_options(settings.whisper_decode.clone());
                         log::info!("Whisper engine initialized with model {:?}", settings.whisper_model);
                         Some(Box::new(engine))
                     }
@@ -97,8 +94,27 @@ impl AppState {
             }
         };
 
+        // Un modèle importé remplace le modèle intégré de son moteur ; en cas
+        // d'échec de chargement, le modèle intégré prend le relais
+        let custom_engine = settings
+            .custom_model
+            .as_deref()
+            .and_then(|id| model_manager.custom_model(id))
+            .filter(|model| model.engine == settings.engine_type)
+            .and_then(|model| match Self::custom_engine(&model, &settings, &model_manager) {
+                Ok(engine) => {
+                    log::info!("Imported model '{}' initialized", model.name);
+                    Some(engine)
+                }
+                Err(e) => {
+                    log::error!("Failed to load imported model '{}': {}", model.name, e);
+                    None
+                }
+            });
+
         // Load engine based on configured type
         let engine: Option<Box<dyn SpeechEngine>> = match settings.engine_type {
+            _ if custom_engine.is_some() => custom_engine,
             EngineType::Whisper => load_whisper(&model_manager, &settings),
             EngineType::Parakeet => {
                 // On macOS, use CoreML-based Parakeet engine
@@ -127,7 +143,9 @@ impl AppState {
                 {
                     if let Some(model_path) = model_manager.get_parakeet_model_path(settings.parakeet_model) {
                         match ParakeetEngine::new(&model_path, settings.parakeet_model.into()) {
-                            Ok(engine) => {
+                            Ok(mut engine) => {
+                                engine.set_beam_width(settings.parakeet_beam_width as usize);
+                                engine.set_language_identifier(Self::load_language_identifier(&model_manager));
                                 log::info!("Parakeet engine initialized with model {:?}", settings.parakeet_model);
                                 Some(Box::new(engine))
                             }
@@ -170,6 +188,16 @@ impl AppState {
             }
         };
 
+        // Biais de reconnaissance : dictionnaire personnalisé
+        let language = Self::language_setting(&settings);
+        let engine = engine.map(|mut engine| {
+            engine.set_vocabulary(&dictionary::load_dictionary().words);
+            if let Err(e) = engine.set_language(language) {
+                log::warn!("Language not applied to {} engine: {}", engine.name(), e);
+            }
+            engine
+        });
+
         Ok(Self {
             is_recording: Arc::new(RwLock::new(false)),
             settings: Arc::new(RwLock::new(settings)),
@@ -181,6 +209,101 @@ impl AppState {
         })
     }
 
+    /// Langue imposée au moteur, `None` en détection automatique
+    pub fn language_setting(settings: &AppSettings) -> Option<String> {
+        if settings.auto_detect_language {
+            None
+        } else {
+            Some(settings.transcription_language.clone())
+        }
+    }
+
+    /// Front-end d'identification de langue : le plus petit modèle Whisper
+    /// multilingue installé
+    #[cfg(not(target_os = "macos"))]
+    fn load_language_identifier(
+        model_manager: &ModelManager,
+    ) -> Option<Arc<crate::engines::LanguageIdentifier>> {
+        let size = model_manager
+            .installed_models()
+            .into_iter()
+            .filter(ModelSize::multilingual)
+            .min_by_key(ModelSize::size_bytes)?;
+        let model_path = model_manager.get_model_path(size)?;
+        match crate::engines::LanguageIdentifier::new(&model_path) {
+            Ok(identifier) => Some(Arc::new(identifier)),
+            Err(e) => {
+                log::warn!("Language identification unavailable: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Moteur d'un modèle importé, configuré comme les modèles intégrés
+    /// (sans le dictionnaire ni la langue, appliqués par l'appelant)
+    fn custom_engine(
+        model: &CustomModel,
+        settings: &AppSettings,
+        _model_manager: &ModelManager,
+    ) -> Result<Box<dyn SpeechEngine>, String> {
+        match model.engine {
+            EngineType::Whisper => {
+                let language = Self::language_setting(settings);
+                let mut engine = WhisperEngine::custom(&model.path, language, &model.name)?;
+                engine.set_decode_options(settings.whisper_decode.clone());
+                Ok(Box::new(engine))
+            }
+            EngineType::Parakeet => {
+                // Le moteur CoreML de macOS ne charge pas les exports ONNX
+                #[cfg(target_os = "macos")]
+                {
+                    Err("Imported Parakeet ONNX models are not supported on macOS".to_string())
+                }
+                #[cfg(not(target_os = "macos"))]
+                {
+                    let mut engine = ParakeetEngine::new(&model.path, Default::default())?;
+                    engine.set_display_name(model.name.clone());
+                    engine.set_beam_width(settings.parakeet_beam_width as usize);
+                    engine.set_language_identifier(Self::load_language_identifier(_model_manager));
+                    Ok(Box::new(engine))
+                }
+            }
+            EngineType::Vosk => {
+                let language = model.language.ok_or("Imported Vosk model has no language")?;
+                let mut engine = VoskEngine::new(&model.path, language)?;
+                engine.set_display_name(model.name.clone());
+                Ok(Box::new(engine))
+            }
+        }
+    }
+
+    /// Modèle importé sélectionné pour `engine_type`, s'il y en a un
+    fn selected_custom_model(&self, engine_type: EngineType) -> Result<Option<CustomModel>, String> {
+        let settings = self.settings.read().map_err(|e| e.to_string())?;
+        Ok(settings
+            .custom_model
+            .as_deref()
+            .and_then(|id| self.model_manager.custom_model(id))
+            .filter(|model| model.engine == engine_type))
+    }
+
+    /// Remplace le moteur courant par celui d'un modèle importé
+    pub fn load_custom_model(&self, model: &CustomModel) -> Result<(), String> {
+        let settings = self.settings.read().map_err(|e| e.to_string())?.clone();
+
+        let mut new_engine = Self::custom_engine(model, &settings, &self.model_manager)?;
+        new_engine.set_vocabulary(&dictionary::load_dictionary().words);
+        if let Err(e) = new_engine.set_language(Self::language_setting(&settings)) {
+            log::warn!("Language not applied to {} engine: {}", new_engine.name(), e);
+        }
+
+        let mut engine = self.engine.write().map_err(|e| e.to_string())?;
+        *engine = Some(new_engine);
+
+        log::info!("Engine reloaded with imported model '{}'", model.name);
+        Ok(())
+    }
+
     /// Find the Parakeet CoreML sidecar binary
     #[cfg(target_os = "macos")]
     fn find_parakeet_sidecar(resource_path: &PathBuf) -> Option<PathBuf> {
@@ -231,11 +354,25 @@ impl AppState {
 
     /// Recharge le moteur Whisper avec un nouveau modèle
     pub fn reload_engine(&self, model_size: ModelSize, language: Option<String>) -> Result<(), String> {
+        if let Some(model) = self.selected_custom_model(EngineType::Whisper)? {
+            return self.load_custom_model(&model);
+        }
+
+        self.load_whisper_model(model_size, language)
+    }
+
+    /// Charge le modèle Whisper intégré `model_size`, même si un modèle importé
+    /// est sélectionné
+    pub fn load_whisper_model(&self, model_size: ModelSize, language: Option<String>) -> Result<(), String> {
         let model_path = self.model_manager
             .get_model_path(model_size)
             .ok_or_else(|| format!("Model {:?} not available", model_size))?;
 
-        let new_engine = WhisperEngine::new(&model_path, language, model_size)?;
+        let decode_options = self.settings.read().map_err(|e| e.to_string())?.whisper_decode.clone();
+
+        let mut new_engine = WhisperEngine::new(&model_path, language, model_size)?;
+        new_engine.set_decode_options(decode_options);
+        new_engine.set_vocabulary(&dictionary::load_dictionary().words);
 
         let mut engine = self.engine.write().map_err(|e| e.to_string())?;
         *engine = Some(Box::new(new_engine));
@@ -245,14 +382,24 @@ impl AppState {
     }
 
     /// Recharge le moteur Parakeet avec un nouveau modèle
-    pub fn reload_parakeet_engine(&self, _model_size: ParakeetModelSize) -> Result<(), String> {
+    pub fn reload_parakeet_engine(&self, model_size: ParakeetModelSize) -> Result<(), String> {
+        if let Some(model) = self.selected_custom_model(EngineType::Parakeet)? {
+            return self.load_custom_model(&model);
+        }
+
+        self.load_parakeet_model(model_size)
+    }
+
+    /// Charge le modèle Parakeet intégré, même si un modèle importé est sélectionné
+    pub fn load_parakeet_model(&self, _model_size: ParakeetModelSize) -> Result<(), String> {
         // On macOS, use CoreML-based Parakeet engine
         #[cfg(target_os = "macos")]
         {
             let sidecar_path = Self::find_parakeet_sidecar(&self.resource_path)
                 .ok_or_else(|| "Parakeet CoreML sidecar not found".to_string())?;
 
-            let new_engine = ParakeetCoreMLEngine::new(sidecar_path)?;
+            let mut new_engine = ParakeetCoreMLEngine::new(sidecar_path)?;
+            new_engine.set_vocabulary(&dictionary::load_dictionary().words);
 
             let mut engine = self.engine.write().map_err(|e| e.to_string())?;
             *engine = Some(Box::new(new_engine));
@@ -267,7 +414,18 @@ impl AppState {
                 .get_parakeet_model_path(_model_size)
                 .ok_or_else(|| format!("Parakeet model {:?} not available", _model_size))?;
 
-            let new_engine = ParakeetEngine::new(&model_path, _model_size.into())?;
+            let (beam_width, language) = {
+                let settings = self.settings.read().map_err(|e| e.to_string())?;
+                (settings.parakeet_beam_width, Self::language_setting(&settings))
+            };
+
+            let mut new_engine = ParakeetEngine::new(&model_path, _model_size.into())?;
+            new_engine.set_beam_width(beam_width as usize);
+            new_engine.set_language_identifier(Self::load_language_identifier(&self.model_manager));
+            new_engine.set_vocabulary(&dictionary::load_dictionary().words);
+            if let Err(e) = new_engine.set_language(language) {
+                log::warn!("Language not applied to Parakeet engine: {}", e);
+            }
 
             let mut engine = self.engine.write().map_err(|e| e.to_string())?;
             *engine = Some(Box::new(new_engine));
@@ -279,11 +437,22 @@ impl AppState {
 
     /// Recharge le moteur Vosk avec une nouvelle langue
     pub fn reload_vosk_engine(&self, language: VoskLanguage) -> Result<(), String> {
+        if let Some(model) = self.selected_custom_model(EngineType::Vosk)? {
+            return self.load_custom_model(&model);
+        }
+
+        self.load_vosk_model(language)
+    }
+
+    /// Charge le modèle Vosk intégré de `language`, même si un modèle importé
+    /// est sélectionné
+    pub fn load_vosk_model(&self, language: VoskLanguage) -> Result<(), String> {
         let model_path = self.model_manager
             .get_vosk_model_path(language)
             .ok_or_else(|| format!("Vosk model for {:?} not available", language))?;
 
-        let new_engine = VoskEngine::new(&model_path, language)?;
+        let mut new_engine = VoskEngine::new(&model_path, language)?;
+        new_engine.set_vocabulary(&dictionary::load_dictionary().words);
 
         let mut engine = self.engine.write().map_err(|e| e.to_string())?;
         *engine = Some(Box::new(new_engine));
@@ -292,17 +461,61 @@ impl AppState {
         Ok(())
     }
 
+    /// Transmet le dictionnaire personnalisé au moteur courant
+    pub fn refresh_vocabulary(&self) -> Result<(), String> {
+        let words = dictionary::load_dictionary().words;
+        let mut engine = self.engine.write().map_err(|e| e.to_string())?;
+        if let Some(ref mut engine) = *engine {
+            engine.set_vocabulary(&words);
+            log::info!("Vocabulary updated on {} engine ({} words)", engine.name(), words.len());
+        }
+        Ok(())
+    }
+
+    /// Applique la langue des réglages au moteur courant sans le recharger,
+    /// sauf pour Vosk dont chaque modèle ne couvre qu'une langue
+    pub fn apply_language(&self) -> Result<(), String> {
+        let settings = self.settings.read().map_err(|e| e.to_string())?.clone();
+
+        let result = {
+            let mut engine = self.engine.write().map_err(|e| e.to_string())?;
+            match engine.as_mut() {
+                Some(engine) => engine.set_language(Self::language_setting(&settings)),
+                None => return Ok(()),
+            }
+        };
+
+        match result {
+            Err(e) if settings.engine_type == EngineType::Vosk && settings.vosk_language.is_none() => {
+                let language = VoskLanguage::from_language_code(&settings.transcription_language)
+                    .ok_or(e)?;
+                self.reload_vosk_engine(language)
+            }
+            result => result,
+        }
+    }
+
+    /// Applique les options de décodage Whisper des réglages au moteur chargé
+    pub fn apply_whisper_decode_options(&self) -> Result<(), String> {
+        let options = self
+            .settings
+            .read()
+            .map_err(|e| e.to_string())?
+            .whisper_decode
+            .clone();
+        if let Some(engine) = self.engine.write().map_err(|e| e.to_string())?.as_mut() {
+            engine.set_decode_options(options);
+        }
+        Ok(())
+    }
+
     /// Change le type de moteur (Whisper, Parakeet ou Vosk)
     pub fn switch_engine_type(&self, engine_type: EngineType) -> Result<(), String> {
         let settings = self.settings.read().map_err(|e| e.to_string())?;
 
         match engine_type {
             EngineType::Whisper => {
-                let language = if settings.auto_detect_language {
-                    None
-                } else {
-                    Some(settings.transcription_language.clone())
-                };
+                let language = Self::language_setting(&settings);
                 let model_size = settings.whisper_model;
                 drop(settings);
                 self.reload_engine(model_size, language)
@@ -193,7 +193,7 @@ fn handle_tray_menu_event(app: &tauri::AppHandle, menu_id: &str) {
     }
 }
 
-/// Met à jour la langue de transcription
+/// Met à jour la langue de transcription et l'applique au moteur courant
 fn update_language(app: &tauri::AppHandle, lang: &str) {
     let state: tauri::State<'_, AppState> = app.state();
     if let Ok(mut settings) = state.settings.write() {
@@ -202,6 +202,10 @@ fn update_language(app: &tauri::AppHandle, lang: &str) {
         let _ = storage::config::save_settings(&settings);
         log::info!("Language updated to: {}", lang);
     };
+
+    if let Err(e) = state.apply_language() {
+        log::warn!("Failed to apply language {}: {}", lang, e);
+    }
 }
 
 /// Construit le tray icon complet lors du setup de l'application
@@ -1,4 +1,6 @@
+use crate::engines::model_manifest::{self, ManifestEngine, ManifestModel};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -22,6 +24,9 @@ pub enum LlmProvider {
     #[default]
     Groq,
     Local,
+    /// Serveur compatible OpenAI (vLLM, Ollama, llama-server...)
+    #[serde(rename = "openai")]
+    OpenAi,
 }
 
 /// Taille des modèles LLM locaux
@@ -37,61 +42,55 @@ pub enum LocalLlmModel {
 }
 
 impl LocalLlmModel {
-    pub fn file_name(&self) -> &'static str {
-        match self {
-            LocalLlmModel::SmolLM2_360M => "SmolLM2-360M-Instruct-Q8_0.gguf",
-            LocalLlmModel::Phi3Mini => "Phi-3-mini-4k-instruct-Q4_K_M.gguf",
-            LocalLlmModel::Qwen2_5_3B => "qwen2.5-3b-instruct-q4_k_m.gguf",
-        }
+    fn manifest(&self) -> &'static ManifestModel {
+        model_manifest::builtin(ManifestEngine::Llm, self)
     }
 
-    pub fn download_url(&self) -> &'static str {
-        match self {
-            LocalLlmModel::SmolLM2_360M => "https://huggingface.co/bartowski/SmolLM2-360M-Instruct-GGUF/resolve/main/SmolLM2-360M-Instruct-Q8_0.gguf",
-            LocalLlmModel::Phi3Mini => "https://huggingface.co/bartowski/Phi-3-mini-4k-instruct-GGUF/resolve/main/Phi-3-mini-4k-instruct-Q4_K_M.gguf",
-            LocalLlmModel::Qwen2_5_3B => "https://huggingface.co/Qwen/Qwen2.5-3B-Instruct-GGUF/resolve/main/qwen2.5-3b-instruct-q4_k_m.gguf",
-        }
+    pub fn file_name(&self) -> &'static str {
+        &self.manifest().install_name
     }
 
     pub fn size_bytes(&self) -> u64 {
-        match self {
-            LocalLlmModel::SmolLM2_360M => 386_000_000,    // ~386 MB
-            LocalLlmModel::Phi3Mini => 2_200_000_000,      // ~2.2 GB
-            LocalLlmModel::Qwen2_5_3B => 2_000_000_000,    // ~2.0 GB
-        }
+        self.manifest().size_bytes
     }
 
     pub fn display_name(&self) -> &'static str {
+        &self.manifest().display_name
+    }
+
+    /// Consigne de résumé adaptée à la taille du modèle
+    pub fn summary_instruction(&self) -> &'static str {
         match self {
-            LocalLlmModel::SmolLM2_360M => "SmolLM2 360M (386 MB) - Rapide",
-            LocalLlmModel::Phi3Mini => "Phi-3 Mini (2.2 GB) - Recommandé",
-            LocalLlmModel::Qwen2_5_3B => "Qwen2.5 3B (2 GB) - Qualité",
+            LocalLlmModel::SmolLM2_360M => "Resume ce texte en 2 phrases:",
+            LocalLlmModel::Phi3Mini => "Resume ce texte en 2-3 phrases concises en francais:",
+            LocalLlmModel::Qwen2_5_3B => "Resume ce texte en 2-3 phrases concises:",
         }
     }
 
-    /// Format du prompt pour ce modèle
-    pub fn format_prompt(&self, _instruction: &str, text: &str) -> String {
+    /// Format du prompt pour ce modèle (`system_prompt` vide : pas de
+    /// message système)
+    pub fn format_prompt(&self, system_prompt: &str, text: &str) -> String {
         match self {
-            LocalLlmModel::SmolLM2_360M => {
-                // SmolLM2 - format ChatML simplifié
+            LocalLlmModel::SmolLM2_360M | LocalLlmModel::Qwen2_5_3B => {
+                // SmolLM2 et Qwen2.5 - format ChatML
+                let system = if system_prompt.is_empty() {
+                    String::new()
+                } else {
+                    format!("<|im_start|>system\n{}<|im_end|>\n", system_prompt)
+                };
                 format!(
-                    "<|im_start|>user\nResume ce texte en 2 phrases:\n\n{}<|im_end|>\n<|im_start|>assistant\n",
-                    text
+                    "{}<|im_start|>user\n{}<|im_end|>\n<|im_start|>assistant\n",
+                    system, text
                 )
             }
             LocalLlmModel::Phi3Mini => {
-                // Phi-3 utilise un format spécifique
-                format!(
-                    "<|user|>\nResume ce texte en 2-3 phrases concises en francais:\n\n{}<|end|>\n<|assistant|>\n",
-                    text
-                )
-            }
-            LocalLlmModel::Qwen2_5_3B => {
-                // Qwen2.5 - format ChatML
-                format!(
-                    "<|im_start|>user\nResume ce texte en 2-3 phrases concises:\n\n{}<|im_end|>\n<|im_start|>assistant\n",
-                    text
-                )
+                // Phi-3 n'a pas de rôle système : la consigne précède le texte
+                let user = if system_prompt.is_empty() {
+                    text.to_string()
+                } else {
+                    format!("{}\n\n{}", system_prompt, text)
+                };
+                format!("<|user|>\n{}<|end|>\n<|assistant|>\n", user)
             }
         }
     }
@@ -115,48 +114,110 @@ impl Default for DictationMode {
     }
 }
 
+/// Taille de fichier à partir de laquelle un modèle Whisper tourne sur GPU
+pub const GPU_MIN_MODEL_BYTES: u64 = 500_000_000;
+
+/// Modèles Whisper (ggml) : tailles OpenAI, variantes turbo et distil,
+/// et versions quantifiées (q5/q8) plus légères
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum ModelSize {
     Tiny,
+    TinyQ5,
+    TinyQ8,
+    Base,
+    BaseQ5,
+    BaseQ8,
     Small,
+    SmallQ5,
+    SmallQ8,
     Medium,
+    MediumQ5,
+    MediumQ8,
+    LargeV3,
+    LargeV3Q5,
+    LargeV3Turbo,
+    LargeV3TurboQ5,
+    LargeV3TurboQ8,
+    DistilMediumEn,
+    DistilLargeV3,
 }
 
 impl ModelSize {
-    pub fn file_name(&self) -> &'static str {
-        match self {
-            ModelSize::Tiny => "ggml-tiny.bin",
-            ModelSize::Small => "ggml-small.bin",
-            ModelSize::Medium => "ggml-medium.bin",
-        }
+    /// Tous les modèles proposés, du plus léger au plus lourd par famille
+    pub const ALL: [ModelSize; 19] = [
+        ModelSize::TinyQ5,
+        ModelSize::TinyQ8,
+        ModelSize::Tiny,
+        ModelSize::BaseQ5,
+        ModelSize::BaseQ8,
+        ModelSize::Base,
+        ModelSize::SmallQ5,
+        ModelSize::SmallQ8,
+        ModelSize::Small,
+        ModelSize::MediumQ5,
+        ModelSize::MediumQ8,
+        ModelSize::Medium,
+        ModelSize::LargeV3TurboQ5,
+        ModelSize::LargeV3TurboQ8,
+        ModelSize::LargeV3Turbo,
+        ModelSize::LargeV3Q5,
+        ModelSize::LargeV3,
+        ModelSize::DistilMediumEn,
+        ModelSize::DistilLargeV3,
+    ];
+
+    fn manifest(&self) -> &'static ManifestModel {
+        model_manifest::builtin(ManifestEngine::Whisper, self)
     }
 
-    pub fn download_url(&self) -> &'static str {
-        match self {
-            ModelSize::Tiny => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-tiny.bin",
-            ModelSize::Small => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small.bin",
-            ModelSize::Medium => "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-medium.bin",
-        }
+    pub fn file_name(&self) -> &'static str {
+        &self.manifest().install_name
     }
 
     pub fn size_bytes(&self) -> u64 {
-        match self {
-            ModelSize::Tiny => 75_000_000,
-            ModelSize::Small => 466_000_000,
-            ModelSize::Medium => 1_500_000_000,
-        }
+        self.manifest().size_bytes
+    }
+
+    /// Mémoire nécessaire à l'inférence (poids + tampons de whisper.cpp)
+    pub fn ram_bytes(&self) -> u64 {
+        self.manifest().ram_bytes.unwrap_or(self.size_bytes())
+    }
+
+    /// Vitesse de transcription attendue, relative à Large v3 (1.0).
+    /// La quantification réduit surtout la mémoire, pas le temps de calcul.
+    pub fn relative_speed(&self) -> f32 {
+        self.manifest().relative_speed.unwrap_or(1.0)
+    }
+
+    /// Les modèles distil ne transcrivent que l'anglais
+    pub fn multilingual(&self) -> bool {
+        self.manifest().multilingual()
+    }
+
+    /// Le GPU (Metal/CUDA) ne profite qu'aux gros modèles ; les petits sont
+    /// plus rapides sur CPU
+    pub fn prefers_gpu(&self) -> bool {
+        self.size_bytes() >= GPU_MIN_MODEL_BYTES
     }
 
     pub fn display_name(&self) -> &'static str {
-        match self {
-            ModelSize::Tiny => "Tiny (75 MB)",
-            ModelSize::Small => "Small (466 MB)",
-            ModelSize::Medium => "Medium (1.5 GB)",
-        }
+        &self.manifest().display_name
     }
 }
 
+/// Modèle Whisper du catalogue et ses caractéristiques
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelInfo {
+    pub size: ModelSize,
+    pub display_name: String,
+    pub available: bool,
+    pub size_bytes: u64,
+    pub ram_bytes: u64,
+    pub relative_speed: f32,
+    pub multilingual: bool,
+}
+
 impl Default for ModelSize {
     fn default() -> Self {
         ModelSize::Tiny
@@ -180,16 +241,20 @@ pub enum ParakeetModelSize {
 }
 
 impl ParakeetModelSize {
+    fn manifest(&self) -> &'static ManifestModel {
+        model_manifest::builtin(ManifestEngine::Parakeet, self)
+    }
+
     pub fn model_name(&self) -> &'static str {
-        match self {
-            ParakeetModelSize::Tdt06bV3 => "parakeet-tdt-0.6b-v3",
-        }
+        &self.manifest().install_name
     }
 
     pub fn display_name(&self) -> &'static str {
-        match self {
-            ParakeetModelSize::Tdt06bV3 => "Parakeet TDT 0.6B v3 (Multilingual)",
-        }
+        &self.manifest().display_name
+    }
+
+    pub fn size_bytes(&self) -> u64 {
+        self.manifest().size_bytes
     }
 }
 
@@ -210,33 +275,35 @@ pub enum VoskLanguage {
 }
 
 impl VoskLanguage {
-    pub fn model_name(&self) -> &'static str {
-        match self {
-            VoskLanguage::En => "vosk-model-small-en-us-0.15",
-            VoskLanguage::Fr => "vosk-model-small-fr-0.22",
-            VoskLanguage::De => "vosk-model-small-de-0.15",
-            VoskLanguage::Es => "vosk-model-small-es-0.42",
-            VoskLanguage::It => "vosk-model-small-it-0.22",
-            VoskLanguage::Ru => "vosk-model-small-ru-0.22",
-            VoskLanguage::Zh => "vosk-model-small-cn-0.22",
-            VoskLanguage::Ja => "vosk-model-small-ja-0.22",
-            VoskLanguage::Ko => "vosk-model-small-ko-0.22",
-            VoskLanguage::Pt => "vosk-model-small-pt-0.3",
-            VoskLanguage::Nl => "vosk-model-small-nl-0.22",
-            VoskLanguage::Pl => "vosk-model-small-pl-0.22",
-            VoskLanguage::Uk => "vosk-model-small-uk-v3-small",
-            VoskLanguage::Tr => "vosk-model-small-tr-0.3",
-            VoskLanguage::Vi => "vosk-model-small-vn-0.4",
-            VoskLanguage::Ar => "vosk-model-ar-mgb2-0.4",
-            VoskLanguage::Hi => "vosk-model-small-hi-0.22",
-            VoskLanguage::Fa => "vosk-model-small-fa-0.5",
-            VoskLanguage::Ca => "vosk-model-small-ca-0.4",
-            VoskLanguage::Cs => "vosk-model-small-cs-0.4-rhasspy",
-        }
+    pub const ALL: [VoskLanguage; 20] = [
+        VoskLanguage::En,
+        VoskLanguage::Fr,
+        VoskLanguage::De,
+        VoskLanguage::Es,
+        VoskLanguage::It,
+        VoskLanguage::Ru,
+        VoskLanguage::Zh,
+        VoskLanguage::Ja,
+        VoskLanguage::Ko,
+        VoskLanguage::Pt,
+        VoskLanguage::Nl,
+        VoskLanguage::Pl,
+        VoskLanguage::Uk,
+        VoskLanguage::Tr,
+        VoskLanguage::Vi,
+        VoskLanguage::Ar,
+        VoskLanguage::Hi,
+        VoskLanguage::Fa,
+        VoskLanguage::Ca,
+        VoskLanguage::Cs,
+    ];
+
+    fn manifest(&self) -> &'static ManifestModel {
+        model_manifest::builtin(ManifestEngine::Vosk, self)
     }
 
-    pub fn download_url(&self) -> String {
-        format!("https://alphacephei.com/vosk/models/{}.zip", self.model_name())
+    pub fn model_name(&self) -> &'static str {
+        &self.manifest().install_name
     }
 
     pub fn display_name(&self) -> &'static str {
@@ -291,6 +358,53 @@ impl VoskLanguage {
     }
 }
 
+/// Modèle importé depuis un chemin local (fichier ggml, dossier ONNX
+/// Parakeet ou dossier Vosk), référencé sans copie dans le registre
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CustomModel {
+    pub id: String,
+    pub name: String,
+    pub engine: EngineType,
+    pub path: PathBuf,
+    /// Langue d'un modèle Vosk (un modèle par langue)
+    #[serde(default)]
+    pub language: Option<VoskLanguage>,
+    pub imported_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ModelRegistry {
+    pub models: Vec<CustomModel>,
+}
+
+/// Mot horodaté (secondes depuis le début de l'audio)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordTiming {
+    pub word: String,
+    pub start: f32,
+    pub end: f32,
+    pub probability: f32,
+}
+
+/// Segment de transcription horodaté (phrase ou énoncé)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Segment {
+    pub start: f32,
+    pub end: f32,
+    pub text: String,
+    #[serde(default)]
+    pub words: Vec<WordTiming>,
+}
+
+/// Plage de mots peu sûrs à faire vérifier par l'utilisateur
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfidenceSpan {
+    pub start: f32,
+    pub end: f32,
+    pub text: String,
+    pub probability: f32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptionResult {
     pub text: String,
@@ -301,6 +415,10 @@ pub struct TranscriptionResult {
     pub timestamp: i64,
     #[serde(default)]
     pub model_used: Option<String>,
+    #[serde(default)]
+    pub segments: Vec<Segment>,
+    #[serde(default)]
+    pub low_confidence_spans: Vec<ConfidenceSpan>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -334,6 +452,9 @@ pub struct AppSettings {
     pub translation_target_language: String,
     #[serde(default = "default_hotkey_translate")]
     pub hotkey_translate: String,
+    /// Dictée traduite vers `translation_target_language` (push-to-talk)
+    #[serde(default = "default_hotkey_translate_dictation")]
+    pub hotkey_translate_dictation: String,
     #[serde(default = "default_hotkey_voice_action")]
     pub hotkey_voice_action: String,
     #[serde(default)]
@@ -342,6 +463,16 @@ pub struct AppSettings {
     pub vosk_language: Option<VoskLanguage>,
     #[serde(default)]
     pub parakeet_model: ParakeetModelSize,
+    /// Modèle importé (id du registre) utilisé à la place du modèle intégré
+    /// de son moteur
+    #[serde(default)]
+    pub custom_model: Option<String>,
+    /// Manifeste de modèles distant, en complément du manifeste embarqué
+    #[serde(default)]
+    pub model_manifest_url: Option<String>,
+    /// Largeur du faisceau du décodeur Parakeet (1 = glouton, le plus rapide)
+    #[serde(default = "default_parakeet_beam_width")]
+    pub parakeet_beam_width: u32,
     #[serde(default)]
     pub groq_api_key: Option<String>,
     #[serde(default)]
@@ -349,6 +480,17 @@ pub struct AppSettings {
     #[serde(default)]
     pub local_llm_model: LocalLlmModel,
     #[serde(default)]
+    pub openai_provider: OpenAiProviderConfig,
+    /// Réponse du LLM tapée au curseur au fil de la génération (dictée,
+    /// actions vocales, traduction du presse-papiers) plutôt que collée à la
+    /// fin. Si la génération échoue, les mots déjà tapés restent en place.
+    #[serde(default)]
+    pub llm_stream_typing: bool,
+    /// Inactivité (minutes) après laquelle le LLM local est déchargé de la
+    /// mémoire ; 0 le garde chargé
+    #[serde(default = "default_llm_idle_timeout_minutes")]
+    pub llm_idle_timeout_minutes: u32,
+    #[serde(default)]
     pub onboarding_completed: bool,
     #[serde(default)]
     pub tour_completed: bool,
@@ -360,8 +502,20 @@ pub struct AppSettings {
     pub subtitles_font_size: u32,
     #[serde(default = "default_true")]
     pub stats_tracking_enabled: bool,
+    /// Retire les silences (détection d'activité vocale) avant transcription.
+    /// Activé pour les nouvelles installations, désactivé pour les réglages
+    /// enregistrés avant son apparition.
+    #[serde(default)]
+    pub vad_enabled: bool,
+    /// Silence (secondes) qui arrête une dictée en mode toggle, 0 pour désactiver
+    #[serde(default = "default_toggle_auto_stop_secs")]
+    pub toggle_auto_stop_secs: f32,
+    #[serde(default)]
+    pub whisper_decode: WhisperDecodeOptions,
     #[serde(default)]
     pub integrations: IntegrationConfig,
+    #[serde(default)]
+    pub export: ExportConfig,
 }
 
 fn default_true() -> bool {
@@ -376,6 +530,10 @@ fn default_hotkey_translate() -> String {
     "Control+Alt+T".to_string()
 }
 
+fn default_hotkey_translate_dictation() -> String {
+    "Control+Alt+Space".to_string()
+}
+
 fn default_hotkey_voice_action() -> String {
     "Control+Alt+A".to_string()
 }
@@ -384,6 +542,18 @@ fn default_subtitles_font_size() -> u32 {
     20
 }
 
+fn default_toggle_auto_stop_secs() -> f32 {
+    3.0
+}
+
+fn default_parakeet_beam_width() -> u32 {
+    1
+}
+
+fn default_llm_idle_timeout_minutes() -> u32 {
+    5
+}
+
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
@@ -408,20 +578,31 @@ impl Default for AppSettings {
             translation_enabled: true,
             translation_target_language: "en".to_string(),
             hotkey_translate: "Control+Alt+T".to_string(),
+            hotkey_translate_dictation: "Control+Alt+Space".to_string(),
             hotkey_voice_action: "Control+Alt+A".to_string(),
             engine_type: EngineType::default(),
             vosk_language: None,
             parakeet_model: ParakeetModelSize::default(),
+            custom_model: None,
+            model_manifest_url: None,
+            parakeet_beam_width: 1,
             groq_api_key: None,
             llm_provider: LlmProvider::default(),
             local_llm_model: LocalLlmModel::default(),
+            openai_provider: OpenAiProviderConfig::default(),
+            llm_stream_typing: false,
+            llm_idle_timeout_minutes: 5,
             onboarding_completed: false,
             tour_completed: false,
             system_commands_enabled: false,
             subtitles_enabled: false,
             subtitles_font_size: 20,
             stats_tracking_enabled: true,
+            vad_enabled: true,
+            toggle_auto_stop_secs: 3.0,
+            whisper_decode: WhisperDecodeOptions::default(),
             integrations: IntegrationConfig::default(),
+            export: ExportConfig::default(),
         }
     }
 }
@@ -481,3 +662,118 @@ pub struct IntegrationConfig {
     #[serde(default)]
     pub obsidian_vault_path: Option<String>,
 }
+
+/// Formats d'export des transcriptions de fichiers
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Srt,
+    Vtt,
+    Txt,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Srt => "srt",
+            ExportFormat::Vtt => "vtt",
+            ExportFormat::Txt => "txt",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+/// Serveur de chat compatible OpenAI utilisé par `LlmProvider::OpenAi`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct OpenAiProviderConfig {
+    /// URL de base de l'API, sans `/chat/completions`
+    pub base_url: String,
+    pub model: String,
+    /// Envoyée en `Authorization: Bearer`, facultative pour un serveur local
+    pub api_key: Option<String>,
+    /// En-têtes HTTP ajoutés à chaque requête
+    pub headers: std::collections::BTreeMap<String, String>,
+}
+
+impl Default for OpenAiProviderConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "http://localhost:11434/v1".to_string(),
+            model: "llama3.1".to_string(),
+            api_key: None,
+            headers: std::collections::BTreeMap::new(),
+        }
+    }
+}
+
+/// Options de décodage Whisper : précision contre vitesse
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct WhisperDecodeOptions {
+    /// Largeur du faisceau (1 = décodage glouton, le plus rapide)
+    pub beam_size: u32,
+    /// Température du premier décodage (0 = déterministe)
+    pub temperature: f32,
+    /// Pas de température pour redécoder un segment qui échoue aux seuils
+    /// ci-dessous, jusqu'à 1.0 (0 = pas de repli)
+    pub temperature_increment: f32,
+    /// En dessous de cette entropie des tokens (texte répétitif), le segment
+    /// est redécodé ; whisper.cpp l'utilise à la place du taux de compression
+    #[serde(alias = "compression_ratio_threshold")]
+    pub entropy_threshold: f32,
+    /// En dessous de cette log-probabilité moyenne, le segment est redécodé
+    pub logprob_threshold: f32,
+    /// Probabilité de silence au-delà de laquelle un segment est ignoré
+    pub no_speech_threshold: f32,
+    /// Texte donné comme contexte avant l'audio (style, ponctuation, termes)
+    pub initial_prompt: Option<String>,
+    /// Conditionner chaque fenêtre de 30 s sur le texte de la précédente
+    /// (plus cohérent, mais propage les hallucinations)
+    pub condition_on_previous_text: bool,
+}
+
+impl Default for WhisperDecodeOptions {
+    fn default() -> Self {
+        Self {
+            beam_size: 1,
+            temperature: 0.0,
+            temperature_increment: 0.2,
+            entropy_threshold: 2.4,
+            logprob_threshold: -1.0,
+            no_speech_threshold: 0.6,
+            initial_prompt: None,
+            condition_on_previous_text: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportConfig {
+    /// Exporter automatiquement après chaque transcription de fichier
+    pub auto_export: bool,
+    pub formats: Vec<ExportFormat>,
+    /// Dossier de sortie (par défaut : à côté du fichier source)
+    pub output_dir: Option<String>,
+    /// Nombre maximal de caractères par ligne de sous-titre
+    pub max_line_length: usize,
+    /// Nombre maximal de lignes par sous-titre
+    pub max_lines_per_caption: usize,
+    /// Durée maximale d'un sous-titre en secondes
+    pub max_caption_duration_secs: f32,
+}
+
+impl Default for ExportConfig {
+    fn default() -> Self {
+        Self {
+            auto_export: false,
+            formats: vec![ExportFormat::Srt, ExportFormat::Txt],
+            output_dir: None,
+            max_line_length: 42,
+            max_lines_per_caption: 2,
+            max_caption_duration_secs: 6.0,
+        }
+    }
+}
@@ -361,6 +361,11 @@ function App() {
                   LLM (Local)
                 </span>
               )}
+              {settings?.llm_enabled && settings?.llm_provider === 'openai' && (
+                <span className="tag-frost success flex items-center gap-2">
+                  LLM ({settings.openai_provider.model})
+                </span>
+              )}
             </div>
             <div className="flex items-center gap-3">
               <span className="kbd-frost">{formatHotkey(settings?.hotkey_push_to_talk || 'CommandOrControl+Shift+Space')}</span>
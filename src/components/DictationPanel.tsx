@@ -3,7 +3,7 @@ import { writeText } from '@tauri-apps/plugin-clipboard-manager';
 import { listen } from '@tauri-apps/api/event';
 import { useTranscriptionStore } from '../stores/transcriptionStore';
 import { useSettingsStore } from '../stores/settingsStore';
-import { StreamingChunk } from '../types';
+import { StreamingChunk, StreamingTextEvent } from '../types';
 
 export function DictationPanel() {
   const { status, result, error, startRecording, stopRecording, clearError, setStatus } = useTranscriptionStore();
@@ -34,15 +34,19 @@ export function DictationPanel() {
       if (chunk.is_final) {
         // Transcription finale complète
         setStreamingText(chunk.text);
-      } else if (chunk.text) {
-        // Nouveau chunk de texte partiel - afficher le dernier segment transcrit
-        setStreamingText(chunk.text);
       }
-      // Les chunks vides (text: '') sont des indicateurs de durée, on les ignore pour le texte
+      // Les chunks non finaux (text: '') sont des indicateurs de durée, on les ignore pour le texte
+    });
+
+    // Texte validé suivi de la partie encore provisoire
+    const unlistenPartial = listen<StreamingTextEvent>('transcription-partial', (event) => {
+      const { committed_text, text } = event.payload;
+      setStreamingText([committed_text, text].filter(Boolean).join(' '));
     });
 
     return () => {
       unlistenChunk.then((fn) => fn());
+      unlistenPartial.then((fn) => fn());
     };
   }, [settings?.streaming_enabled]);
 
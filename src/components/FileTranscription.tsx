@@ -2,7 +2,8 @@ import { useState, useCallback, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
 import { open } from '@tauri-apps/plugin-dialog';
-import { FileTranscriptionResult, FileTranscriptionProgress, LlmProvider } from '../types';
+import { ExportFormat, FileTranscriptionResult, FileTranscriptionProgress, LlmProvider, LlmRequestEvent, LlmToken } from '../types';
+import { EXPORT_FORMATS } from './settings';
 import { useSettingsStore } from '../stores/settingsStore';
 
 interface FileTranscriptionProps {
@@ -16,6 +17,10 @@ interface SummaryState {
     loading: boolean;
     text: string | null;
     error: string | null;
+    /** Resume genere par le LLM local, le seul qui peut etre interrompu */
+    local?: boolean;
+    /** Requete du LLM local a annuler */
+    requestId?: number;
   };
 }
 
@@ -26,8 +31,11 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
   const [progress, setProgress] = useState<FileTranscriptionProgress | null>(null);
   const [supportedFormats, setSupportedFormats] = useState<string[]>([]);
   const [summaries, setSummaries] = useState<SummaryState>({});
+  const [summarizingIndex, setSummarizingIndex] = useState<number | null>(null);
+  const [exportErrors, setExportErrors] = useState<Record<number, string>>({});
   const [localLlmAvailable, setLocalLlmAvailable] = useState(false);
   const settings = useSettingsStore(state => state.settings);
+  const llmProvider = settings?.llm_provider;
 
   useEffect(() => {
     invoke<string[]>('get_supported_audio_formats').then(setSupportedFormats).catch(console.error);
@@ -51,6 +59,25 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
     };
   }, []);
 
+  // Affiche le resume au fil de la generation
+  useEffect(() => {
+    if (summarizingIndex === null) return;
+    const unlistenToken = listen<LlmToken>('llm-token', (event) => {
+      if (event.payload.source !== 'summary') return;
+      setSummaries(prev => {
+        const current = prev[summarizingIndex];
+        if (!current?.loading) return prev;
+        return {
+          ...prev,
+          [summarizingIndex]: { ...current, text: (current.text ?? '') + event.payload.token }
+        };
+      });
+    });
+    return () => {
+      unlistenToken.then(fn => fn());
+    };
+  }, [summarizingIndex]);
+
   // Handle files from drag & drop
   useEffect(() => {
     if (initialFiles && initialFiles.length > 0) {
@@ -88,7 +115,7 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
 
     setIsProcessing(true);
     setResults([]);
-    setProgress({ current: 0, total: files.length, file_name: '', status: 'starting' });
+    setProgress({ current: 0, total: files.length, file_name: '', status: 'starting', percent: 0 });
 
     try {
       const transcriptionResults = await invoke<FileTranscriptionResult[]>('transcribe_files', {
@@ -103,6 +130,14 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
     }
   }, [files]);
 
+  const handleCancel = useCallback(async () => {
+    try {
+      await invoke('cancel_file_transcription');
+    } catch (e) {
+      console.error('Failed to cancel transcription:', e);
+    }
+  }, []);
+
   const handleCopyResult = useCallback((text: string) => {
     navigator.clipboard.writeText(text);
   }, []);
@@ -112,10 +147,24 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
   }, []);
 
   const handleSummarize = useCallback(async (index: number, text: string, provider?: LlmProvider) => {
+    const local = (provider ?? llmProvider) === 'local';
     setSummaries(prev => ({
       ...prev,
-      [index]: { loading: true, text: null, error: null }
+      [index]: { loading: true, text: null, error: null, local }
     }));
+    setSummarizingIndex(index);
+
+    // La premiere requete de resume mise en file apres ce clic est la notre
+    let requestId: number | null = null;
+    const unlistenRequest = local
+      ? await listen<LlmRequestEvent>('llm-request', (event) => {
+          if (event.payload.source !== 'summary' || requestId !== null) return;
+          requestId = event.payload.request_id;
+          setSummaries(prev => prev[index]?.loading
+            ? { ...prev, [index]: { ...prev[index], requestId: event.payload.request_id } }
+            : prev);
+        })
+      : null;
 
     try {
       // Utilise summarize_text_smart qui choisit automatiquement le provider
@@ -129,6 +178,17 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
         ...prev,
         [index]: { loading: false, text: null, error: String(e) }
       }));
+    } finally {
+      unlistenRequest?.();
+      setSummarizingIndex(null);
+    }
+  }, [llmProvider]);
+
+  const handleCancelSummary = useCallback(async (requestId: number) => {
+    try {
+      await invoke('cancel_llm_request', { requestId });
+    } catch (e) {
+      console.error('Failed to cancel summary:', e);
     }
   }, []);
 
@@ -136,6 +196,23 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
     navigator.clipboard.writeText(text);
   }, []);
 
+  const handleExport = useCallback(async (index: number, result: FileTranscriptionResult, format: ExportFormat) => {
+    if (!settings || !result.transcription) return;
+    setExportErrors(prev => ({ ...prev, [index]: '' }));
+    try {
+      const paths = await invoke<string[]>('export_transcription', {
+        transcription: result.transcription,
+        sourcePath: result.file_path,
+        config: { ...settings.export, formats: [format] },
+      });
+      setResults(prev => prev.map((r, i) =>
+        i === index ? { ...r, exported_files: [...r.exported_files.filter(p => !paths.includes(p)), ...paths] } : r
+      ));
+    } catch (e) {
+      setExportErrors(prev => ({ ...prev, [index]: String(e) }));
+    }
+  }, [settings]);
+
   const handleSendTo = useCallback(async (target: 'apple_notes' | 'obsidian', text: string, fileName: string) => {
     const title = `Transcription - ${fileName}`;
     try {
@@ -243,14 +320,19 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
                   {progress.status === 'transcribing' ? 'Transcription en cours...' : progress.status}
                 </span>
               </div>
-              <span className="tag-frost accent">
-                {progress.current}/{progress.total}
-              </span>
+              <div className="flex items-center gap-2">
+                <span className="tag-frost accent">
+                  {progress.current}/{progress.total} · {Math.round(progress.percent)}%
+                </span>
+                <button onClick={handleCancel} className="btn-glass text-[0.8rem]">
+                  Annuler
+                </button>
+              </div>
             </div>
             <div className="progress-frost">
               <div
                 className="bar"
-                style={{ width: `${(progress.current / progress.total) * 100}%` }}
+                style={{ width: `${progress.percent}%` }}
               />
             </div>
             {progress.file_name && (
@@ -305,13 +387,24 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
                       <div className="flex items-center gap-2">
                         {/* Bouton résumé avec choix local/cloud */}
                         {summaries[index]?.loading ? (
-                          <button
-                            disabled
-                            className="btn-glass text-[0.75rem] py-1.5 px-3 opacity-50"
-                          >
-                            <div className="w-3 h-3 border-2 border-current border-t-transparent rounded-full animate-spin" />
-                            Resume...
-                          </button>
+                          <>
+                            <button
+                              disabled
+                              className="btn-glass text-[0.75rem] py-1.5 px-3 opacity-50"
+                            >
+                              <div className="w-3 h-3 border-2 border-current border-t-transparent rounded-full animate-spin" />
+                              Resume...
+                            </button>
+                            {summaries[index].local && (
+                              <button
+                                onClick={() => handleCancelSummary(summaries[index].requestId!)}
+                                disabled={summaries[index].requestId === undefined}
+                                className="btn-glass text-[0.75rem] py-1.5 px-3"
+                              >
+                                Annuler
+                              </button>
+                            )}
+                          </>
                         ) : localLlmAvailable && settings?.groq_api_key ? (
                           // Les deux providers sont disponibles - afficher un dropdown
                           <div className="relative group">
@@ -373,6 +466,27 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
                           </svg>
                           Copier
                         </button>
+                        <div className="relative group">
+                          <button className="btn-glass text-[0.75rem] py-1.5 px-3">
+                            <svg width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" strokeWidth="2">
+                              <path d="M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4" />
+                              <polyline points="7 10 12 15 17 10" />
+                              <line x1="12" y1="15" x2="12" y2="3" />
+                            </svg>
+                            Exporter
+                          </button>
+                          <div className="absolute top-full right-0 mt-1 py-1 min-w-[140px] bg-[var(--glass-bg)] backdrop-blur-xl border border-[var(--glass-border)] rounded-lg shadow-lg opacity-0 invisible group-hover:opacity-100 group-hover:visible transition-all z-10">
+                            {EXPORT_FORMATS.map(({ format, label }) => (
+                              <button
+                                key={format}
+                                onClick={() => handleExport(index, result, format)}
+                                className="w-full px-3 py-2 text-left text-[0.75rem] text-[var(--text-secondary)] hover:bg-[rgba(255,255,255,0.08)]"
+                              >
+                                {label}
+                              </button>
+                            ))}
+                          </div>
+                        </div>
                         {(settings?.integrations?.apple_notes_enabled || settings?.integrations?.obsidian_enabled) && (
                           <div className="relative group">
                             <button className="btn-glass text-[0.75rem] py-1.5 px-3">
@@ -416,6 +530,19 @@ export function FileTranscription({ isOpen, initialFiles }: FileTranscriptionPro
                       </p>
                     ) : null}
 
+                    {result.exported_files.length > 0 && (
+                      <div className="space-y-1">
+                        {result.exported_files.map((path) => (
+                          <p key={path} className="text-[0.7rem] text-[var(--text-muted)] truncate">
+                            Exporte : {path}
+                          </p>
+                        ))}
+                      </div>
+                    )}
+                    {exportErrors[index] && (
+                      <p className="text-[0.75rem] text-[var(--accent-danger)]">{exportErrors[index]}</p>
+                    )}
+
                     {/* Affichage du résumé */}
                     {summaries[index]?.error && (
                       <div className="p-3 rounded-lg bg-[var(--accent-danger-soft)] border border-[var(--accent-danger)]">
@@ -2,7 +2,7 @@ import { useEffect, useState, useRef } from "react";
 import { listen } from "@tauri-apps/api/event";
 import { invoke } from "@tauri-apps/api/core";
 import { getCurrentWindow } from "@tauri-apps/api/window";
-import { DictationMode, StreamingChunk } from "../types";
+import { DictationMode, StreamingChunk, StreamingTextEvent } from "../types";
 
 type RecordingStatus = "idle" | "recording" | "processing";
 
@@ -77,9 +77,17 @@ export default function FloatingWindow() {
       }
     }).then((unlisten) => unlisteners.push(unlisten));
 
-    // Chunks de streaming — chaque chunk contient la transcription complète
+    // Transcription finale
     listen<StreamingChunk>("transcription-chunk", (event) => {
-      setStreamingText(event.payload.text);
+      if (event.payload.is_final) {
+        setStreamingText(event.payload.text);
+      }
+    }).then((unlisten) => unlisteners.push(unlisten));
+
+    // Streaming — texte validé suivi de la partie encore provisoire
+    listen<StreamingTextEvent>("transcription-partial", (event) => {
+      const { committed_text, text } = event.payload;
+      setStreamingText([committed_text, text].filter(Boolean).join(" "));
     }).then((unlisten) => unlisteners.push(unlisten));
 
     // Infos de statut (mode, LLM)
@@ -3,6 +3,7 @@ import { useSettingsStore } from '../stores/settingsStore';
 import {
   AudioSection,
   EngineSection,
+  CustomModelsSection,
   LlmSection,
   TranslationSection,
   DictationSection,
@@ -13,6 +14,7 @@ import {
   DictionarySection,
   SnippetsSection,
   IntegrationsSection,
+  ExportSection,
 } from './settings';
 import { StatisticsPanel } from './StatisticsPanel';
 import logoSvg from '../assets/logo.svg';
@@ -72,6 +74,7 @@ export function SettingsPanel({ isOpen, onClose }: SettingsPanelProps) {
         <div className="flex-1 overflow-y-auto p-6 space-y-8 scrollbar-thin">
           <AudioSection settings={settings} devices={devices} updateSettings={updateSettings} />
           <EngineSection settings={settings} updateSettings={updateSettings} />
+          <CustomModelsSection settings={settings} />
           <LlmSection settings={settings} updateSettings={updateSettings} onApiKeyStatusChange={setApiKeyStatus} />
           <TranslationSection settings={settings} updateSettings={updateSettings} apiKeyStatus={apiKeyStatus} />
           <DictationSection settings={settings} updateSettings={updateSettings} />
@@ -80,6 +83,7 @@ export function SettingsPanel({ isOpen, onClose }: SettingsPanelProps) {
           <SystemSection settings={settings} updateSettings={updateSettings} />
           <StatisticsPanel />
           <IntegrationsSection settings={settings} updateSettings={updateSettings} />
+          <ExportSection settings={settings} updateSettings={updateSettings} />
           <ShortcutsSection settings={settings} updateSettings={updateSettings} />
           <DictionarySection dictionary={dictionary} addWord={addWord} removeWord={removeWord} />
           <SnippetsSection />
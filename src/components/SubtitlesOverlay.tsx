@@ -7,6 +7,12 @@ interface StreamingChunk {
   duration_seconds: number;
 }
 
+interface StreamingTextEvent {
+  text: string;
+  committed_text: string;
+  duration_seconds: number;
+}
+
 export function SubtitlesOverlay() {
   const [text, setText] = useState('');
   const [isVisible, setIsVisible] = useState(false);
@@ -36,6 +42,16 @@ export function SubtitlesOverlay() {
       }
     }).then(unlisten => unlisteners.push(unlisten));
 
+    // Streaming : texte validé suivi de la partie encore provisoire
+    listen<StreamingTextEvent>('transcription-partial', (event) => {
+      const { committed_text, text } = event.payload;
+      const current = [committed_text, text].filter(Boolean).join(' ');
+      if (current.trim()) {
+        setText(current);
+        setIsVisible(true);
+      }
+    }).then(unlisten => unlisteners.push(unlisten));
+
     listen<string>('recording-status', (event) => {
       if (event.payload === 'recording') {
         setIsVisible(true);
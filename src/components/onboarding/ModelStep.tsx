@@ -23,6 +23,9 @@ const ENGINE_INFO: Record<EngineType, { description: string }> = {
   parakeet: { description: 'NVIDIA — Detection auto, 25 langues (macOS)' },
 };
 
+// Les autres tailles restent disponibles dans les reglages
+const ONBOARDING_MODELS: ModelSize[] = ['tiny', 'small', 'medium'];
+
 export function ModelStep({ onValidChange }: StepProps) {
   const { settings, updateSettings } = useSettingsStore();
   const [selectedEngine, setSelectedEngine] = useState<EngineType>(settings?.engine_type || 'whisper');
@@ -171,13 +174,13 @@ export function ModelStep({ onValidChange }: StepProps) {
     }
   };
 
-  const qualityLabels: Record<ModelSize, string> = {
+  const qualityLabels: Partial<Record<ModelSize, string>> = {
     tiny: 'Basique',
     small: 'Bonne',
     medium: 'Tres bonne',
   };
 
-  const qualityColors: Record<ModelSize, string> = {
+  const qualityColors: Partial<Record<ModelSize, string>> = {
     tiny: 'var(--text-muted)',
     small: 'var(--accent-primary)',
     medium: 'var(--accent-success)',
@@ -228,7 +231,7 @@ export function ModelStep({ onValidChange }: StepProps) {
       {/* Whisper models */}
       {selectedEngine === 'whisper' && (
         <div className="space-y-3">
-          {models.map((model) => (
+          {models.filter((model) => ONBOARDING_MODELS.includes(model.size)).map((model) => (
             <div
               key={model.size}
               className={`glass-card p-4 transition-all ${
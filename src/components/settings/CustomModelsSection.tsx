@@ -0,0 +1,264 @@
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { open } from '@tauri-apps/plugin-dialog';
+import { AppSettings, CatalogModel, CustomModel, DownloadProgress } from '../../types';
+import { useSettingsStore } from '../../stores/settingsStore';
+
+interface CustomModelsSectionProps {
+  settings: AppSettings;
+}
+
+const ENGINE_LABELS: Record<CustomModel['engine'], string> = {
+  whisper: 'Whisper (ggml)',
+  parakeet: 'Parakeet (ONNX)',
+  vosk: 'Vosk',
+};
+
+export function CustomModelsSection({ settings }: CustomModelsSectionProps) {
+  const { loadSettings, updateSettings } = useSettingsStore();
+  const [models, setModels] = useState<CustomModel[]>([]);
+  const [catalog, setCatalog] = useState<CatalogModel[]>([]);
+  const [downloadingCatalog, setDownloadingCatalog] = useState<string | null>(null);
+  const [catalogProgress, setCatalogProgress] = useState<DownloadProgress | null>(null);
+  const [manifestUrl, setManifestUrl] = useState(settings.model_manifest_url ?? '');
+  const [path, setPath] = useState('');
+  const [name, setName] = useState('');
+  const [language, setLanguage] = useState('');
+  const [error, setError] = useState<string | null>(null);
+
+  const loadModels = async () => {
+    try {
+      setModels(await invoke<CustomModel[]>('get_custom_models'));
+    } catch (e) {
+      console.error('Failed to load imported models:', e);
+    }
+  };
+
+  const loadCatalog = async () => {
+    try {
+      setCatalog(await invoke<CatalogModel[]>('get_catalog_models'));
+    } catch (e) {
+      console.error('Failed to load model catalog:', e);
+    }
+  };
+
+  useEffect(() => {
+    loadModels();
+    loadCatalog();
+  }, []);
+
+  useEffect(() => {
+    const unlistenProgress = listen<DownloadProgress>('catalog-download-progress', (event) => {
+      setCatalogProgress(event.payload);
+    });
+
+    return () => {
+      unlistenProgress.then(fn => fn());
+    };
+  }, []);
+
+  const handleRefreshManifest = async () => {
+    setError(null);
+    try {
+      await updateSettings({ model_manifest_url: manifestUrl.trim() || null });
+      if (manifestUrl.trim()) {
+        await invoke('refresh_model_manifest');
+      }
+      await loadCatalog();
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const handleDownloadCatalogModel = async (id: string) => {
+    setError(null);
+    setDownloadingCatalog(id);
+    setCatalogProgress({ downloaded: 0, total: 1, percent: 0 });
+    try {
+      await invoke('download_catalog_model', { id });
+      await loadModels();
+      await loadCatalog();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setDownloadingCatalog(null);
+      setCatalogProgress(null);
+    }
+  };
+
+  const handleBrowse = async (directory: boolean) => {
+    try {
+      const selected = await open({ directory, multiple: false });
+      if (selected && typeof selected === 'string') {
+        setPath(selected);
+      }
+    } catch (e) {
+      console.error('Failed to select model:', e);
+    }
+  };
+
+  const handleImport = async () => {
+    setError(null);
+    try {
+      await invoke('import_model', { path, name, language: language || null });
+      setPath('');
+      setName('');
+      await loadModels();
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const handleUse = async (id: string) => {
+    setError(null);
+    try {
+      await invoke('switch_model', { size: settings.whisper_model, customId: id });
+      await loadSettings();
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const handleRemove = async (id: string) => {
+    try {
+      await invoke('remove_custom_model', { id });
+      await loadModels();
+      await loadSettings();
+    } catch (e) {
+      console.error('Failed to remove imported model:', e);
+    }
+  };
+
+  return (
+    <section className="space-y-4">
+      <h3 className="section-title primary">Modeles personnalises</h3>
+
+      {models.map((model) => {
+        const active = settings.custom_model === model.id && settings.engine_type === model.engine;
+        return (
+          <div
+            key={model.id}
+            className={`glass-card p-4 ${active ? 'border-[var(--accent-success)]' : ''}`}
+          >
+            <div className="flex items-center justify-between">
+              <div className="min-w-0">
+                <div className="text-[0.9375rem] text-[var(--text-primary)] font-medium">{model.name}</div>
+                <div className="text-[0.7rem] text-[var(--text-muted)] truncate">
+                  {ENGINE_LABELS[model.engine]} · {model.path}
+                </div>
+              </div>
+              <div className="flex items-center gap-3">
+                {active ? (
+                  <span className="tag-frost success">Actif</span>
+                ) : (
+                  <button
+                    onClick={() => handleUse(model.id)}
+                    className="text-[0.8rem] text-[var(--accent-primary)] hover:underline font-medium"
+                  >
+                    Utiliser
+                  </button>
+                )}
+                <button
+                  onClick={() => handleRemove(model.id)}
+                  className="text-[var(--text-muted)] hover:text-[var(--accent-danger)] transition-colors p-1"
+                >
+                  <svg width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" strokeWidth="2">
+                    <line x1="18" y1="6" x2="6" y2="18" />
+                    <line x1="6" y1="6" x2="18" y2="18" />
+                  </svg>
+                </button>
+              </div>
+            </div>
+          </div>
+        );
+      })}
+
+      {catalog.filter((model) => !model.installed).map((model) => (
+        <div key={model.id} className="glass-card p-4">
+          <div className="flex items-center justify-between">
+            <div className="min-w-0">
+              <div className="text-[0.9375rem] text-[var(--text-primary)] font-medium">{model.display_name}</div>
+              <div className="text-[0.7rem] text-[var(--text-muted)]">
+                {ENGINE_LABELS[model.engine]} · ~{(model.size_bytes / 1_000_000_000).toFixed(1)} GB · {model.license}
+                {model.languages.length > 0 && ` · ${model.languages.join(', ')}`}
+              </div>
+            </div>
+            {downloadingCatalog === model.id ? (
+              <div className="w-24 progress-frost">
+                <div className="bar" style={{ width: `${catalogProgress?.percent || 0}%` }} />
+              </div>
+            ) : (
+              <button
+                onClick={() => handleDownloadCatalogModel(model.id)}
+                disabled={downloadingCatalog !== null}
+                className="btn-glass text-[0.8rem]"
+              >
+                Telecharger
+              </button>
+            )}
+          </div>
+        </div>
+      ))}
+
+      <div className="space-y-2">
+        <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Manifeste de modeles (URL)</label>
+        <div className="flex gap-2 items-center">
+          <input
+            type="text"
+            value={manifestUrl}
+            onChange={(e) => setManifestUrl(e.target.value)}
+            placeholder="Manifeste integre"
+            className="input-glass flex-1 text-[0.8rem]"
+          />
+          <button onClick={handleRefreshManifest} className="btn-glass text-[0.8rem]">
+            Actualiser
+          </button>
+        </div>
+      </div>
+
+      <div className="space-y-2">
+        <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Importer un modele</label>
+        <div className="flex gap-2 items-center">
+          <input
+            type="text"
+            value={path}
+            readOnly
+            placeholder="Fichier ggml ou dossier ONNX / Vosk..."
+            className="input-glass flex-1 text-[0.8rem]"
+          />
+          <button onClick={() => handleBrowse(false)} className="btn-glass px-3 text-[var(--accent-primary)]">
+            Fichier
+          </button>
+          <button onClick={() => handleBrowse(true)} className="btn-glass px-3 text-[var(--accent-primary)]">
+            Dossier
+          </button>
+        </div>
+        <div className="flex gap-2 items-center">
+          <input
+            type="text"
+            value={name}
+            onChange={(e) => setName(e.target.value)}
+            placeholder="Nom affiche"
+            className="input-glass flex-1 text-[0.8rem]"
+          />
+          <input
+            type="text"
+            value={language}
+            onChange={(e) => setLanguage(e.target.value)}
+            placeholder="Langue (Vosk)"
+            className="input-glass w-28 text-[0.8rem]"
+          />
+          <button
+            onClick={handleImport}
+            disabled={!path || !name.trim()}
+            className="btn-glass text-[0.8rem]"
+          >
+            Importer
+          </button>
+        </div>
+        {error && <p className="text-[0.75rem] text-[var(--accent-danger)]">{error}</p>}
+      </div>
+    </section>
+  );
+}
@@ -11,6 +11,7 @@ import {
   VoskModelInfo,
   ParakeetModelSize,
   ParakeetModelInfo,
+  WhisperDecodeOptions,
 } from '../../types';
 import { useSettingsStore } from '../../stores/settingsStore';
 
@@ -168,6 +169,9 @@ export function EngineSection({ settings, updateSettings }: EngineSectionProps)
     }
   };
 
+  const updateWhisperDecode = (changes: Partial<WhisperDecodeOptions>) =>
+    updateSettings({ whisper_decode: { ...settings.whisper_decode, ...changes } });
+
   const handleDownloadParakeetModel = async (size: ParakeetModelSize) => {
     setDownloadingParakeet(size);
     setParakeetDownloadProgress({ downloaded: 0, total: 1, percent: 0 });
@@ -231,13 +235,13 @@ export function EngineSection({ settings, updateSettings }: EngineSectionProps)
             <div
               key={model.size}
               className={`glass-card p-4 ${
-                settings.whisper_model === model.size ? 'border-[var(--accent-success)]' : ''
+                !settings.custom_model && settings.whisper_model === model.size ? 'border-[var(--accent-success)]' : ''
               }`}
             >
               <div className="flex items-center justify-between">
                 <div className="flex items-center gap-3">
                   <div className={`w-3 h-3 rounded-full ${
-                    settings.whisper_model === model.size
+                    !settings.custom_model && settings.whisper_model === model.size
                       ? 'bg-[var(--accent-success)]'
                       : 'bg-[var(--glass-border)]'
                   }`} />
@@ -245,6 +249,10 @@ export function EngineSection({ settings, updateSettings }: EngineSectionProps)
                     <div className="text-[0.9375rem] text-[var(--text-primary)] font-medium">
                       {model.display_name}
                     </div>
+                    <div className="text-[0.7rem] text-[var(--text-muted)]">
+                      RAM ~{(model.ram_bytes / 1_000_000_000).toFixed(1)} GB · vitesse x{model.relative_speed}
+                      {!model.multilingual && ' · anglais uniquement'}
+                    </div>
                     {model.size === 'small' && (
                       <div className="text-[0.7rem] text-[var(--accent-primary)]">Recommande</div>
                     )}
@@ -259,10 +267,11 @@ export function EngineSection({ settings, updateSettings }: EngineSectionProps)
                     <span className="text-[0.75rem] text-[var(--text-muted)] w-12 text-right tabular-nums">
                       {Math.round(downloadProgress?.percent || 0)}%
                     </span>
+                    <DownloadActions id={downloadProgress?.id} />
                   </div>
                 ) : model.available ? (
                   <div className="flex items-center gap-3">
-                    {settings.whisper_model === model.size ? (
+                    {!settings.custom_model && settings.whisper_model === model.size ? (
                       <span className="tag-frost success">Actif</span>
                     ) : (
                       <button
@@ -300,6 +309,90 @@ export function EngineSection({ settings, updateSettings }: EngineSectionProps)
               </div>
             </div>
           ))}
+
+          {/* Options de decodage Whisper */}
+          <div className="space-y-3 pt-2">
+            <div className="grid grid-cols-2 gap-3">
+              <div>
+                <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Decodage</label>
+                <select
+                  value={settings.whisper_decode.beam_size}
+                  onChange={(e) => updateWhisperDecode({ beam_size: Number(e.target.value) })}
+                  className="select-glass"
+                >
+                  <option value={1}>Glouton (rapide)</option>
+                  <option value={3}>Faisceau 3</option>
+                  <option value={5}>Faisceau 5 (plus precis)</option>
+                </select>
+              </div>
+              <div>
+                <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Repli en temperature</label>
+                <select
+                  value={settings.whisper_decode.temperature_increment}
+                  onChange={(e) => updateWhisperDecode({ temperature_increment: Number(e.target.value) })}
+                  className="select-glass"
+                >
+                  <option value={0}>Desactive</option>
+                  <option value={0.2}>Par pas de 0,2</option>
+                  <option value={0.4}>Par pas de 0,4</option>
+                </select>
+              </div>
+            </div>
+            <div className="grid grid-cols-3 gap-3">
+              <div>
+                <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Seuil entropie</label>
+                <input
+                  type="number"
+                  step={0.1}
+                  value={settings.whisper_decode.entropy_threshold}
+                  onChange={(e) => updateWhisperDecode({ entropy_threshold: Number(e.target.value) })}
+                  className="input-glass w-full text-[0.8rem]"
+                />
+              </div>
+              <div>
+                <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Seuil log-prob</label>
+                <input
+                  type="number"
+                  step={0.1}
+                  value={settings.whisper_decode.logprob_threshold}
+                  onChange={(e) => updateWhisperDecode({ logprob_threshold: Number(e.target.value) })}
+                  className="input-glass w-full text-[0.8rem]"
+                />
+              </div>
+              <div>
+                <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Seuil silence</label>
+                <input
+                  type="number"
+                  step={0.05}
+                  min={0}
+                  max={1}
+                  value={settings.whisper_decode.no_speech_threshold}
+                  onChange={(e) => updateWhisperDecode({ no_speech_threshold: Number(e.target.value) })}
+                  className="input-glass w-full text-[0.8rem]"
+                />
+              </div>
+            </div>
+            <div>
+              <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Prompt initial</label>
+              <input
+                type="text"
+                defaultValue={settings.whisper_decode.initial_prompt || ''}
+                onBlur={(e) => updateWhisperDecode({ initial_prompt: e.target.value.trim() || null })}
+                placeholder="Ex : Bonjour, voici le compte rendu de la reunion."
+                className="input-glass w-full text-[0.8rem]"
+              />
+              <p className="text-[0.65rem] text-[var(--text-muted)] mt-1">Guide le style et la ponctuation de la transcription</p>
+            </div>
+            <label className="checkbox-frost">
+              <input
+                type="checkbox"
+                checked={settings.whisper_decode.condition_on_previous_text}
+                onChange={(e) => updateWhisperDecode({ condition_on_previous_text: e.target.checked })}
+              />
+              <span className="check-box" />
+              <span className="check-label">Utiliser le texte precedent comme contexte</span>
+            </label>
+          </div>
         </div>
       )}
 
@@ -314,13 +407,13 @@ export function EngineSection({ settings, updateSettings }: EngineSectionProps)
               <div
                 key={model.language}
                 className={`glass-card p-3 ${
-                  settings.vosk_language === model.language ? 'border-[var(--accent-success)]' : ''
+                  !settings.custom_model && settings.vosk_language === model.language ? 'border-[var(--accent-success)]' : ''
                 }`}
               >
                 <div className="flex items-center justify-between">
                   <div className="flex items-center gap-2">
                     <div className={`w-2 h-2 rounded-full ${
-                      settings.vosk_language === model.language
+                      !settings.custom_model && settings.vosk_language === model.language
                         ? 'bg-[var(--accent-success)]'
                         : 'bg-[var(--glass-border)]'
                     }`} />
@@ -334,9 +427,10 @@ export function EngineSection({ settings, updateSettings }: EngineSectionProps)
                       <div className="w-12 progress-frost">
                         <div className="bar" style={{ width: `${voskDownloadProgress?.percent || 0}%` }} />
                       </div>
+                      <DownloadActions id={voskDownloadProgress?.id} />
                     </div>
                   ) : model.available ? (
-                    settings.vosk_language === model.language ? (
+                    !settings.custom_model && settings.vosk_language === model.language ? (
                       <span className="text-[0.65rem] text-[var(--accent-success)]">Actif</span>
                     ) : (
                       <button
@@ -375,13 +469,13 @@ export function EngineSection({ settings, updateSettings }: EngineSectionProps)
             <div
               key={model.size}
               className={`glass-card p-4 ${
-                settings.parakeet_model === model.size && model.available ? 'border-[var(--accent-success)]' : ''
+                !settings.custom_model && settings.parakeet_model === model.size && model.available ? 'border-[var(--accent-success)]' : ''
               }`}
             >
               <div className="flex items-center justify-between">
                 <div className="flex items-center gap-3">
                   <div className={`w-3 h-3 rounded-full ${
-                    settings.parakeet_model === model.size && model.available
+                    !settings.custom_model && settings.parakeet_model === model.size && model.available
                       ? 'bg-[var(--accent-success)]'
                       : 'bg-[var(--glass-border)]'
                   }`} />
@@ -403,10 +497,11 @@ export function EngineSection({ settings, updateSettings }: EngineSectionProps)
                     <span className="text-[0.75rem] text-[var(--text-muted)] w-12 text-right tabular-nums">
                       {Math.round(parakeetDownloadProgress?.percent || 0)}%
                     </span>
+                    <DownloadActions id={parakeetDownloadProgress?.id} />
                   </div>
                 ) : model.available ? (
                   <div className="flex items-center gap-3">
-                    {settings.parakeet_model === model.size ? (
+                    {!settings.custom_model && settings.parakeet_model === model.size ? (
                       <span className="tag-frost success">Actif</span>
                     ) : (
                       <button
@@ -442,8 +537,55 @@ export function EngineSection({ settings, updateSettings }: EngineSectionProps)
               </div>
             </div>
           ))}
+          <div>
+            <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Decodage</label>
+            <select
+              value={settings.parakeet_beam_width}
+              onChange={(e) => updateSettings({ parakeet_beam_width: Number(e.target.value) })}
+              className="select-glass"
+            >
+              <option value={1}>Glouton (rapide)</option>
+              <option value={2}>Faisceau 2</option>
+              <option value={4}>Faisceau 4 (plus precis)</option>
+              <option value={8}>Faisceau 8 (lent)</option>
+            </select>
+          </div>
         </div>
       )}
     </section>
   );
 }
+
+/** Pause (le fichier partiel est conserve et repris au prochain telechargement) et annulation */
+function DownloadActions({ id }: { id?: string }) {
+  if (!id) return null;
+
+  const run = (command: 'pause_download' | 'cancel_download') => {
+    invoke(command, { id }).catch((e) => console.error(`${command} failed:`, e));
+  };
+
+  return (
+    <div className="flex items-center">
+      <button
+        onClick={() => run('pause_download')}
+        title="Mettre en pause"
+        className="text-[var(--text-muted)] hover:text-[var(--text-primary)] transition-colors p-1"
+      >
+        <svg width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" strokeWidth="2">
+          <line x1="9" y1="5" x2="9" y2="19" />
+          <line x1="15" y1="5" x2="15" y2="19" />
+        </svg>
+      </button>
+      <button
+        onClick={() => run('cancel_download')}
+        title="Annuler"
+        className="text-[var(--text-muted)] hover:text-[var(--accent-danger)] transition-colors p-1"
+      >
+        <svg width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" strokeWidth="2">
+          <line x1="18" y1="6" x2="6" y2="18" />
+          <line x1="6" y1="6" x2="18" y2="18" />
+        </svg>
+      </button>
+    </div>
+  );
+}
@@ -0,0 +1,93 @@
+import { open } from '@tauri-apps/plugin-dialog';
+import { AppSettings, ExportConfig, ExportFormat } from '../../types';
+
+interface ExportSectionProps {
+  settings: AppSettings;
+  updateSettings: (settings: Partial<AppSettings>) => Promise<void>;
+}
+
+export const EXPORT_FORMATS: { format: ExportFormat; label: string }[] = [
+  { format: 'srt', label: 'SRT' },
+  { format: 'vtt', label: 'WebVTT' },
+  { format: 'txt', label: 'TXT horodate' },
+  { format: 'json', label: 'JSON' },
+];
+
+export function ExportSection({ settings, updateSettings }: ExportSectionProps) {
+  const config = settings.export;
+
+  const updateExport = (changes: Partial<ExportConfig>) =>
+    updateSettings({ export: { ...config, ...changes } });
+
+  const toggleFormat = (format: ExportFormat, enabled: boolean) =>
+    updateExport({
+      formats: enabled
+        ? EXPORT_FORMATS.map((f) => f.format).filter((f) => f === format || config.formats.includes(f))
+        : config.formats.filter((f) => f !== format),
+    });
+
+  const handleSelectDirectory = async () => {
+    try {
+      const selected = await open({ directory: true, multiple: false });
+      if (selected && typeof selected === 'string') {
+        await updateExport({ output_dir: selected });
+      }
+    } catch (e) {
+      console.error('Failed to select export directory:', e);
+    }
+  };
+
+  return (
+    <section className="space-y-4">
+      <h3 className="section-title secondary">Export des fichiers</h3>
+
+      <label className="checkbox-frost">
+        <input
+          type="checkbox"
+          checked={config.auto_export}
+          onChange={(e) => updateExport({ auto_export: e.target.checked })}
+        />
+        <span className="check-box" />
+        <span className="check-label">Exporter automatiquement apres transcription</span>
+      </label>
+
+      <div>
+        <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Formats</label>
+        <div className="grid grid-cols-2 gap-2">
+          {EXPORT_FORMATS.map(({ format, label }) => (
+            <label key={format} className="checkbox-frost">
+              <input
+                type="checkbox"
+                checked={config.formats.includes(format)}
+                onChange={(e) => toggleFormat(format, e.target.checked)}
+              />
+              <span className="check-box" />
+              <span className="check-label">{label}</span>
+            </label>
+          ))}
+        </div>
+      </div>
+
+      <div>
+        <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Dossier de sortie</label>
+        <div className="flex gap-2 items-center">
+          <input
+            type="text"
+            value={config.output_dir || ''}
+            readOnly
+            placeholder="A cote du fichier source"
+            className="input-glass flex-1 text-[0.8rem]"
+          />
+          <button onClick={handleSelectDirectory} className="btn-glass px-3 text-[var(--accent-primary)]">
+            Parcourir
+          </button>
+          {config.output_dir && (
+            <button onClick={() => updateExport({ output_dir: null })} className="btn-glass px-3">
+              Reinitialiser
+            </button>
+          )}
+        </div>
+      </div>
+    </section>
+  );
+}
@@ -8,6 +8,7 @@ import {
   DownloadProgress,
   LlmDownloadProgress,
   GroqQuota,
+  OpenAiProviderConfig,
 } from '../../types';
 
 interface LlmSectionProps {
@@ -153,11 +154,21 @@ export function LlmSection({ settings, updateSettings, onApiKeyStatusChange }: L
           <span className="check-label">Activer le post-traitement LLM</span>
         </label>
 
+        <label className="checkbox-frost">
+          <input
+            type="checkbox"
+            checked={settings.llm_stream_typing}
+            onChange={(e) => updateSettings({ llm_stream_typing: e.target.checked })}
+          />
+          <span className="check-box" />
+          <span className="check-label">Taper la reponse au fil de la generation (dictee, actions vocales, traduction)</span>
+        </label>
+
         {settings.llm_enabled && (
           <>
             {/* Provider Selection */}
             <div>
-              <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Provider de resume</label>
+              <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Provider LLM</label>
               <div className="flex gap-2">
                 <button
                   onClick={() => updateSettings({ llm_provider: 'groq' })}
@@ -173,6 +184,13 @@ export function LlmSection({ settings, updateSettings, onApiKeyStatusChange }: L
                   <span className="w-2 h-2 rounded-full bg-green-500" />
                   Local
                 </button>
+                <button
+                  onClick={() => updateSettings({ llm_provider: 'openai' })}
+                  className={`btn-glass flex-1 ${settings.llm_provider === 'openai' ? 'border-[var(--accent-primary)] bg-[var(--accent-primary-soft)]' : ''}`}
+                >
+                  <span className="w-2 h-2 rounded-full bg-purple-500" />
+                  Serveur
+                </button>
               </div>
             </div>
 
@@ -352,6 +370,20 @@ export function LlmSection({ settings, updateSettings, onApiKeyStatusChange }: L
                   </div>
                 </div>
 
+                <div>
+                  <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">
+                    Decharger le modele apres inactivite (minutes, 0 = jamais)
+                  </label>
+                  <input
+                    type="number"
+                    min={0}
+                    step={1}
+                    value={settings.llm_idle_timeout_minutes}
+                    onChange={(e) => updateSettings({ llm_idle_timeout_minutes: Math.max(0, Math.round(Number(e.target.value))) })}
+                    className="input-glass w-28 text-[0.8rem]"
+                  />
+                </div>
+
                 {llmDownloadError && (
                   <div className="glass-card p-4 border-[var(--accent-danger)]">
                     <p className="text-[0.8rem] text-[var(--accent-danger)]">
@@ -370,6 +402,14 @@ export function LlmSection({ settings, updateSettings, onApiKeyStatusChange }: L
               </div>
             )}
 
+            {/* OpenAI-compatible Server Configuration */}
+            {settings.llm_provider === 'openai' && (
+              <OpenAiProviderSettings
+                config={settings.openai_provider}
+                onSave={(openai_provider) => updateSettings({ openai_provider })}
+              />
+            )}
+
             <div>
               <label className="text-[0.8rem] text-[var(--text-muted)] mb-3 block">Mode de correction</label>
               <div className="space-y-2">
@@ -396,3 +436,97 @@ export function LlmSection({ settings, updateSettings, onApiKeyStatusChange }: L
     </section>
   );
 }
+
+/** Serveur compatible OpenAI (vLLM, Ollama, llama-server...) */
+function OpenAiProviderSettings({
+  config,
+  onSave,
+}: {
+  config: OpenAiProviderConfig;
+  onSave: (config: OpenAiProviderConfig) => Promise<void>;
+}) {
+  const [baseUrl, setBaseUrl] = useState(config.base_url);
+  const [model, setModel] = useState(config.model);
+  const [apiKey, setApiKey] = useState(config.api_key ?? '');
+  const [headers, setHeaders] = useState(
+    Object.entries(config.headers).map(([name, value]) => `${name}: ${value}`).join('\n')
+  );
+  const [status, setStatus] = useState<{ ok: boolean; message: string } | null>(null);
+
+  const currentConfig = (): OpenAiProviderConfig => ({
+    base_url: baseUrl.trim(),
+    model: model.trim(),
+    api_key: apiKey.trim() || null,
+    headers: Object.fromEntries(
+      headers
+        .split('\n')
+        .map((line) => line.split(':'))
+        .filter(([name, ...value]) => name.trim() && value.length > 0)
+        .map(([name, ...value]) => [name.trim(), value.join(':').trim()])
+    ),
+  });
+
+  const handleSave = async () => {
+    const next = currentConfig();
+    await onSave(next);
+    try {
+      await invoke<string>('test_openai_provider', { config: next });
+      setStatus({ ok: true, message: 'Serveur joignable' });
+    } catch (e) {
+      setStatus({ ok: false, message: String(e) });
+    }
+  };
+
+  return (
+    <div className="space-y-3">
+      <div>
+        <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">URL de base</label>
+        <input
+          type="text"
+          value={baseUrl}
+          onChange={(e) => setBaseUrl(e.target.value)}
+          placeholder="http://localhost:11434/v1"
+          className="input-glass w-full"
+        />
+      </div>
+      <div className="flex gap-2">
+        <div className="flex-1">
+          <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Modele</label>
+          <input
+            type="text"
+            value={model}
+            onChange={(e) => setModel(e.target.value)}
+            placeholder="llama3.1"
+            className="input-glass w-full"
+          />
+        </div>
+        <div className="flex-1">
+          <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Cle API (optionnelle)</label>
+          <input
+            type="password"
+            value={apiKey}
+            onChange={(e) => setApiKey(e.target.value)}
+            className="input-glass w-full"
+          />
+        </div>
+      </div>
+      <div>
+        <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">En-tetes HTTP (un par ligne, Nom: valeur)</label>
+        <textarea
+          value={headers}
+          onChange={(e) => setHeaders(e.target.value)}
+          rows={2}
+          className="input-glass w-full text-[0.8rem] font-mono"
+        />
+      </div>
+      <button onClick={handleSave} disabled={!baseUrl.trim() || !model.trim()} className="btn-glass">
+        Enregistrer et tester
+      </button>
+      {status && (
+        <p className={`text-[0.75rem] ${status.ok ? 'text-[var(--accent-success)]' : 'text-[var(--accent-danger)]'}`}>
+          {status.ok ? '✓' : '✗'} {status.message}
+        </p>
+      )}
+    </div>
+  );
+}
@@ -42,6 +42,21 @@ export function ShortcutsSection({ settings, updateSettings }: ShortcutsSectionP
             value={settings.hotkey_toggle_record}
             onChange={(hotkey) => updateSettings({ hotkey_toggle_record: hotkey })}
           />
+          <p className="text-[0.65rem] text-[var(--text-muted)] mt-1">Appuyer pour dicter, appuyer a nouveau pour coller</p>
+        </div>
+        <div>
+          <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Arret automatique (toggle)</label>
+          <select
+            value={settings.toggle_auto_stop_secs}
+            onChange={(e) => updateSettings({ toggle_auto_stop_secs: Number(e.target.value) })}
+            className="select-glass"
+          >
+            <option value={0}>Desactive</option>
+            <option value={1.5}>1,5 s de silence</option>
+            <option value={3}>3 s de silence</option>
+            <option value={5}>5 s de silence</option>
+            <option value={10}>10 s de silence</option>
+          </select>
         </div>
       </div>
       <p className="text-[0.75rem] text-[var(--text-muted)]">
@@ -25,6 +25,19 @@ export function SystemSection({ settings, updateSettings }: SystemSectionProps)
           </div>
         </label>
 
+        <label className="checkbox-frost">
+          <input
+            type="checkbox"
+            checked={settings.vad_enabled}
+            onChange={(e) => updateSettings({ vad_enabled: e.target.checked })}
+          />
+          <span className="check-box" />
+          <div>
+            <span className="check-label block">Detection de la voix</span>
+            <span className="text-[0.75rem] text-[var(--text-muted)]">Ignore les silences avant la transcription</span>
+          </div>
+        </label>
+
         <label className="checkbox-frost">
           <input
             type="checkbox"
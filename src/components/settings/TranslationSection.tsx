@@ -23,7 +23,7 @@ export function TranslationSection({ settings, updateSettings, apiKeyStatus }: T
           <div>
             <span className="check-label block">Traduction instantanee</span>
             <span className="text-[0.75rem] text-[var(--text-muted)]">
-              Traduit le texte du presse-papier via Groq
+              Traduit le texte du presse-papier via le provider LLM
             </span>
           </div>
         </label>
@@ -63,7 +63,19 @@ export function TranslationSection({ settings, updateSettings, apiKeyStatus }: T
               </p>
             </div>
 
-            {!apiKeyStatus && (
+            <div>
+              <label className="text-[0.8rem] text-[var(--text-muted)] mb-2 block">Dictee traduite (maintenir)</label>
+              <HotkeyInput
+                value={settings.hotkey_translate_dictation}
+                onChange={(hotkey) => updateSettings({ hotkey_translate_dictation: hotkey })}
+              />
+              <p className="text-[0.75rem] text-[var(--text-muted)] mt-2">
+                Parlez dans n'importe quelle langue, le texte est colle dans la langue cible.
+                Vers l'anglais, Whisper traduit hors ligne ; sinon la traduction passe par le provider LLM.
+              </p>
+            </div>
+
+            {settings.llm_provider === 'groq' && !apiKeyStatus && (
               <div className="glass-card p-4 border-[var(--accent-warning)]">
                 <p className="text-[0.8rem] text-[var(--accent-warning)]">
                   ⚠️ Une cle API Groq est requise pour la traduction.
@@ -1,5 +1,6 @@
 export { AudioSection } from './AudioSection';
 export { EngineSection } from './EngineSection';
+export { CustomModelsSection } from './CustomModelsSection';
 export { LlmSection } from './LlmSection';
 export { TranslationSection } from './TranslationSection';
 export { DictationSection } from './DictationSection';
@@ -10,3 +11,4 @@ export { ShortcutsSection } from './ShortcutsSection';
 export { DictionarySection } from './DictionarySection';
 export { SnippetsSection } from './SnippetsSection';
 export { IntegrationsSection } from './IntegrationsSection';
+export { ExportSection, EXPORT_FORMATS } from './ExportSection';
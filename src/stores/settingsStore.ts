@@ -30,9 +30,20 @@ const defaultSettings: AppSettings = {
   engine_type: 'whisper',
   vosk_language: null,
   parakeet_model: 'tdt06bv3',
+  custom_model: null,
+  model_manifest_url: null,
+  parakeet_beam_width: 1,
   groq_api_key: null,
   llm_provider: 'groq',
   local_llm_model: 'phi3_mini',
+  openai_provider: {
+    base_url: 'http://localhost:11434/v1',
+    model: 'llama3.1',
+    api_key: null,
+    headers: {},
+  },
+  llm_stream_typing: false,
+  llm_idle_timeout_minutes: 5,
   llm_enabled: false,
   llm_mode: 'basic',
   voice_commands_enabled: true,
@@ -43,6 +54,7 @@ const defaultSettings: AppSettings = {
   translation_enabled: true,
   translation_target_language: 'en',
   hotkey_translate: 'Control+Alt+T',
+  hotkey_translate_dictation: 'Control+Alt+Space',
   hotkey_voice_action: 'Control+Alt+A',
   onboarding_completed: false,
   tour_completed: false,
@@ -50,11 +62,31 @@ const defaultSettings: AppSettings = {
   subtitles_enabled: false,
   subtitles_font_size: 20,
   stats_tracking_enabled: true,
+  vad_enabled: true,
+  toggle_auto_stop_secs: 3,
   integrations: {
     apple_notes_enabled: false,
     obsidian_enabled: false,
     obsidian_vault_path: null,
   },
+  whisper_decode: {
+    beam_size: 1,
+    temperature: 0,
+    temperature_increment: 0.2,
+    entropy_threshold: 2.4,
+    logprob_threshold: -1,
+    no_speech_threshold: 0.6,
+    initial_prompt: null,
+    condition_on_previous_text: false,
+  },
+  export: {
+    auto_export: false,
+    formats: ['srt', 'txt'],
+    output_dir: null,
+    max_line_length: 42,
+    max_lines_per_caption: 2,
+    max_caption_duration_secs: 6,
+  },
 };
 
 export const useSettingsStore = create<SettingsStore>((set, get) => ({
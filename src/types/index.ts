@@ -1,3 +1,24 @@
+export interface WordTiming {
+  word: string;
+  start: number;
+  end: number;
+  probability: number;
+}
+
+export interface Segment {
+  start: number;
+  end: number;
+  text: string;
+  words: WordTiming[];
+}
+
+export interface ConfidenceSpan {
+  start: number;
+  end: number;
+  text: string;
+  probability: number;
+}
+
 export interface TranscriptionResult {
   text: string;
   confidence: number;
@@ -6,9 +27,18 @@ export interface TranscriptionResult {
   detected_language: string | null;
   timestamp: number;
   model_used: string | null;
+  segments: Segment[];
+  low_confidence_spans: ConfidenceSpan[];
 }
 
-export type ModelSize = 'tiny' | 'small' | 'medium';
+export type ModelSize =
+  | 'tiny' | 'tiny_q5' | 'tiny_q8'
+  | 'base' | 'base_q5' | 'base_q8'
+  | 'small' | 'small_q5' | 'small_q8'
+  | 'medium' | 'medium_q5' | 'medium_q8'
+  | 'large_v3' | 'large_v3_q5'
+  | 'large_v3_turbo' | 'large_v3_turbo_q5' | 'large_v3_turbo_q8'
+  | 'distil_medium_en' | 'distil_large_v3';
 
 export type EngineType = 'whisper' | 'parakeet' | 'vosk';
 
@@ -25,7 +55,14 @@ export interface ParakeetModelInfo {
 
 export type LlmMode = 'off' | 'basic' | 'smart' | 'contextual';
 
-export type LlmProvider = 'groq' | 'local';
+export type LlmProvider = 'groq' | 'local' | 'openai';
+
+export interface OpenAiProviderConfig {
+  base_url: string;
+  model: string;
+  api_key: string | null;
+  headers: Record<string, string>;
+}
 
 export type LocalLlmModel = 'smollm2_360m' | 'phi3_mini' | 'qwen2_5_3b';
 
@@ -41,14 +78,37 @@ export type QwenModelSize = LocalLlmModel;
 
 export type DictationMode = 'general' | 'email' | 'code' | 'notes';
 
+export interface CustomModel {
+  id: string;
+  name: string;
+  engine: EngineType;
+  path: string;
+  language: VoskLanguage | null;
+  imported_at: number;
+}
+
+export interface CatalogModel {
+  id: string;
+  engine: EngineType;
+  display_name: string;
+  license: string;
+  languages: string[];
+  size_bytes: number;
+  installed: boolean;
+}
+
 export interface ModelInfo {
   size: ModelSize;
   display_name: string;
   available: boolean;
   size_bytes: number;
+  ram_bytes: number;
+  relative_speed: number;
+  multilingual: boolean;
 }
 
 export interface DownloadProgress {
+  id?: string;
   downloaded: number;
   total: number;
   percent: number;
@@ -68,9 +128,15 @@ export interface AppSettings {
   engine_type: EngineType;
   vosk_language: VoskLanguage | null;
   parakeet_model: ParakeetModelSize;
+  custom_model: string | null;
+  model_manifest_url: string | null;
+  parakeet_beam_width: number;
   groq_api_key: string | null;
   llm_provider: LlmProvider;
   local_llm_model: LocalLlmModel;
+  openai_provider: OpenAiProviderConfig;
+  llm_stream_typing: boolean;
+  llm_idle_timeout_minutes: number;
   llm_enabled: boolean;
   llm_mode: LlmMode;
   voice_commands_enabled: boolean;
@@ -81,6 +147,7 @@ export interface AppSettings {
   translation_enabled: boolean;
   translation_target_language: string;
   hotkey_translate: string;
+  hotkey_translate_dictation: string;
   hotkey_voice_action: string;
   onboarding_completed: boolean;
   tour_completed: boolean;
@@ -88,7 +155,11 @@ export interface AppSettings {
   subtitles_enabled: boolean;
   subtitles_font_size: number;
   stats_tracking_enabled: boolean;
+  vad_enabled: boolean;
+  toggle_auto_stop_secs: number;
+  whisper_decode: WhisperDecodeOptions;
   integrations: IntegrationConfig;
+  export: ExportConfig;
 }
 
 export interface VoskModelInfo {
@@ -102,6 +173,7 @@ export interface FileTranscriptionResult {
   file_name: string;
   transcription: TranscriptionResult | null;
   error: string | null;
+  exported_files: string[];
 }
 
 export interface FileTranscriptionProgress {
@@ -109,6 +181,7 @@ export interface FileTranscriptionProgress {
   total: number;
   file_name: string;
   status: string;
+  percent: number;
 }
 
 export interface Snippet {
@@ -132,12 +205,34 @@ export interface UsageStats {
   languages_used: Record<string, number>;
 }
 
+export interface WhisperDecodeOptions {
+  beam_size: number;
+  temperature: number;
+  temperature_increment: number;
+  entropy_threshold: number;
+  logprob_threshold: number;
+  no_speech_threshold: number;
+  initial_prompt: string | null;
+  condition_on_previous_text: boolean;
+}
+
 export interface IntegrationConfig {
   apple_notes_enabled: boolean;
   obsidian_enabled: boolean;
   obsidian_vault_path: string | null;
 }
 
+export type ExportFormat = 'srt' | 'vtt' | 'txt' | 'json';
+
+export interface ExportConfig {
+  auto_export: boolean;
+  formats: ExportFormat[];
+  output_dir: string | null;
+  max_line_length: number;
+  max_lines_per_caption: number;
+  max_caption_duration_secs: number;
+}
+
 export interface AudioDevice {
   id: string;
   name: string;
@@ -161,6 +256,26 @@ export interface StreamingChunk {
   duration_seconds: number;
 }
 
+// Événements 'transcription-committed' (mots validés, définitifs)
+// et 'transcription-partial' (fin provisoire de l'hypothèse)
+export interface StreamingTextEvent {
+  text: string;
+  committed_text: string;
+  duration_seconds: number;
+}
+
+/** Fragment de reponse LLM emis au fil de la generation */
+export interface LlmToken {
+  source: 'summary' | 'translation' | 'post_processing' | 'voice_action';
+  token: string;
+}
+
+/** Requete mise en file par le LLM local, annulable par son identifiant */
+export interface LlmRequestEvent {
+  source: 'summary';
+  request_id: number;
+}
+
 export interface LlmDownloadProgress {
   model: LocalLlmModel;
   downloaded: number;